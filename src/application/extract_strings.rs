@@ -1,6 +1,10 @@
-use crate::domain::models::LanguageFile;
+use crate::domain::i18next::PluralGroup;
+use crate::domain::icu::{Message, is_icu_message};
+use crate::domain::models::{
+    CatalogFormat, CatalogMetadata, ExtractedMessage, LanguageFile, PluralFormat,
+};
 use crate::domain::ports::{FileScanner, FileWriter, StringExtractor};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 /// Use case: Extract all translatable strings from a codebase
@@ -9,10 +13,13 @@ pub struct ExtractStringsUseCase;
 
 impl ExtractStringsUseCase {
     #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
     pub async fn execute(
         source_path: &Path,
         output_path: &Path,
         base_language: &str,
+        plural_format: PluralFormat,
+        catalog_format: CatalogFormat,
         scanner: &dyn FileScanner,
         extractor: &dyn StringExtractor,
         writer: &dyn FileWriter,
@@ -23,9 +30,11 @@ impl ExtractStringsUseCase {
 
         // 2. Extract strings from each file
         let mut all_keys = HashMap::new();
+        let mut metadata = CatalogMetadata::default();
         for (file_path, file_type) in files {
             let keys = extractor.extract(&file_path, file_type).await?;
             for key in keys {
+                metadata.add(&key);
                 all_keys.insert(key.id.clone(), key.source.clone());
             }
         }
@@ -33,16 +42,78 @@ impl ExtractStringsUseCase {
         tracing::info!("Found {} unique keys", all_keys.len());
 
         // 3. Create language file and write
-        let mut language_file = LanguageFile::new();
-        for (key, value) in all_keys {
-            language_file.insert(key, value);
+        let output_file = output_path.join(format!("{}.json", base_language));
+        if catalog_format == CatalogFormat::FormatJs {
+            if plural_format == PluralFormat::I18next {
+                return Err(anyhow::anyhow!(
+                    "FormatJS catalogs hold ICU messages; use --plural-format icu"
+                ));
+            }
+            // Developer comments become descriptions for translators
+            let messages: BTreeMap<String, ExtractedMessage> = all_keys
+                .into_iter()
+                .map(|(key, value)| {
+                    let description = metadata.keys.get(&key).and_then(|m| m.comment.clone());
+                    let message = ExtractedMessage {
+                        default_message: value,
+                        description,
+                    };
+                    (key, message)
+                })
+                .collect();
+            writer
+                .write_extracted_messages(&output_file, &messages)
+                .await?;
+        } else {
+            Self::write_flat(&output_file, all_keys, plural_format, writer).await?;
         }
 
-        let output_file = output_path.join(format!("{}.json", base_language));
+        // 4. Write translator context next to the catalog
         writer
-            .write_language_file(&output_file, &language_file)
+            .write_metadata(&CatalogMetadata::path_for(&output_file), &metadata)
             .await?;
 
         Ok(())
     }
+
+    /// Write a `key -> message` catalog, plurals in `plural_format`
+    async fn write_flat(
+        output_file: &Path,
+        all_keys: HashMap<String, String>,
+        plural_format: PluralFormat,
+        writer: &dyn FileWriter,
+    ) -> anyhow::Result<()> {
+        let mut language_file = LanguageFile::new();
+        for (key, value) in all_keys {
+            match plural_format {
+                PluralFormat::I18next if is_icu_message(&value) => {
+                    Self::insert_i18next(&mut language_file, key, value)
+                }
+                _ => language_file.insert(key, value),
+            }
+        }
+
+        writer
+            .write_language_file(output_file, &language_file)
+            .await
+    }
+
+    /// Write a plural message as i18next `key_one` / `key_other` entries
+    ///
+    /// Messages i18next cannot express are kept as ICU.
+    fn insert_i18next(language_file: &mut LanguageFile, key: String, value: String) {
+        let group =
+            Message::parse(&value).and_then(|message| PluralGroup::from_message(&key, &message));
+        match group {
+            Ok(group) => {
+                for (category, text) in &group.forms {
+                    language_file.insert(group.key(*category), text.clone());
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Keeping {} as an ICU message: {}", key, e);
+                language_file.insert(key, value);
+            }
+        }
+    }
 }
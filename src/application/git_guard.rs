@@ -0,0 +1,186 @@
+use crate::domain::models::GitSafety;
+use crate::domain::ports::VersionControl;
+use std::path::{Path, PathBuf};
+
+/// Git checks around a command that rewrites source files
+///
+/// Without a repository there is nothing to check, but `--branch` and `--commit` are refused.
+pub struct GitGuard<'a> {
+    vcs: Option<&'a dyn VersionControl>,
+    safety: GitSafety,
+}
+
+impl<'a> GitGuard<'a> {
+    pub fn new(vcs: Option<&'a dyn VersionControl>, safety: GitSafety) -> Self {
+        Self { vcs, safety }
+    }
+
+    /// Top of the git working tree, if there is one
+    pub fn root(&self) -> Option<&Path> {
+        self.vcs.map(|vcs| vcs.root())
+    }
+
+    /// Refuse to overwrite uncommitted work, then switch to the requested branch
+    pub async fn before_write(&self, overwritten: &[PathBuf]) -> anyhow::Result<()> {
+        let Some(vcs) = self.vcs else {
+            if self.safety.branch.is_some() || self.safety.commit {
+                return Err(anyhow::anyhow!(
+                    "--branch and --commit need a git repository"
+                ));
+            }
+            return Ok(());
+        };
+
+        let dirty = vcs.dirty_files(overwritten).await?;
+        if !dirty.is_empty() && !self.safety.force {
+            let files: Vec<String> = dirty.iter().map(|p| p.display().to_string()).collect();
+            return Err(anyhow::anyhow!(
+                "Uncommitted changes in {}; commit or stash them first, or pass --force",
+                files.join(", ")
+            ));
+        }
+
+        if let Some(branch) = &self.safety.branch {
+            vcs.create_branch(branch).await?;
+            tracing::info!("Switched to new branch {}", branch);
+        }
+        Ok(())
+    }
+
+    /// Commit what the command wrote, if asked to
+    pub async fn after_write(&self, written: &[PathBuf], message: &str) -> anyhow::Result<()> {
+        if let Some(vcs) = self.vcs
+            && self.safety.commit
+        {
+            vcs.commit(written, message).await?;
+            tracing::info!("Committed {} files: {}", written.len(), message);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Repository whose `dirty` files have uncommitted changes, recording what is asked of it
+    struct FakeRepository {
+        dirty: Vec<PathBuf>,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl FakeRepository {
+        fn new(dirty: &[&str]) -> Self {
+            Self {
+                dirty: dirty.iter().map(PathBuf::from).collect(),
+                calls: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl VersionControl for FakeRepository {
+        fn root(&self) -> &Path {
+            Path::new("/repo")
+        }
+
+        async fn dirty_files(&self, paths: &[PathBuf]) -> anyhow::Result<Vec<PathBuf>> {
+            Ok(paths
+                .iter()
+                .filter(|p| self.dirty.contains(p))
+                .cloned()
+                .collect())
+        }
+
+        async fn create_branch(&self, name: &str) -> anyhow::Result<()> {
+            self.calls.lock().unwrap().push(format!("branch {}", name));
+            Ok(())
+        }
+
+        async fn commit(&self, paths: &[PathBuf], message: &str) -> anyhow::Result<()> {
+            let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("commit {}: {}", paths.join(" "), message));
+            Ok(())
+        }
+    }
+
+    fn paths(names: &[&str]) -> Vec<PathBuf> {
+        names.iter().map(PathBuf::from).collect()
+    }
+
+    #[tokio::test]
+    async fn test_before_write_refuses_uncommitted_files() {
+        let repo = FakeRepository::new(&["App.jsx"]);
+        let guard = GitGuard::new(Some(&repo), GitSafety::default());
+        let err = guard
+            .before_write(&paths(&["App.jsx", "Nav.jsx"]))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("App.jsx"), "{}", err);
+        assert!(!err.to_string().contains("Nav.jsx"), "{}", err);
+        guard.before_write(&paths(&["Nav.jsx"])).await.unwrap();
+
+        let forced = GitGuard::new(
+            Some(&repo),
+            GitSafety {
+                force: true,
+                ..Default::default()
+            },
+        );
+        forced.before_write(&paths(&["App.jsx"])).await.unwrap();
+        assert!(repo.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_branch_and_commit() {
+        let repo = FakeRepository::new(&[]);
+        let guard = GitGuard::new(
+            Some(&repo),
+            GitSafety {
+                force: false,
+                branch: Some("i18n".to_string()),
+                commit: true,
+            },
+        );
+        guard.before_write(&paths(&["App.jsx"])).await.unwrap();
+        guard
+            .after_write(&paths(&["App.jsx"]), "Replace strings")
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.calls(),
+            vec!["branch i18n", "commit App.jsx: Replace strings"]
+        );
+
+        // Without --commit nothing is committed
+        let repo = FakeRepository::new(&[]);
+        let guard = GitGuard::new(Some(&repo), GitSafety::default());
+        guard.after_write(&paths(&["App.jsx"]), "x").await.unwrap();
+        assert!(repo.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_branch_and_commit_need_a_repository() {
+        let guard = GitGuard::new(None, GitSafety::default());
+        guard.before_write(&paths(&["App.jsx"])).await.unwrap();
+        assert!(guard.root().is_none());
+
+        let guard = GitGuard::new(
+            None,
+            GitSafety {
+                commit: true,
+                ..Default::default()
+            },
+        );
+        assert!(guard.before_write(&[]).await.is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::domain::journal::{Journal, JournalEntry, content_hash};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs;
+
+/// Writes the files of one run, journaling and backing up each one first
+///
+/// If the process dies mid-run, every file it may have touched is in the journal,
+/// so `undo` can still put it back.
+pub struct JournaledWriter {
+    dir: PathBuf,
+    journal: Journal,
+}
+
+impl JournaledWriter {
+    /// Start a run in `<project_dir>/.langsmith/journal/<timestamp>`
+    pub async fn begin(project_dir: &Path, command: &str) -> anyhow::Result<Self> {
+        let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        let dir = Journal::root(project_dir).join(format!("{:013}", created_at));
+        fs::create_dir_all(&dir).await?;
+
+        // Backups are copies of source files; keep them out of commits
+        let ignore = project_dir.join(".langsmith").join(".gitignore");
+        if !ignore.exists() {
+            fs::write(&ignore, "*\n").await?;
+        }
+
+        Ok(Self {
+            dir,
+            journal: Journal {
+                command: command.to_string(),
+                created_at,
+                files: Vec::new(),
+            },
+        })
+    }
+
+    pub async fn write(&mut self, path: &Path, content: &str) -> anyhow::Result<()> {
+        let path = std::path::absolute(path)?;
+        let original = fs::read(&path).await.ok();
+
+        let backup = match &original {
+            Some(bytes) => {
+                let name = format!("{}.bak", self.journal.files.len());
+                write_atomic(&self.dir.join(&name), bytes).await?;
+                Some(name)
+            }
+            None => None,
+        };
+        self.journal.files.push(JournalEntry {
+            path: path.clone(),
+            original_hash: original.as_deref().map(content_hash),
+            backup,
+            written_hash: content_hash(content.as_bytes()),
+        });
+        self.save().await?;
+
+        write_atomic(&path, content.as_bytes()).await
+    }
+
+    /// Put back every file written so far and drop the run
+    pub async fn rollback(self) -> anyhow::Result<()> {
+        for entry in self.journal.files.iter().rev() {
+            restore(&self.dir, entry).await?;
+        }
+        fs::remove_dir_all(&self.dir).await?;
+        Ok(())
+    }
+
+    /// Keep the journal for `undo`, with the last few runs before it; a run
+    /// that wrote nothing leaves no trace
+    pub async fn finish(self) -> anyhow::Result<()> {
+        if self.journal.files.is_empty() {
+            fs::remove_dir_all(&self.dir).await?;
+            return Ok(());
+        }
+
+        let Some(root) = self.dir.parent() else {
+            return Ok(());
+        };
+        let mut runs = Vec::new();
+        let mut entries = fs::read_dir(root).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_dir() {
+                runs.push(entry.path());
+            }
+        }
+        // Names are zero-padded timestamps, oldest first once sorted
+        runs.sort();
+        let old = runs.len().saturating_sub(Journal::KEPT_RUNS);
+        for run in &runs[..old] {
+            fs::remove_dir_all(run).await?;
+        }
+        Ok(())
+    }
+
+    async fn save(&self) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(&self.journal)?;
+        write_atomic(&self.dir.join(Journal::FILE_NAME), json.as_bytes()).await
+    }
+}
+
+/// Bring a file back to its state before the run: its backup, or nothing if the run created it
+pub async fn restore(run_dir: &Path, entry: &JournalEntry) -> anyhow::Result<()> {
+    match &entry.backup {
+        Some(backup) => {
+            let original = fs::read(run_dir.join(backup)).await?;
+            write_atomic(&entry.path, &original).await
+        }
+        None => match fs::remove_file(&entry.path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        },
+    }
+}
+
+/// Write through a temporary file and rename it, so readers never see half a file
+pub async fn write_atomic(path: &Path, content: &[u8]) -> anyhow::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Not a file path: {}", path.display()))?;
+    let tmp = path.with_file_name(format!(".{}.langsmith-tmp", file_name.to_string_lossy()));
+
+    fs::write(&tmp, content).await?;
+    if let Err(e) = fs::rename(&tmp, path).await {
+        fs::remove_file(&tmp).await.ok();
+        return Err(e.into());
+    }
+    Ok(())
+}
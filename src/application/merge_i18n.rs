@@ -1,3 +1,4 @@
+use super::git_guard::GitGuard;
 use owo_colors::OwoColorize;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -95,7 +96,18 @@ impl MergeI18nUseCase {
     }
 
     /// Performs the actual merge operation
-    pub async fn execute_merge(summary: &MergeSummary) -> anyhow::Result<MergeResult> {
+    pub async fn execute_merge(
+        summary: &MergeSummary,
+        git: &GitGuard<'_>,
+    ) -> anyhow::Result<MergeResult> {
+        let originals: Vec<PathBuf> = summary
+            .files_to_merge
+            .iter()
+            .map(|f| f.original_file.clone())
+            .collect();
+        git.before_write(&originals).await?;
+
+        let mut merged = Vec::new();
         let mut successful = 0;
         let mut failed = 0;
         let mut errors = Vec::new();
@@ -104,6 +116,8 @@ impl MergeI18nUseCase {
             match Self::merge_single_file(merge_file).await {
                 Ok(_) => {
                     successful += 1;
+                    merged.push(merge_file.original_file.clone());
+                    merged.push(merge_file.i18n_file.clone());
                 }
                 Err(e) => {
                     failed += 1;
@@ -112,6 +126,9 @@ impl MergeI18nUseCase {
             }
         }
 
+        git.after_write(&merged, "Merge translated .i18n files into their originals")
+            .await?;
+
         Ok(MergeResult {
             successful,
             failed,
@@ -1,11 +1,18 @@
 pub mod extract_strings;
+pub mod git_guard;
+pub mod journal;
 pub mod merge_i18n;
 pub mod replace_strings;
+pub mod review_translations;
 pub mod translate_keys;
+pub mod undo_replace;
 
 pub use extract_strings::ExtractStringsUseCase;
+pub use git_guard::GitGuard;
 #[allow(unused_imports)]
 pub use merge_i18n::MergeI18nUseCase;
 #[allow(unused_imports)]
-pub use replace_strings::ReplaceStringsUseCase;
-pub use translate_keys::TranslateKeysUseCase;
+pub use replace_strings::{ReplaceStringsUseCase, ReplaceSummary};
+pub use review_translations::{ReviewEntry, ReviewTranslationsUseCase};
+pub use translate_keys::{CostEstimate, TranslateKeysUseCase, TranslateOptions};
+pub use undo_replace::UndoReplaceUseCase;
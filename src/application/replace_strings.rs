@@ -1,11 +1,41 @@
+use super::git_guard::GitGuard;
+use super::journal::JournaledWriter;
+use crate::domain::i18next;
 use crate::domain::models::*;
 use crate::domain::ports::*;
+use owo_colors::OwoColorize;
+use similar::TextDiff;
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use tokio::fs;
 
 pub struct ReplaceStringsUseCase;
 
+/// Outcome of a replace run
+#[derive(Debug, Default)]
+pub struct ReplaceSummary {
+    pub files: usize,
+    pub strings: usize,
+    /// Files left unmodified because the rewritten code no longer parsed
+    pub invalid: Vec<(PathBuf, SyntaxError)>,
+    /// Files whose original already fails the syntax check, so the rewrite cannot be
+    /// checked; left unmodified unless the run writes unchecked files
+    pub unchecked: Vec<(PathBuf, SyntaxError)>,
+    /// Strings left alone where the strategy has no translation function, e.g.
+    /// outside components, as (file, line, key)
+    pub unreachable: Vec<(PathBuf, usize, String)>,
+}
+
+/// What the syntax check makes of a rewrite
+#[derive(Debug, PartialEq, Eq)]
+enum SyntaxCheck {
+    Passed,
+    /// The original parsed, the rewrite does not
+    Broken(SyntaxError),
+    /// The original already fails the check, so the rewrite cannot be judged by it
+    Unchecked(SyntaxError),
+}
+
 impl ReplaceStringsUseCase {
     #[allow(clippy::too_many_arguments)]
     pub async fn execute(
@@ -14,28 +44,62 @@ impl ReplaceStringsUseCase {
         strategy: ReplacementStrategy,
         dry_run: bool,
         in_place: bool,
+        diff_output: Option<&Path>,
+        write_unchecked: bool,
+        project_dir: &Path,
+        git: &GitGuard<'_>,
         scanner: &dyn FileScanner,
         extractor: &dyn StringExtractor,
         replacer: &dyn CodeReplacer,
+        hook_injector: &dyn HookInjector,
         import_mgr: &dyn ImportManager,
-    ) -> anyhow::Result<()> {
+        validator: &dyn SyntaxValidator,
+    ) -> anyhow::Result<ReplaceSummary> {
         // 1. Load translation keys from JSON
         let translations = Self::load_translations(translation_file).await?;
 
         // 2. Scan source files
         let files = scanner.scan(source_path).await?;
 
-        // 3. Process each file
+        // 3. Process each file; nothing is written until every file is ready
+        let patch_root = match git.root() {
+            Some(root) => root.to_path_buf(),
+            None => std::env::current_dir()?,
+        };
+        let mut patch = String::new();
+        let mut outputs = Vec::new();
+        let mut summary = ReplaceSummary::default();
         for (file_path, file_type) in files {
             // Extract strings using position tracking
             let all_keys = Self::extract_with_positions(extractor, &file_path, file_type).await?;
 
             // Filter: only replace strings that exist in translation JSON
-            let keys_to_replace: Vec<_> = all_keys
+            let mut keys_to_replace: Vec<_> = all_keys
                 .into_iter()
-                .filter(|k| translations.contains_key(&k.id))
+                .filter(|k| i18next::has_key(&translations, &k.id))
                 .collect();
 
+            // A call nothing can translate is worse than the string it replaces
+            let original = fs::read_to_string(&file_path).await?;
+            let positions: Vec<usize> = keys_to_replace.iter().map(|k| k.start_byte).collect();
+            let unreachable =
+                hook_injector.unreachable(&original, file_type, &strategy, &positions);
+            keys_to_replace.retain(|k| {
+                if !unreachable.contains(&k.start_byte) {
+                    return true;
+                }
+                tracing::warn!(
+                    "{}:{}: no translation function outside components, leaving {}",
+                    file_path.display(),
+                    k.line,
+                    k.id
+                );
+                summary
+                    .unreachable
+                    .push((file_path.clone(), k.line, k.id.clone()));
+                false
+            });
+
             if keys_to_replace.is_empty() {
                 continue;
             }
@@ -45,48 +109,183 @@ impl ReplaceStringsUseCase {
                 .replace_in_file(&file_path, &keys_to_replace, &strategy)
                 .await?;
 
+            // Declare `t` where the calls landed
+            content = hook_injector
+                .inject_hooks(&content, file_type, &strategy)
+                .await?;
+
             // Add imports
             content = import_mgr
                 .ensure_import(&content, file_type, &strategy)
                 .await?;
 
-            if dry_run {
-                println!(
-                    "Would replace {} strings in {}",
-                    keys_to_replace.len(),
-                    file_path.display()
-                );
-                for key in &keys_to_replace {
-                    println!(
-                        "  - Line {}: \"{}\" -> t(\"{}\")",
-                        key.line, key.source, key.id
+            // Leave the file alone if the rewrite broke it
+            match Self::check_syntax(validator, &original, &content, file_type) {
+                SyntaxCheck::Passed => {}
+                SyntaxCheck::Broken(error) => {
+                    tracing::warn!(
+                        "Skipping {}: rewritten code does not parse at {}",
+                        file_path.display(),
+                        error
                     );
+                    summary.invalid.push((file_path, error));
+                    continue;
+                }
+                SyntaxCheck::Unchecked(error) if write_unchecked => {
+                    tracing::warn!(
+                        "{}: the original does not pass the syntax check ({}), rewritten unchecked",
+                        file_path.display(),
+                        error
+                    );
+                    summary.unchecked.push((file_path.clone(), error));
+                }
+                SyntaxCheck::Unchecked(error) => {
+                    tracing::warn!(
+                        "Skipping {}: the original does not pass the syntax check ({}), so the rewrite cannot be checked",
+                        file_path.display(),
+                        error
+                    );
+                    summary.unchecked.push((file_path, error));
+                    continue;
                 }
-                continue;
             }
 
-            // Write result
             let output_path = if in_place {
                 file_path.clone()
             } else {
                 Self::create_i18n_filename(&file_path)?
             };
+            summary.files += 1;
+            summary.strings += keys_to_replace.len();
+
+            // Preview: diff of the file the run would write, the `.i18n.*` copy unless
+            // in place, ready for `git apply`; like a dry run, a patch run writes nothing
+            if dry_run || diff_output.is_some() {
+                let current = fs::read_to_string(&output_path).await.ok();
+                let diff = Self::file_diff(&output_path, &patch_root, current.as_deref(), &content);
+                if dry_run {
+                    println!(
+                        "Would replace {} strings in {}",
+                        keys_to_replace.len(),
+                        output_path.display()
+                    );
+                    Self::print_diff(&diff);
+                }
+                patch.push_str(&diff);
+                continue;
+            }
 
-            fs::write(&output_path, content).await?;
-            tracing::info!(
-                "✓ Replaced {} strings in {}",
-                keys_to_replace.len(),
-                output_path.display()
+            outputs.push((output_path, content, keys_to_replace.len()));
+        }
+
+        // 4. Write results, journaled so the run can be undone or rolled back
+        if !outputs.is_empty() {
+            // Only in-place runs overwrite files git may hold uncommitted work in
+            let written: Vec<PathBuf> = outputs.iter().map(|(path, _, _)| path.clone()).collect();
+            git.before_write(if in_place { &written } else { &[] })
+                .await?;
+
+            let mut writer = JournaledWriter::begin(project_dir, "replace").await?;
+            for (output_path, content, count) in &outputs {
+                if let Err(e) = writer.write(output_path, content).await {
+                    writer.rollback().await?;
+                    return Err(e.context(format!(
+                        "Failed to write {}; every file of this run was restored",
+                        output_path.display()
+                    )));
+                }
+                tracing::info!("✓ Replaced {} strings in {}", count, output_path.display());
+            }
+            writer.finish().await?;
+
+            git.after_write(&written, "Replace hardcoded strings with translation calls")
+                .await?;
+        }
+
+        if let Some(diff_path) = diff_output {
+            fs::write(diff_path, &patch).await?;
+            println!(
+                "✓ Patch written to {}, no source file was changed (apply it with: git apply {})",
+                diff_path.display(),
+                diff_path.display()
             );
         }
 
-        Ok(())
+        Ok(summary)
+    }
+
+    /// Judge a rewrite by the validator, unless it already rejects the original
+    fn check_syntax(
+        validator: &dyn SyntaxValidator,
+        original: &str,
+        rewritten: &str,
+        file_type: FileType,
+    ) -> SyntaxCheck {
+        let Some(error) = validator.validate(rewritten, file_type) else {
+            return SyntaxCheck::Passed;
+        };
+        match validator.validate(original, file_type) {
+            None => SyntaxCheck::Broken(error),
+            Some(original_error) => SyntaxCheck::Unchecked(original_error),
+        }
+    }
+
+    /// Git-style unified diff turning `current` into `proposed`; a missing file is created
+    fn file_diff(path: &Path, root: &Path, current: Option<&str>, proposed: &str) -> String {
+        if current == Some(proposed) {
+            return String::new();
+        }
+
+        let name = Self::patch_path(path, root);
+        let mut diff = format!("diff --git a/{} b/{}\n", name, name);
+        let old_name = match current {
+            Some(_) => format!("a/{}", name),
+            None => {
+                diff.push_str("new file mode 100644\n");
+                "/dev/null".to_string()
+            }
+        };
+        diff.push_str(
+            &TextDiff::from_lines(current.unwrap_or_default(), proposed)
+                .unified_diff()
+                .header(&old_name, &format!("b/{}", name))
+                .to_string(),
+        );
+        diff
+    }
+
+    /// Path as written in a patch: relative to the top of the working tree, which is
+    /// what `git apply` expects from any directory, with `/` separators
+    fn patch_path(path: &Path, root: &Path) -> String {
+        let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let relative = absolute.strip_prefix(root).unwrap_or(path);
+        relative
+            .components()
+            .filter(|c| !matches!(c, Component::CurDir))
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn print_diff(diff: &str) {
+        for line in diff.lines() {
+            if line.starts_with("diff ") || line.starts_with("+++") || line.starts_with("---") {
+                println!("{}", line.bold());
+            } else if line.starts_with("@@") {
+                println!("{}", line.cyan());
+            } else if line.starts_with('+') {
+                println!("{}", line.green());
+            } else if line.starts_with('-') {
+                println!("{}", line.red());
+            } else {
+                println!("{}", line);
+            }
+        }
     }
 
     async fn load_translations(path: &Path) -> anyhow::Result<HashMap<String, String>> {
         let content = fs::read_to_string(path).await?;
-        let translations: HashMap<String, String> = serde_json::from_str(&content)?;
-        Ok(translations)
+        Ok(LanguageFile::parse(&content)?.translations)
     }
 
     /// Creates a new filename with .i18n. prefix before the extension
@@ -130,12 +329,12 @@ impl ReplaceStringsUseCase {
         path: &Path,
         file_type: FileType,
     ) -> anyhow::Result<Vec<TranslationKeyWithPosition>> {
-        // Try to use SwcStringExtractor's extended method if available
-        // For now, convert from basic extraction
-        let basic_keys = extractor.extract(path, file_type).await?;
+        if let Some(keys) = extractor.extract_with_positions(path, file_type).await? {
+            return Ok(keys);
+        }
 
-        // Re-extract with positions using a temporary SwcStringExtractor instance
-        // This is a workaround until we can make the trait method available
+        // Extractor without positions: locate each quoted source string
+        let basic_keys = extractor.extract(path, file_type).await?;
         let content = fs::read_to_string(path).await?;
         let mut keys = Vec::new();
 
@@ -143,7 +342,7 @@ impl ReplaceStringsUseCase {
             // Find byte position of the source string
             if let Some(start_byte) = content.find(&format!("\"{}\"", basic_key.source)) {
                 let end_byte = start_byte + basic_key.source.len() + 2; // +2 for quotes
-                let line = content[..start_byte].lines().count();
+                let line = content[..start_byte].matches('\n').count() + 1;
 
                 keys.push(TranslationKeyWithPosition {
                     id: basic_key.id,
@@ -153,10 +352,11 @@ impl ReplaceStringsUseCase {
                     start_byte,
                     end_byte,
                     quote_type: QuoteType::Double,
+                    params: Vec::new(),
                 });
             } else if let Some(start_byte) = content.find(&format!("'{}'", basic_key.source)) {
                 let end_byte = start_byte + basic_key.source.len() + 2; // +2 for quotes
-                let line = content[..start_byte].lines().count();
+                let line = content[..start_byte].matches('\n').count() + 1;
 
                 keys.push(TranslationKeyWithPosition {
                     id: basic_key.id,
@@ -166,6 +366,7 @@ impl ReplaceStringsUseCase {
                     start_byte,
                     end_byte,
                     quote_type: QuoteType::Single,
+                    params: Vec::new(),
                 });
             }
         }
@@ -173,3 +374,267 @@ impl ReplaceStringsUseCase {
         Ok(keys)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_diff_is_a_git_patch() {
+        let cwd = std::env::current_dir().unwrap();
+        let diff = ReplaceStringsUseCase::file_diff(
+            Path::new("./src/App.tsx"),
+            &cwd,
+            Some("a\n<p>Hello</p>\nb\n"),
+            "a\n<p>{t(\"hello\")}</p>\nb\n",
+        );
+        assert_eq!(
+            diff,
+            "diff --git a/src/App.tsx b/src/App.tsx\n--- a/src/App.tsx\n+++ b/src/App.tsx\n@@ -1,3 +1,3 @@\n a\n-<p>Hello</p>\n+<p>{t(\"hello\")}</p>\n b\n"
+        );
+
+        let created =
+            ReplaceStringsUseCase::file_diff(Path::new("src/App.i18n.tsx"), &cwd, None, "x\n");
+        assert!(created.contains(
+            "new file mode 100644\n--- /dev/null\n+++ b/src/App.i18n.tsx\n@@ -0,0 +1 @@\n+x\n"
+        ));
+        assert_eq!(
+            ReplaceStringsUseCase::file_diff(Path::new("a.ts"), &cwd, Some("x"), "x"),
+            ""
+        );
+    }
+
+    /// Rejects any content with an unclosed `{`
+    struct BraceValidator;
+
+    impl SyntaxValidator for BraceValidator {
+        fn validate(&self, content: &str, _file_type: FileType) -> Option<SyntaxError> {
+            (content.matches('{').count() != content.matches('}').count()).then(|| SyntaxError {
+                line: 1,
+                column: 1,
+                message: "unclosed `{`".to_string(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_check_syntax() {
+        let check = |original, rewritten| {
+            ReplaceStringsUseCase::check_syntax(&BraceValidator, original, rewritten, FileType::JSX)
+        };
+        assert_eq!(
+            check("<p>Hi</p>", "<p>{t(\"hi\")}</p>"),
+            SyntaxCheck::Passed
+        );
+        assert!(matches!(
+            check("<p>Hi</p>", "<p>{t(\"hi\")</p>"),
+            SyntaxCheck::Broken(_)
+        ));
+        // The checker rejects the original too: skipped unless unchecked files are written
+        assert!(matches!(
+            check("<p>{Hi</p>", "<p>{{t(\"hi\")}</p>"),
+            SyntaxCheck::Unchecked(_)
+        ));
+    }
+
+    #[test]
+    fn test_patch_applies_from_a_subdirectory() {
+        let dir = std::env::temp_dir().join(format!("langsmith-patch-{}", std::process::id()));
+        let source = dir.join("web/src/App.tsx");
+        std::fs::create_dir_all(source.parent().unwrap()).unwrap();
+        std::fs::write(&source, "a\n<p>Hello</p>\nb\n").unwrap();
+        let git = |cwd: &Path, args: &[&str]| {
+            std::process::Command::new("git")
+                .current_dir(cwd)
+                .args(args)
+                .output()
+                .unwrap()
+        };
+        assert!(git(&dir, &["init", "-q"]).status.success());
+
+        let proposed = "a\n<p>{t(\"hello\")}</p>\nb\n";
+        let diff =
+            ReplaceStringsUseCase::file_diff(&source, &dir, Some("a\n<p>Hello</p>\nb\n"), proposed);
+        let patch = dir.join("replace.patch");
+        std::fs::write(&patch, diff).unwrap();
+
+        // `git apply` skips paths it cannot find rather than failing, so check by name
+        let web = dir.join("web");
+        let check = git(&web, &["apply", "--check", "-v", patch.to_str().unwrap()]);
+        assert!(check.status.success());
+        let log = String::from_utf8_lossy(&check.stderr);
+        assert!(log.contains("Checking patch web/src/App.tsx"), "{}", log);
+        assert!(!log.contains("Skipped"), "{}", log);
+
+        assert!(
+            git(&web, &["apply", patch.to_str().unwrap()])
+                .status
+                .success()
+        );
+        assert_eq!(std::fs::read_to_string(&source).unwrap(), proposed);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_extract_then_replace_keeps_the_directive_first() {
+        use crate::infrastructure::*;
+
+        let dir =
+            std::env::temp_dir().join(format!("langsmith-replace-e2e-{}", std::process::id()));
+        let src = dir.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        let page = src.join("Greeting.jsx");
+        std::fs::write(
+            &page,
+            "'use client';\n\nexport function Greeting() {\n  return <p>Welcome back</p>;\n}\n",
+        )
+        .unwrap();
+
+        // Extract: the directive is not a message
+        let keys = SwcStringExtractor
+            .extract(&page, FileType::JSX)
+            .await
+            .unwrap();
+        let catalog: HashMap<String, String> = keys.into_iter().map(|k| (k.id, k.source)).collect();
+        assert_eq!(catalog.keys().collect::<Vec<_>>(), vec!["welcome_back"]);
+        let catalog_path = dir.join("en.json");
+        std::fs::write(&catalog_path, serde_json::to_string(&catalog).unwrap()).unwrap();
+
+        let summary = ReplaceStringsUseCase::execute(
+            &src,
+            &catalog_path,
+            ReplacementStrategy::NextIntl { namespace: None },
+            false,
+            true,
+            None,
+            false,
+            &dir,
+            &GitGuard::new(None, GitSafety::default()),
+            &FileSystemScanner,
+            &SwcStringExtractor,
+            &SyntaxReplacer,
+            &ComponentHookInjector,
+            &SimpleImportManager,
+            &JsxSyntaxValidator,
+        )
+        .await
+        .unwrap();
+        assert_eq!(summary.strings, 1);
+        assert_eq!(
+            std::fs::read_to_string(&page).unwrap(),
+            "'use client';\nimport { useTranslations } from 'next-intl';\n\nexport function Greeting() {\n  const t = useTranslations();\n  return <p>{t(\"welcome_back\")}</p>;\n}\n"
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_patch_creates_the_i18n_copy_unless_in_place() {
+        use crate::infrastructure::*;
+
+        let dir = std::env::temp_dir().join(format!("langsmith-preview-{}", std::process::id()));
+        let src = dir.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        let original = "export function Greeting() {\n  return <p>Welcome back</p>;\n}\n";
+        let page = src.join("Greeting.jsx");
+        std::fs::write(&page, original).unwrap();
+        let catalog_path = dir.join("en.json");
+        std::fs::write(&catalog_path, r#"{"welcome_back": "Welcome back"}"#).unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .current_dir(&dir)
+                .args(args)
+                .output()
+                .unwrap()
+        };
+        assert!(git(&["init", "-q"]).status.success());
+        let repo = GitRepository::discover(&dir).await.unwrap().unwrap();
+
+        let patch = dir.join("replace.patch");
+        let summary = ReplaceStringsUseCase::execute(
+            &src,
+            &catalog_path,
+            ReplacementStrategy::ReactI18n,
+            false,
+            false,
+            Some(&patch),
+            false,
+            &dir,
+            &GitGuard::new(Some(&repo), GitSafety::default()),
+            &FileSystemScanner,
+            &SwcStringExtractor,
+            &SyntaxReplacer,
+            &ComponentHookInjector,
+            &SimpleImportManager,
+            &JsxSyntaxValidator,
+        )
+        .await
+        .unwrap();
+        assert_eq!(summary.strings, 1);
+
+        // The patch creates the copy the run would write; the source stays as it is
+        let diff = std::fs::read_to_string(&patch).unwrap();
+        assert!(
+            diff.starts_with("diff --git a/src/Greeting.i18n.jsx b/src/Greeting.i18n.jsx\nnew file mode 100644\n--- /dev/null\n"),
+            "{}",
+            diff
+        );
+        let copy = src.join("Greeting.i18n.jsx");
+        assert!(!copy.exists());
+        assert!(git(&["apply", "replace.patch"]).status.success());
+        assert!(
+            std::fs::read_to_string(&copy)
+                .unwrap()
+                .contains("{t(\"welcome_back\")}")
+        );
+        assert_eq!(std::fs::read_to_string(&page).unwrap(), original);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_unparsed_originals_are_only_written_on_request() {
+        use crate::infrastructure::*;
+
+        let dir = std::env::temp_dir().join(format!("langsmith-unchecked-{}", std::process::id()));
+        let src = dir.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        // The stray `)` already breaks the original
+        let original =
+            "export function Greeting() {\n  return <input placeholder=\"Welcome back\" />;\n})\n";
+        let page = src.join("Greeting.jsx");
+        let catalog_path = dir.join("en.json");
+        std::fs::write(&catalog_path, r#"{"welcome_back": "Welcome back"}"#).unwrap();
+
+        for write_unchecked in [false, true] {
+            std::fs::write(&page, original).unwrap();
+            let summary = ReplaceStringsUseCase::execute(
+                &src,
+                &catalog_path,
+                ReplacementStrategy::ReactI18n,
+                false,
+                true,
+                None,
+                write_unchecked,
+                &dir,
+                &GitGuard::new(None, GitSafety::default()),
+                &FileSystemScanner,
+                &SwcStringExtractor,
+                &SyntaxReplacer,
+                &ComponentHookInjector,
+                &SimpleImportManager,
+                &JsxSyntaxValidator,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(summary.unchecked.len(), 1);
+            assert_eq!(summary.files, usize::from(write_unchecked));
+            let written = std::fs::read_to_string(&page).unwrap();
+            assert_eq!(written == original, !write_unchecked);
+        }
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
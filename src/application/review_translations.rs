@@ -0,0 +1,157 @@
+use super::translate_keys::TranslateKeysUseCase;
+use crate::domain::locale::Locale;
+use crate::domain::review::{Origin, ReviewState, ReviewStatus};
+use std::path::{Path, PathBuf};
+
+/// Use case: Track and approve machine translations
+pub struct ReviewTranslationsUseCase;
+
+/// A key of a target catalog with its review status
+#[derive(Debug, Clone)]
+pub struct ReviewEntry {
+    pub key: String,
+    pub source: String,
+    pub value: String,
+    pub status: ReviewStatus,
+}
+
+impl ReviewTranslationsUseCase {
+    /// Keys of the source catalog that have a value in the target catalog, sorted by key
+    pub fn entries(source_file: &Path, lang: &str) -> anyhow::Result<Vec<ReviewEntry>> {
+        let (lang, target_file) = Self::target_catalog(source_file, lang)?;
+        let source = TranslateKeysUseCase::load_existing(source_file)?;
+        let target = TranslateKeysUseCase::load_existing(&target_file)?;
+        let state = Self::load_state(source_file)?;
+
+        let mut entries: Vec<ReviewEntry> = source
+            .into_iter()
+            .filter_map(|(key, source)| {
+                let value = target.get(&key)?.clone();
+                let status = state.status(&lang, &key, &source, &value);
+                Some(ReviewEntry {
+                    key,
+                    source,
+                    value,
+                    status,
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(entries)
+    }
+
+    /// Mark keys as reviewed with their current source text and value
+    ///
+    /// Fails without changing anything if a key is missing from either catalog.
+    pub fn approve(source_file: &Path, lang: &str, keys: &[String]) -> anyhow::Result<usize> {
+        let (lang, target_file) = Self::target_catalog(source_file, lang)?;
+        let source = TranslateKeysUseCase::load_existing(source_file)?;
+        let target = TranslateKeysUseCase::load_existing(&target_file)?;
+
+        let unknown: Vec<&str> = keys
+            .iter()
+            .filter(|key| !source.contains_key(*key) || !target.contains_key(*key))
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Not in {} or {}: {}",
+                source_file.display(),
+                target_file.display(),
+                unknown.join(", ")
+            ));
+        }
+
+        let mut state = Self::load_state(source_file)?;
+        for key in keys {
+            state.record(&lang, key, Origin::Reviewed, &source[key], &target[key]);
+        }
+        Self::save_state(source_file, &state)?;
+
+        Ok(keys.len())
+    }
+
+    /// Load the review state kept next to a catalog; missing means nothing tracked yet
+    pub fn load_state(catalog: &Path) -> anyhow::Result<ReviewState> {
+        let path = ReviewState::path_for(catalog.parent().unwrap_or(Path::new(".")));
+        if !path.exists() {
+            return Ok(ReviewState::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Invalid review state {}: {}", path.display(), e))
+    }
+
+    pub fn save_state(catalog: &Path, state: &ReviewState) -> anyhow::Result<()> {
+        let path = ReviewState::path_for(catalog.parent().unwrap_or(Path::new(".")));
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(state)?)?;
+        Ok(())
+    }
+
+    /// Canonical tag and path of the target catalog next to the source
+    fn target_catalog(source_file: &Path, lang: &str) -> anyhow::Result<(String, PathBuf)> {
+        let tag = Locale::parse(lang)?.tag();
+        let path = source_file.with_file_name(format!("{}.json", tag));
+        if !path.exists() {
+            return Err(anyhow::anyhow!(
+                "No {} catalog at {}, run translate first",
+                tag,
+                path.display()
+            ));
+        }
+        Ok((tag, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approve_and_list() {
+        let dir = std::env::temp_dir().join(format!("langsmith-review-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("en.json");
+        std::fs::write(&source, r#"{"save": "Save", "open": "Open"}"#).unwrap();
+        std::fs::write(
+            dir.join("de.json"),
+            r#"{"save": "Speichern", "open": "Öffnen"}"#,
+        )
+        .unwrap();
+
+        let mut state = ReviewState::default();
+        state.record("de", "save", Origin::Machine, "Save", "Speichern");
+        state.record("de", "open", Origin::Machine, "Open", "Offnen");
+        ReviewTranslationsUseCase::save_state(&source, &state).unwrap();
+
+        let statuses = |lang| {
+            ReviewTranslationsUseCase::entries(&source, lang)
+                .unwrap()
+                .into_iter()
+                .map(|e| (e.key, e.status))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            statuses("de"),
+            vec![
+                ("open".to_string(), ReviewStatus::Edited),
+                ("save".to_string(), ReviewStatus::Machine),
+            ]
+        );
+
+        let approved =
+            ReviewTranslationsUseCase::approve(&source, "de", &["save".to_string()]).unwrap();
+        assert_eq!(approved, 1);
+        assert_eq!(statuses("de")[1].1, ReviewStatus::Reviewed);
+
+        assert!(
+            ReviewTranslationsUseCase::approve(&source, "de", &["missing".to_string()]).is_err()
+        );
+        assert!(ReviewTranslationsUseCase::entries(&source, "fr").is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,70 +1,1430 @@
-use crate::domain::models::LanguageFile;
+use crate::domain::i18next::{PluralGroup, plural_groups};
+use crate::domain::icu::{Expansion, Message, Variant, is_icu_message};
+use crate::domain::locale::Locale;
+use crate::domain::models::{
+    Glossary, GlossaryViolation, KeyContext, LanguageFile, Quota, TranslationRequest,
+    TranslationStyle, TranslationUnit, UsageEstimate,
+};
+use crate::domain::plural::PluralRules;
 use crate::domain::ports::Translator;
-use std::collections::HashMap;
-use std::path::Path;
+use crate::domain::quality::QualityCheck;
+use crate::domain::review::{Origin, ReviewState, ReviewStatus};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use super::review_translations::ReviewTranslationsUseCase;
 
 /// Use case: Translate extracted strings to target languages
 pub struct TranslateKeysUseCase;
 
+/// Settings for a translation run
+#[derive(Debug, Clone)]
+pub struct TranslateOptions {
+    /// Language of the source catalog; derived from its filename when None
+    pub source_lang: Option<String>,
+    pub glossary: Option<Glossary>,
+    /// Key -> where it is used, forwarded to translators
+    pub contexts: HashMap<String, KeyContext>,
+    /// Keys sent per request (1 = one request per key)
+    pub batch_size: usize,
+    /// Keep translations already in the target catalogs and only send missing keys
+    pub incremental: bool,
+    /// Stop once this many source characters have been sent
+    pub max_chars: Option<usize>,
+    /// Target language -> formality and tone
+    pub styles: HashMap<String, TranslationStyle>,
+    /// Back-translate new translations into the source language and flag suspicious ones
+    pub verify: bool,
+}
+
+impl Default for TranslateOptions {
+    fn default() -> Self {
+        Self {
+            source_lang: None,
+            glossary: None,
+            contexts: HashMap::new(),
+            batch_size: 1,
+            incremental: false,
+            max_chars: None,
+            styles: HashMap::new(),
+            verify: false,
+        }
+    }
+}
+
+/// Outcome of a translation run
+#[derive(Debug, Default)]
+pub struct TranslationReport {
+    pub total: usize,
+    pub successful: usize,
+    pub failed: usize,
+    /// Keys kept from an existing catalog in incremental mode
+    pub reused: usize,
+    /// Keys kept because they were reviewed or edited by hand
+    pub protected: usize,
+    /// Keys left untranslated because the character budget ran out
+    pub skipped: usize,
+    /// Source characters sent to the provider
+    pub characters: usize,
+    pub budget_exhausted: bool,
+    pub languages: Vec<String>,
+    pub glossary_violations: Vec<GlossaryViolation>,
+    /// Keys checked by back-translation (`verify`)
+    pub verified: usize,
+    /// Verified keys with at least one quality issue
+    pub flagged: Vec<QualityCheck>,
+}
+
+/// Expected usage for one target language
+#[derive(Debug, Clone)]
+pub struct LanguageEstimate {
+    pub target: String,
+    /// Provider that would translate this language; None when it reports no cost model
+    pub provider: Option<String>,
+    /// Keys that would be sent
+    pub keys: usize,
+    /// Keys sharing the translation of an identical string
+    pub cached: usize,
+    /// Keys already present in the target catalog (incremental mode)
+    pub reused: usize,
+    pub usage: UsageEstimate,
+}
+
+/// Outcome of `--estimate`: expected usage per language and provider quotas
+#[derive(Debug, Default)]
+pub struct CostEstimate {
+    pub languages: Vec<LanguageEstimate>,
+    pub quotas: Vec<Quota>,
+}
+
+impl CostEstimate {
+    pub fn total_characters(&self) -> usize {
+        self.languages.iter().map(|l| l.usage.characters).sum()
+    }
+
+    /// Total USD, or None when any language has unknown pricing
+    pub fn total_cost(&self) -> Option<f64> {
+        self.languages.iter().map(|l| l.usage.cost).sum()
+    }
+}
+
+/// Work for one target language, after incremental and duplicate filtering
+struct LanguagePlan {
+    target: Locale,
+    output_file: PathBuf,
+    /// Entries written without a request: short strings and reused keys
+    kept: LanguageFile,
+    /// Strings too short to translate, copied as-is
+    short: usize,
+    reused: usize,
+    /// Reviewed or hand-edited values, never overwritten
+    protected: usize,
+    /// One unit per distinct text and context
+    units: Vec<TranslationUnit>,
+    /// Unit key -> other keys with the same text and context
+    duplicates: HashMap<String, Vec<String>>,
+    /// Plural/select messages split into one unit per form: (key, source, expansion)
+    plurals: Vec<(String, String, Expansion)>,
+    /// Unit key of a form -> its message key and variant index
+    plural_forms: HashMap<String, (String, usize)>,
+    /// ICU messages that could not be split, sent whole; the result must still parse
+    unsplit: HashSet<String>,
+    /// Plural messages stored as i18next `_one`/`_other` keys, by base key
+    i18next: HashMap<String, PluralGroup>,
+}
+
+impl LanguagePlan {
+    fn cached(&self) -> usize {
+        self.duplicates.values().map(Vec::len).sum()
+    }
+
+    fn keys_for<'a>(&'a self, unit: &'a TranslationUnit) -> impl Iterator<Item = &'a String> {
+        std::iter::once(&unit.key).chain(self.duplicates.get(&unit.key).into_iter().flatten())
+    }
+
+    /// Catalog entries of a translated plural message as (key, source, translation)
+    ///
+    /// i18next messages get one key per plural category of the target.
+    fn plural_entries(
+        &self,
+        key: &str,
+        source: &str,
+        message: &Message,
+    ) -> anyhow::Result<Vec<(String, String, String)>> {
+        let Some(source_group) = self.i18next.get(key) else {
+            return Ok(vec![(
+                key.to_string(),
+                source.to_string(),
+                message.to_string(),
+            )]);
+        };
+        let translated = PluralGroup::from_message(key, message)?;
+        Ok(translated
+            .forms
+            .iter()
+            .map(|(category, text)| {
+                (
+                    translated.key(*category),
+                    source_group.source_for(*category).to_string(),
+                    text.clone(),
+                )
+            })
+            .collect())
+    }
+
+    /// Source text written in place of a plural message that failed, as (key, text)
+    fn plural_fallback(&self, key: &str, source: &str) -> Vec<(String, String)> {
+        match self.i18next.get(key) {
+            Some(group) => PluralRules::for_locale(&self.target)
+                .categories()
+                .into_iter()
+                .map(|category| (group.key(category), group.source_for(category).to_string()))
+                .collect(),
+            None => vec![(key.to_string(), source.to_string())],
+        }
+    }
+
+    /// Catalog keys covered by units; plural forms are counted with their message
+    fn catalog_keys<'a>(&'a self, units: impl Iterator<Item = &'a TranslationUnit>) -> usize {
+        units
+            .filter(|u| !self.plural_forms.contains_key(&u.key))
+            .map(|u| self.keys_for(u).count())
+            .sum()
+    }
+}
+
 impl TranslateKeysUseCase {
     pub async fn execute(
         source_file: &Path,
         target_langs: &[&str],
         translator: &dyn Translator,
-    ) -> anyhow::Result<()> {
-        // 1. Load source language file
-        let source_content = std::fs::read_to_string(source_file)?;
-        let source_file_obj: HashMap<String, String> = serde_json::from_str(&source_content)?;
+        options: &TranslateOptions,
+    ) -> anyhow::Result<TranslationReport> {
+        let mut state = ReviewTranslationsUseCase::load_state(source_file)?;
+        let (source_lang, total, plans) =
+            Self::plan(source_file, target_langs, translator, &state, options)?;
+        if options.verify && source_lang.is_none() {
+            return Err(anyhow::anyhow!(
+                "Verification needs the source language, pass it with --from"
+            ));
+        }
 
-        tracing::info!("Loaded {} strings from source", source_file_obj.len());
+        let mut report = TranslationReport::default();
+
+        // Translate to each target language
+        for plan in plans {
+            report.total += total;
+            report.reused += plan.reused;
+            report.protected += plan.protected;
+            report.successful += plan.short;
 
-        // 2. Translate to each target language
-        for target_lang in target_langs {
+            if report.budget_exhausted {
+                report.skipped += plan.catalog_keys(plan.units.iter()) + plan.plurals.len();
+                continue;
+            }
+
+            let target_lang = plan.target.tag();
             tracing::info!("Translating to {}", target_lang);
+            let request = TranslationRequest::new(source_lang.clone(), plan.target.clone())
+                .with_style(Self::style_for(&options.styles, &plan.target));
+            let mut translated = plan.kept.clone();
+            // Successful translations of this run, checked afterwards with --verify
+            let mut new_translations: Vec<(&TranslationUnit, String)> = Vec::new();
+            // Message key -> translated plural forms by variant index
+            let mut forms: HashMap<&str, HashMap<usize, Option<String>>> = HashMap::new();
 
-            let mut translated = LanguageFile::new();
+            let mut stopped = false;
+            let mut chunks = plan.units.chunks(options.batch_size.max(1));
+            for chunk in chunks.by_ref() {
+                let chunk_chars = Self::characters(chunk);
+                if options
+                    .max_chars
+                    .is_some_and(|max| report.characters + chunk_chars > max)
+                {
+                    tracing::warn!("Character budget reached, stopping before {}", chunk[0].key);
+                    report.budget_exhausted = true;
+                    report.skipped += plan.catalog_keys(chunk.iter());
+                    stopped = true;
+                    break;
+                }
+                report.characters += chunk_chars;
 
-            for (key, value) in &source_file_obj {
-                // Skip very short strings
-                if value.len() < 2 {
-                    translated.insert(key.clone(), value.clone());
-                    continue;
+                let results = Self::translate_chunk(translator, chunk, &request).await;
+
+                for (unit, result) in chunk.iter().zip(results) {
+                    let result = result.and_then(|text| {
+                        if plan.unsplit.contains(&unit.key) {
+                            Message::parse(&text)?;
+                        }
+                        Ok(text)
+                    });
+                    match &result {
+                        Ok(translated_text) => {
+                            if let Some(glossary) = &options.glossary {
+                                report.glossary_violations.extend(glossary.check(
+                                    &unit.key,
+                                    &unit.text,
+                                    translated_text,
+                                    &target_lang,
+                                ));
+                            }
+                            tracing::debug!("✓ {}: {}", unit.key, unit.text);
+                            new_translations.push((unit, translated_text.clone()));
+                        }
+                        Err(e) => tracing::warn!("✗ Failed to translate {}: {}", unit.key, e),
+                    }
+
+                    // Plural forms are put back together once every chunk is done
+                    if let Some((key, index)) = plan.plural_forms.get(&unit.key) {
+                        forms
+                            .entry(key.as_str())
+                            .or_default()
+                            .insert(*index, result.ok());
+                        continue;
+                    }
+
+                    let (text, origin) = match result {
+                        Ok(translated_text) => {
+                            report.successful += plan.keys_for(unit).count();
+                            (translated_text, Some(Origin::Machine))
+                        }
+                        Err(_) => {
+                            // Fallback to source
+                            report.failed += plan.keys_for(unit).count();
+                            (unit.text.clone(), None)
+                        }
+                    };
+
+                    for key in plan.keys_for(unit) {
+                        match origin {
+                            Some(origin) => {
+                                state.record(&target_lang, key, origin, &unit.text, &text)
+                            }
+                            None => state.forget(&target_lang, key),
+                        }
+                        translated.insert(key.clone(), text.clone());
+                    }
                 }
+            }
 
-                // Translate
-                match translator.translate(value, target_lang).await {
-                    Ok(translated_text) => {
-                        translated.insert(key.clone(), translated_text);
-                        tracing::debug!("✓ {}: {}", key, value);
+            // Keys past the budget stay out of the catalog so an incremental run picks them up
+            report.skipped += plan.catalog_keys(chunks.flatten());
+
+            for (key, source, expansion) in &plan.plurals {
+                let Some(forms) = forms
+                    .get(key.as_str())
+                    .filter(|forms| forms.len() == expansion.variants.len())
+                else {
+                    report.skipped += 1;
+                    continue;
+                };
+                let assembled = (0..expansion.variants.len())
+                    .map(|index| forms[&index].clone())
+                    .collect::<Option<Vec<String>>>()
+                    .ok_or_else(|| anyhow::anyhow!("a plural form could not be translated"))
+                    .and_then(|translations| expansion.assemble(&translations))
+                    .and_then(|message| plan.plural_entries(key, source, &message));
+
+                match assembled {
+                    Ok(entries) => {
+                        report.successful += 1;
+                        for (entry_key, entry_source, text) in entries {
+                            state.record(
+                                &target_lang,
+                                &entry_key,
+                                Origin::Machine,
+                                &entry_source,
+                                &text,
+                            );
+                            translated.insert(entry_key, text);
+                        }
                     }
                     Err(e) => {
                         tracing::warn!("✗ Failed to translate {}: {}", key, e);
-                        // Fallback to source
-                        translated.insert(key.clone(), value.clone());
+                        report.failed += 1;
+                        for (entry_key, text) in plan.plural_fallback(key, source) {
+                            state.forget(&target_lang, &entry_key);
+                            translated.insert(entry_key, text);
+                        }
                     }
                 }
+            }
 
-                // Rate limiting: small delay to avoid API throttling
-                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            if options.verify {
+                Self::verify(
+                    translator,
+                    &plan,
+                    &new_translations,
+                    &request,
+                    options,
+                    &mut report,
+                )
+                .await;
             }
 
-            // 3. Write translated file
-            let output_file = source_file
-                .parent()
-                .ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "Cannot extract parent directory from path: {}",
-                        source_file.display()
-                    )
-                })?
-                .join(format!("{}.json", target_lang));
+            // A run stopped by the budget keeps what earlier runs translated for the keys
+            // it did not reach; only a complete run drops entries
+            if stopped {
+                for (key, value) in Self::load_existing(&plan.output_file)? {
+                    translated.translations.entry(key).or_insert(value);
+                }
+            }
 
+            // Write translated file
             let json = serde_json::to_string_pretty(&translated.translations)?;
-            std::fs::write(&output_file, json)?;
+            std::fs::write(&plan.output_file, json)?;
+
+            state.retain_keys(&target_lang, |key| {
+                translated.translations.contains_key(key)
+            });
+            ReviewTranslationsUseCase::save_state(source_file, &state)?;
+
+            tracing::info!("Written {}", plan.output_file.display());
+            report.languages.push(target_lang);
+        }
+
+        Ok(report)
+    }
+
+    /// Estimate characters, tokens and cost of a run without translating anything
+    pub async fn estimate(
+        source_file: &Path,
+        target_langs: &[&str],
+        translator: &dyn Translator,
+        options: &TranslateOptions,
+    ) -> anyhow::Result<CostEstimate> {
+        let state = ReviewTranslationsUseCase::load_state(source_file)?;
+        let (_, _, plans) = Self::plan(source_file, target_langs, translator, &state, options)?;
+
+        let languages = plans
+            .iter()
+            .map(|plan| {
+                // Back-translations are assumed to be as long as the source
+                let passes = if options.verify { 2 } else { 1 };
+                let characters = Self::characters(&plan.units) * passes;
+                let requests = plan.units.len().div_ceil(options.batch_size.max(1)) * passes;
+                let cost_model = translator.cost_model(&plan.target);
+
+                LanguageEstimate {
+                    target: plan.target.tag(),
+                    provider: cost_model.as_ref().map(|m| m.provider.clone()),
+                    keys: plan.units.len(),
+                    cached: plan.cached(),
+                    reused: plan.reused + plan.protected,
+                    usage: cost_model
+                        .map(|model| model.estimate(characters, requests))
+                        .unwrap_or(UsageEstimate {
+                            characters,
+                            ..Default::default()
+                        }),
+                }
+            })
+            .collect();
+
+        let quotas = translator.quota().await.unwrap_or_else(|e| {
+            tracing::warn!("Could not fetch provider usage: {}", e);
+            Vec::new()
+        });
+
+        Ok(CostEstimate { languages, quotas })
+    }
+
+    /// Load the source catalog and work out what each target language needs
+    ///
+    /// Returns the source locale, the number of source keys, and one plan per target.
+    fn plan(
+        source_file: &Path,
+        target_langs: &[&str],
+        translator: &dyn Translator,
+        state: &ReviewState,
+        options: &TranslateOptions,
+    ) -> anyhow::Result<(Option<Locale>, usize, Vec<LanguagePlan>)> {
+        let source_content = std::fs::read_to_string(source_file)?;
+        let source_file_obj = LanguageFile::parse(&source_content)?.translations;
+
+        tracing::info!("Loaded {} strings from source", source_file_obj.len());
+
+        let source_lang = match &options.source_lang {
+            Some(tag) => Some(Locale::parse(tag)?),
+            None => Self::source_lang_from_path(source_file),
+        };
+        match &source_lang {
+            Some(locale) => tracing::info!("Source language: {}", locale),
+            None => tracing::warn!(
+                "Could not derive source language from {}, providers will auto-detect",
+                source_file.display()
+            ),
+        }
+
+        // Validate every target before any request is made
+        let targets = Self::validate_targets(target_langs, source_lang.as_ref(), translator)?;
+
+        let output_dir = source_file.parent().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot extract parent directory from path: {}",
+                source_file.display()
+            )
+        })?;
+
+        // i18next plural forms ("items_one", "items_other") are translated as one message
+        let groups = plural_groups(&source_file_obj);
+        let grouped: HashSet<String> = groups
+            .iter()
+            .flat_map(|group| group.forms.keys().map(|category| group.key(*category)))
+            .collect();
+
+        // Stable order keeps batches reproducible between runs
+        let mut entries: Vec<(&String, &String)> = source_file_obj
+            .iter()
+            .filter(|(key, _)| !grouped.contains(*key))
+            .collect();
+        entries.sort();
 
-            tracing::info!("Written {}", output_file.display());
+        // Plural/select messages are checked up front so every invalid one is reported at once
+        let mut messages = HashMap::new();
+        let mut invalid = Vec::new();
+        for (key, value) in &entries {
+            if is_icu_message(value) {
+                match Message::parse(value) {
+                    Ok(message) => {
+                        messages.insert(key.as_str(), message);
+                    }
+                    Err(e) => invalid.push(format!("{}: {}", key, e)),
+                }
+            }
+        }
+        if !invalid.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid ICU messages in {}: {}",
+                source_file.display(),
+                invalid.join("; ")
+            ));
         }
 
-        Ok(())
+        let mut plans = Vec::new();
+        for target in targets {
+            let tag = target.tag();
+            let output_file = output_dir.join(format!("{}.json", tag));
+            // Tracked catalogs are always read so reviewed and edited values survive
+            let existing = if options.incremental || state.languages.contains_key(&tag) {
+                Self::load_existing(&output_file)?
+            } else {
+                HashMap::new()
+            };
+
+            let mut plan = LanguagePlan {
+                target,
+                output_file,
+                kept: LanguageFile::new(),
+                short: 0,
+                reused: 0,
+                protected: 0,
+                units: Vec::new(),
+                duplicates: HashMap::new(),
+                plurals: Vec::new(),
+                plural_forms: HashMap::new(),
+                unsplit: HashSet::new(),
+                i18next: HashMap::new(),
+            };
+            let mut seen: HashMap<(&str, KeyContext), String> = HashMap::new();
+
+            for (key, value) in &entries {
+                // Skip very short strings
+                if value.len() < 2 {
+                    plan.kept.insert((*key).clone(), (*value).clone());
+                    plan.short += 1;
+                    continue;
+                }
+
+                if let Some(existing) = existing.get(*key).filter(|t| !t.trim().is_empty())
+                    && Self::keep_existing(
+                        &mut plan,
+                        state,
+                        options,
+                        &[((*key).clone(), value.as_str(), existing.as_str())],
+                    )
+                {
+                    continue;
+                }
+
+                let context = options.contexts.get(*key).cloned().unwrap_or_default();
+                if let Some(message) = messages.get(key.as_str()) {
+                    Self::plan_message(
+                        &mut plan,
+                        source_lang.as_ref(),
+                        key,
+                        value,
+                        message,
+                        context,
+                    );
+                    continue;
+                }
+                match seen.get(&(value.as_str(), context.clone())) {
+                    Some(first_key) => plan
+                        .duplicates
+                        .entry(first_key.clone())
+                        .or_default()
+                        .push((*key).clone()),
+                    None => {
+                        seen.insert((value.as_str(), context.clone()), (*key).clone());
+                        plan.units.push(TranslationUnit {
+                            key: (*key).clone(),
+                            text: (*value).clone(),
+                            context,
+                        });
+                    }
+                }
+            }
+
+            for group in &groups {
+                let forms: Option<Vec<(String, &str, &str)>> =
+                    PluralRules::for_locale(&plan.target)
+                        .categories()
+                        .into_iter()
+                        .map(|category| {
+                            let key = group.key(category);
+                            let value = existing.get(&key).filter(|t| !t.trim().is_empty())?;
+                            Some((key, group.source_for(category), value.as_str()))
+                        })
+                        .collect();
+                if forms.is_some_and(|forms| Self::keep_existing(&mut plan, state, options, &forms))
+                {
+                    continue;
+                }
+
+                let context = options
+                    .contexts
+                    .get(&group.base)
+                    .cloned()
+                    .unwrap_or_default();
+                let message = group.to_message();
+                Self::plan_message(
+                    &mut plan,
+                    source_lang.as_ref(),
+                    &group.base,
+                    &message.to_string(),
+                    &message,
+                    context,
+                );
+                plan.i18next.insert(group.base.clone(), group.clone());
+            }
+
+            plans.push(plan);
+        }
+
+        Ok((source_lang, entries.len() + groups.len(), plans))
+    }
+
+    /// Keep existing target values that were reviewed, edited, or are current (incremental)
+    ///
+    /// `forms` are (key, source, existing value) of one message; all are kept or none.
+    fn keep_existing(
+        plan: &mut LanguagePlan,
+        state: &ReviewState,
+        options: &TranslateOptions,
+        forms: &[(String, &str, &str)],
+    ) -> bool {
+        let tag = plan.target.tag();
+        if forms
+            .iter()
+            .any(|(key, _, value)| state.is_protected(&tag, key, value))
+        {
+            plan.protected += 1;
+        } else if options.incremental
+            // Stale machine translations are sent again
+            && forms.iter().all(|(key, source, value)| {
+                state.status(&tag, key, source, value) != ReviewStatus::Stale
+            })
+        {
+            plan.reused += 1;
+        } else {
+            return false;
+        }
+
+        for (key, _, value) in forms {
+            plan.kept.insert(key.clone(), value.to_string());
+        }
+        true
+    }
+
+    /// Split a plural/select message into one unit per form of the target language
+    ///
+    /// Messages that cannot be split (ordinals, several plurals) are sent whole.
+    fn plan_message(
+        plan: &mut LanguagePlan,
+        source_lang: Option<&Locale>,
+        key: &str,
+        value: &str,
+        message: &Message,
+        context: KeyContext,
+    ) {
+        let expansion = match message.expand(source_lang, &plan.target) {
+            Ok(expansion) => expansion,
+            Err(e) => {
+                tracing::debug!("Sending {} as a whole message: {}", key, e);
+                plan.unsplit.insert(key.to_string());
+                plan.units.push(TranslationUnit {
+                    key: key.to_string(),
+                    text: value.to_string(),
+                    context,
+                });
+                return;
+            }
+        };
+
+        for (index, variant) in expansion.variants.iter().enumerate() {
+            let form_key = format!("{}#{}", key, variant.selectors.join("/"));
+            plan.plural_forms
+                .insert(form_key.clone(), (key.to_string(), index));
+            plan.units.push(TranslationUnit {
+                key: form_key,
+                text: variant.text.clone(),
+                context: Self::form_context(&context, variant),
+            });
+        }
+        plan.plurals
+            .push((key.to_string(), value.to_string(), expansion));
+    }
+
+    /// Context of one form, telling the translator which number or option it is for
+    fn form_context(context: &KeyContext, variant: &Variant) -> KeyContext {
+        let exact = variant.selectors.iter().any(|s| s.starts_with('='));
+        let note = match &variant.number {
+            Some(number) if exact => {
+                format!(
+                    "One form of a plural message, used when the count is {}",
+                    number
+                )
+            }
+            Some(number) => format!(
+                "One form of a plural message, used for counts like {}; keep the number",
+                number
+            ),
+            None => format!(
+                "Variant '{}' of a select message",
+                variant.selectors.join("/")
+            ),
+        };
+        KeyContext {
+            comment: Some(match &context.comment {
+                Some(comment) => format!("{}. {}", comment.trim_end_matches('.'), note),
+                None => note,
+            }),
+            ..context.clone()
+        }
+    }
+
+    /// Style for a target: its own entry first, then the language's ("de-AT" -> "de")
+    fn style_for(styles: &HashMap<String, TranslationStyle>, target: &Locale) -> TranslationStyle {
+        let find = |matches: &dyn Fn(&Locale) -> bool| {
+            styles
+                .iter()
+                .find(|(lang, _)| Locale::parse(lang).is_ok_and(|locale| matches(&locale)))
+                .map(|(_, style)| style.clone())
+        };
+
+        find(&|locale| locale == target)
+            .or_else(|| {
+                find(&|locale| {
+                    locale.language == target.language
+                        && locale.script.is_none()
+                        && locale.region.is_none()
+                })
+            })
+            .unwrap_or_default()
+    }
+
+    /// Read an existing catalog; a missing file is an empty catalog
+    pub fn load_existing(path: &Path) -> anyhow::Result<HashMap<String, String>> {
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+        let content = std::fs::read_to_string(path)?;
+        LanguageFile::parse(&content)
+            .map(|file| file.translations)
+            .map_err(|e| anyhow::anyhow!("Invalid translation file {}: {}", path.display(), e))
+    }
+
+    /// Source characters in a set of units, as billed by character-based providers
+    fn characters(units: &[TranslationUnit]) -> usize {
+        units.iter().map(|u| u.text.chars().count()).sum()
+    }
+
+    /// Translate new translations back into the source language and score them
+    ///
+    /// Back-translations count towards --max-chars; verification stops when the budget runs out.
+    async fn verify(
+        translator: &dyn Translator,
+        plan: &LanguagePlan,
+        translations: &[(&TranslationUnit, String)],
+        request: &TranslationRequest,
+        options: &TranslateOptions,
+        report: &mut TranslationReport,
+    ) {
+        let Some(source) = request.source.clone() else {
+            return;
+        };
+        if !translator.supports_language(&source) {
+            tracing::warn!(
+                "Cannot back-translate into {}, skipping verification",
+                source
+            );
+            return;
+        }
+        let back_request = TranslationRequest::new(Some(request.target.clone()), source);
+        let target_lang = request.target.tag();
+
+        for chunk in translations.chunks(options.batch_size.max(1)) {
+            let back_units: Vec<TranslationUnit> = chunk
+                .iter()
+                .map(|(unit, translation)| TranslationUnit {
+                    key: unit.key.clone(),
+                    text: translation.clone(),
+                    context: unit.context.clone(),
+                })
+                .collect();
+
+            let chunk_chars = Self::characters(&back_units);
+            if options
+                .max_chars
+                .is_some_and(|max| report.characters + chunk_chars > max)
+            {
+                tracing::warn!(
+                    "Character budget reached, verification of {} stopped",
+                    target_lang
+                );
+                break;
+            }
+            report.characters += chunk_chars;
+
+            let results = Self::translate_chunk(translator, &back_units, &back_request).await;
+            for ((unit, translation), result) in chunk.iter().zip(results) {
+                let check = QualityCheck::new(
+                    &unit.key,
+                    &target_lang,
+                    &unit.text,
+                    translation,
+                    result.ok(),
+                );
+                report.verified += plan.keys_for(unit).count();
+                if check.is_flagged() {
+                    report
+                        .flagged
+                        .extend(plan.keys_for(unit).map(|key| QualityCheck {
+                            key: key.clone(),
+                            ..check.clone()
+                        }));
+                }
+            }
+        }
+    }
+
+    /// Translate a chunk of units, one result per unit in the same order
+    ///
+    /// Multi-key chunks go through `translate_batch`; if the batch fails or
+    /// comes back incomplete, each key is retried on its own.
+    async fn translate_chunk(
+        translator: &dyn Translator,
+        chunk: &[TranslationUnit],
+        req: &TranslationRequest,
+    ) -> Vec<anyhow::Result<String>> {
+        if chunk.len() > 1 {
+            match translator.translate_batch(chunk, req).await {
+                Ok(mut translations) if chunk.iter().all(|u| translations.contains_key(&u.key)) => {
+                    Self::throttle(translator).await;
+                    return chunk
+                        .iter()
+                        .map(|u| Ok(translations.remove(&u.key).unwrap_or_default()))
+                        .collect();
+                }
+                Ok(_) => {
+                    tracing::warn!("Batch response incomplete, retrying keys one by one");
+                }
+                Err(e) => {
+                    tracing::warn!("Batch translation failed ({}), retrying keys one by one", e);
+                }
+            }
+        }
+
+        let mut results = Vec::with_capacity(chunk.len());
+        for unit in chunk {
+            let req = req.clone().with_context(&unit.context);
+            results.push(translator.translate(&unit.text, &req).await);
+            Self::throttle(translator).await;
+        }
+        results
+    }
+
+    /// Parse target tags and check them against the source and the provider
+    ///
+    /// All problems are reported at once so a run never stops halfway through.
+    fn validate_targets(
+        target_langs: &[&str],
+        source_lang: Option<&Locale>,
+        translator: &dyn Translator,
+    ) -> anyhow::Result<Vec<Locale>> {
+        let mut targets = Vec::new();
+        let mut problems = Vec::new();
+
+        for tag in target_langs {
+            let target = match Locale::parse(tag) {
+                Ok(target) => target,
+                Err(e) => {
+                    problems.push(e.to_string());
+                    continue;
+                }
+            };
+
+            // Refused so a typo does not overwrite the source catalog
+            if TranslationRequest::new(source_lang.cloned(), target.clone()).is_same_language() {
+                problems.push(format!("{} is the source language", target));
+            } else if !translator.supports_language(&target) {
+                problems.push(format!("{} is not supported by the provider", target));
+            } else if targets.contains(&target) {
+                tracing::warn!("Duplicate target language {}, skipping", target);
+            } else {
+                targets.push(target);
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid target languages: {}",
+                problems.join("; ")
+            ));
+        }
+
+        Ok(targets)
+    }
+
+    /// Derive the source locale from a catalog filename, e.g. "i18n/en.json" -> "en"
+    ///
+    /// Only stems starting with a two-letter language count, so names like
+    /// "app.json" or "messages.json" are not mistaken for locales.
+    fn source_lang_from_path(source_file: &Path) -> Option<Locale> {
+        let stem = source_file.file_stem()?.to_str()?;
+        Locale::parse(stem)
+            .ok()
+            .filter(|locale| locale.language.len() == 2)
+    }
+
+    /// Rate limiting: small delay to avoid API throttling
+    async fn throttle(translator: &dyn Translator) {
+        let delay = translator.rate_limit();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::CostModel;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Uppercases text; batch requests drop the last key to force a retry
+    struct UppercaseTranslator {
+        batch_calls: AtomicUsize,
+        single_calls: AtomicUsize,
+        /// Context comment of every single-key request
+        comments: std::sync::Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl Translator for UppercaseTranslator {
+        async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+            self.single_calls.fetch_add(1, Ordering::SeqCst);
+            self.comments
+                .lock()
+                .unwrap()
+                .push(req.context.as_ref().and_then(|c| c.comment.clone()));
+            Ok(text.to_uppercase())
+        }
+
+        async fn translate_batch(
+            &self,
+            units: &[TranslationUnit],
+            _langs: &TranslationRequest,
+        ) -> anyhow::Result<HashMap<String, String>> {
+            self.batch_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(units
+                .iter()
+                .take(units.len() - 1)
+                .map(|u| (u.key.clone(), u.text.to_uppercase()))
+                .collect())
+        }
+
+        fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+            Some(CostModel::characters("upper", 20.0))
+        }
+    }
+
+    impl UppercaseTranslator {
+        fn new() -> Self {
+            Self {
+                batch_calls: AtomicUsize::new(0),
+                single_calls: AtomicUsize::new(0),
+                comments: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    /// Temp dir with an en.json source catalog
+    fn catalog(name: &str, source: &str) -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("langsmith-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let source_file = dir.join("en.json");
+        std::fs::write(&source_file, source).unwrap();
+        (dir, source_file)
+    }
+
+    fn read_catalog(path: &Path) -> HashMap<String, String> {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_incomplete_batch_falls_back_to_single_keys() {
+        let (dir, source) = catalog(
+            "translate",
+            r#"{"save": "Save", "cancel": "Cancel", "ok": "O"}"#,
+        );
+
+        let translator = UppercaseTranslator::new();
+        let options = TranslateOptions {
+            batch_size: 10,
+            ..Default::default()
+        };
+
+        let report = TranslateKeysUseCase::execute(&source, &["de"], &translator, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(report.successful, 3);
+        assert_eq!(translator.batch_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(translator.single_calls.load(Ordering::SeqCst), 2);
+
+        let written = read_catalog(&dir.join("de.json"));
+        assert_eq!(written["save"], "SAVE");
+        assert_eq!(written["ok"], "O");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_source_lang_from_path() {
+        let lang = |path: &str| {
+            TranslateKeysUseCase::source_lang_from_path(Path::new(path)).map(|l| l.tag())
+        };
+        assert_eq!(lang("i18n/en.json").as_deref(), Some("en"));
+        assert_eq!(lang("locales/pt_BR.json").as_deref(), Some("pt-BR"));
+        assert_eq!(lang("locales/zh-hant.json").as_deref(), Some("zh-Hant"));
+        assert_eq!(lang("i18n/app.json"), None);
+        assert_eq!(lang("i18n/messages.json"), None);
+        assert_eq!(lang("i18n/translations-v2.json"), None);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_source_language_as_target() {
+        let (dir, source) = catalog("same-lang", r#"{"save": "Save"}"#);
+
+        let translator = UppercaseTranslator::new();
+        let err = TranslateKeysUseCase::execute(
+            &source,
+            &["de", "EN"],
+            &translator,
+            &TranslateOptions::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.to_string().contains("source language"));
+        assert_eq!(translator.single_calls.load(Ordering::SeqCst), 0);
+        assert!(!dir.join("de.json").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Only translates into German
+    struct GermanOnlyTranslator;
+
+    #[async_trait]
+    impl Translator for GermanOnlyTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            _langs: &TranslationRequest,
+        ) -> anyhow::Result<String> {
+            Ok(text.to_string())
+        }
+
+        fn supports_language(&self, target: &Locale) -> bool {
+            target.language == "de"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_all_invalid_targets_before_translating() {
+        let (dir, source) = catalog("targets", r#"{"save": "Save"}"#);
+
+        let err = TranslateKeysUseCase::execute(
+            &source,
+            &["de", "vi", "german"],
+            &GermanOnlyTranslator,
+            &TranslateOptions::default(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(err.contains("vi is not supported"));
+        assert!(err.contains("Invalid locale 'german'"));
+        assert!(!dir.join("de.json").exists());
+
+        let report = TranslateKeysUseCase::execute(
+            &source,
+            &["de_at"],
+            &GermanOnlyTranslator,
+            &TranslateOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(report.languages, vec!["de-AT"]);
+        assert!(dir.join("de-AT.json").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_incremental_reuses_existing_and_dedupes() {
+        let (dir, source) = catalog(
+            "incremental",
+            r#"{"a": "Hello", "b": "Hello", "c": "World", "d": "Save"}"#,
+        );
+        std::fs::write(dir.join("de.json"), r#"{"d": "Speichern", "old": "Alt"}"#).unwrap();
+
+        let translator = UppercaseTranslator::new();
+        let options = TranslateOptions {
+            incremental: true,
+            ..Default::default()
+        };
+        let report = TranslateKeysUseCase::execute(&source, &["de"], &translator, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(translator.single_calls.load(Ordering::SeqCst), 2);
+        assert_eq!(report.reused, 1);
+        assert_eq!(report.successful, 3);
+        assert_eq!(report.characters, 10);
+
+        let written = read_catalog(&dir.join("de.json"));
+        assert_eq!(written["a"], "HELLO");
+        assert_eq!(written["b"], "HELLO");
+        assert_eq!(written["d"], "Speichern");
+        assert!(!written.contains_key("old"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_formatjs_source_catalog() {
+        let (dir, source) = catalog(
+            "formatjs",
+            r#"{"save": {"defaultMessage": "Save", "description": "Form button"}, "quit": "Quit"}"#,
+        );
+
+        let translator = UppercaseTranslator::new();
+        TranslateKeysUseCase::execute(&source, &["de"], &translator, &Default::default())
+            .await
+            .unwrap();
+
+        let written = read_catalog(&dir.join("de.json"));
+        assert_eq!(written["save"], "SAVE");
+        assert_eq!(written["quit"], "QUIT");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_plural_messages_use_target_categories() {
+        let (dir, source) = catalog(
+            "plural",
+            r#"{"items": "{count, plural, one {# item} other {# items}}", "save": "Save"}"#,
+        );
+
+        let translator = UppercaseTranslator::new();
+        let report =
+            TranslateKeysUseCase::execute(&source, &["ru"], &translator, &Default::default())
+                .await
+                .unwrap();
+
+        // one, few, many and other forms plus "save"
+        assert_eq!(translator.single_calls.load(Ordering::SeqCst), 5);
+        assert_eq!(report.successful, 2);
+        let written = read_catalog(&dir.join("ru.json"));
+        assert_eq!(
+            written["items"],
+            "{count, plural, one {# ITEM} few {# ITEMS} many {# ITEMS} other {# ITEMS}}"
+        );
+
+        std::fs::write(
+            &source,
+            r#"{"a": "{n, plural, one {x}}", "b": "{n, select,}"}"#,
+        )
+        .unwrap();
+        let error =
+            TranslateKeysUseCase::execute(&source, &["ru"], &translator, &Default::default())
+                .await
+                .unwrap_err()
+                .to_string();
+        assert!(error.contains("a: ") && error.contains("b: "), "{}", error);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_i18next_plural_keys_get_target_suffixes() {
+        let (dir, source) = catalog(
+            "i18next",
+            r#"{"files_one": "{{count}} file", "files_other": "{{count}} files"}"#,
+        );
+
+        let translator = UppercaseTranslator::new();
+        let report =
+            TranslateKeysUseCase::execute(&source, &["pl"], &translator, &Default::default())
+                .await
+                .unwrap();
+
+        assert_eq!(report.total, 1);
+        assert_eq!(report.successful, 1);
+        let written = read_catalog(&dir.join("pl.json"));
+        assert_eq!(written.len(), 4);
+        assert_eq!(written["files_one"], "{{count}} FILE");
+        assert_eq!(written["files_few"], "{{count}} FILES");
+        assert_eq!(written["files_many"], "{{count}} FILES");
+
+        let options = TranslateOptions {
+            incremental: true,
+            ..Default::default()
+        };
+        let report = TranslateKeysUseCase::execute(&source, &["pl"], &translator, &options)
+            .await
+            .unwrap();
+        assert_eq!(report.reused, 1);
+        assert_eq!(report.characters, 0);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_max_chars_stops_cleanly() {
+        let (dir, source) = catalog("budget", r#"{"a": "Hello", "b": "World"}"#);
+
+        let translator = UppercaseTranslator::new();
+        let options = TranslateOptions {
+            max_chars: Some(7),
+            ..Default::default()
+        };
+        let report = TranslateKeysUseCase::execute(&source, &["de", "fr"], &translator, &options)
+            .await
+            .unwrap();
+
+        assert!(report.budget_exhausted);
+        assert_eq!(report.characters, 5);
+        assert_eq!(report.skipped, 3);
+        assert_eq!(report.languages, vec!["de"]);
+
+        let written = read_catalog(&dir.join("de.json"));
+        assert_eq!(written.len(), 1);
+        assert_eq!(written["a"], "HELLO");
+        assert!(!dir.join("fr.json").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_max_chars_keeps_earlier_translations() {
+        let (dir, source) = catalog("budget-merge", r#"{"a": "Hello", "b": "World"}"#);
+        std::fs::write(dir.join("de.json"), r#"{"a": "Hallo", "b": "Welt"}"#).unwrap();
+
+        let translator = UppercaseTranslator::new();
+        let options = TranslateOptions {
+            max_chars: Some(7),
+            ..Default::default()
+        };
+        let report = TranslateKeysUseCase::execute(&source, &["de"], &translator, &options)
+            .await
+            .unwrap();
+        assert!(report.budget_exhausted);
+
+        // "a" is translated again, "b" from the earlier run survives
+        let written = read_catalog(&dir.join("de.json"));
+        assert_eq!(written["a"], "HELLO");
+        assert_eq!(written["b"], "Welt");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_estimate_counts_pending_characters() {
+        let (dir, source) = catalog("estimate", r#"{"a": "Hello", "b": "Hello", "c": "World"}"#);
+
+        let translator = UppercaseTranslator::new();
+        let estimate = TranslateKeysUseCase::estimate(
+            &source,
+            &["de", "fr"],
+            &translator,
+            &TranslateOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(translator.single_calls.load(Ordering::SeqCst), 0);
+        assert!(!dir.join("de.json").exists());
+
+        let de = &estimate.languages[0];
+        assert_eq!(de.provider.as_deref(), Some("upper"));
+        assert_eq!((de.keys, de.cached), (2, 1));
+        assert_eq!(de.usage.characters, 10);
+        assert_eq!(estimate.total_characters(), 20);
+        assert!((estimate.total_cost().unwrap() - 0.0004).abs() < 1e-9);
+
+        // 400 chars in 2 requests: 100 text tokens, 240 prompt tokens, 100 completion tokens
+        let usage = CostModel::tokens("llm", Some((1.0, 2.0))).estimate(400, 2);
+        assert_eq!(usage.tokens, Some(440));
+        assert!((usage.cost.unwrap() - 0.00054).abs() < 1e-9);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_single_keys_carry_context_in_request() {
+        let (dir, source) = catalog("context", r#"{"save": "Save", "title": "Welcome"}"#);
+        let translator = UppercaseTranslator::new();
+        let options = TranslateOptions {
+            contexts: HashMap::from([(
+                "save".to_string(),
+                KeyContext {
+                    comment: Some("Button label".to_string()),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+
+        let report = TranslateKeysUseCase::execute(&source, &["de"], &translator, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(report.successful, 2);
+        // A batch size of 1 never goes through translate_batch
+        assert_eq!(translator.batch_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(translator.single_calls.load(Ordering::SeqCst), 2);
+        let comments = translator.comments.lock().unwrap();
+        assert!(comments.contains(&Some("Button label".to_string())));
+        assert!(comments.contains(&None));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_reviewed_and_edited_values_survive() {
+        let (dir, source) = catalog(
+            "review-state",
+            r#"{"a": "Open", "b": "Save", "c": "Close", "d": "Delete all"}"#,
+        );
+        std::fs::write(
+            dir.join("de.json"),
+            r#"{"a": "Öffnen", "b": "Sichern", "c": "CLOSE", "d": "DELETE"}"#,
+        )
+        .unwrap();
+
+        let mut state = ReviewState::default();
+        state.record("de", "a", Origin::Reviewed, "Open", "Öffnen");
+        state.record("de", "b", Origin::Machine, "Save", "SAVE");
+        state.record("de", "c", Origin::Machine, "Close", "CLOSE");
+        state.record("de", "d", Origin::Machine, "Delete", "DELETE");
+        ReviewTranslationsUseCase::save_state(&source, &state).unwrap();
+
+        let translator = UppercaseTranslator::new();
+        let options = TranslateOptions {
+            incremental: true,
+            ..Default::default()
+        };
+        let report = TranslateKeysUseCase::execute(&source, &["de"], &translator, &options)
+            .await
+            .unwrap();
+
+        // a reviewed, b edited by hand, c unchanged machine output, d stale
+        assert_eq!(report.protected, 2);
+        assert_eq!(report.reused, 1);
+        let written = read_catalog(&dir.join("de.json"));
+        assert_eq!(written["a"], "Öffnen");
+        assert_eq!(written["b"], "Sichern");
+        assert_eq!(written["d"], "DELETE ALL");
+
+        let state = ReviewTranslationsUseCase::load_state(&source).unwrap();
+        assert_eq!(
+            state.status("de", "d", "Delete all", "DELETE ALL"),
+            ReviewStatus::Machine
+        );
+        assert_eq!(
+            state.status("de", "b", "Save", "Sichern"),
+            ReviewStatus::Edited
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Looks texts up in a fixed table, in both directions
+    struct DictionaryTranslator(HashMap<&'static str, &'static str>);
+
+    #[async_trait]
+    impl Translator for DictionaryTranslator {
+        async fn translate(&self, text: &str, _req: &TranslationRequest) -> anyhow::Result<String> {
+            self.0
+                .get(text)
+                .map(|t| t.to_string())
+                .ok_or_else(|| anyhow::anyhow!("unknown text {}", text))
+        }
+
+        fn rate_limit(&self) -> std::time::Duration {
+            std::time::Duration::ZERO
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_flags_suspicious_translations() {
+        let (dir, source) = catalog(
+            "verify",
+            r#"{"save": "Save changes", "delete": "Delete your account"}"#,
+        );
+        let translator = DictionaryTranslator(HashMap::from([
+            ("Save changes", "Änderungen speichern"),
+            ("Änderungen speichern", "Save changes"),
+            ("Delete your account", "Konto"),
+            ("Konto", "Account"),
+        ]));
+        let options = TranslateOptions {
+            verify: true,
+            ..Default::default()
+        };
+
+        let report = TranslateKeysUseCase::execute(&source, &["de"], &translator, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(report.verified, 2);
+        assert_eq!(report.flagged.len(), 1);
+        assert_eq!(report.flagged[0].key, "delete");
+        assert_eq!(
+            report.flagged[0].back_translation.as_deref(),
+            Some("Account")
+        );
+        // Both directions are billed
+        assert_eq!(report.characters, 31 + 20 + 5);
+
+        std::fs::remove_dir_all(&dir).ok();
     }
 }
@@ -0,0 +1,173 @@
+use super::journal::restore;
+use crate::domain::journal::{Journal, content_hash};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+pub struct UndoReplaceUseCase;
+
+impl UndoReplaceUseCase {
+    /// Restore the files of the last journaled run and drop its journal
+    ///
+    /// # Errors
+    /// Refuses when a file was changed after the run wrote it, so edits made since are not lost
+    pub async fn execute(project_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let run_dir = Self::last_run(project_dir)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Nothing to undo: no journaled run found"))?;
+        let content = fs::read_to_string(run_dir.join(Journal::FILE_NAME)).await?;
+        let journal: Journal = serde_json::from_str(&content)?;
+
+        // Files the run wrote; those already back to their original state are skipped
+        let mut to_restore = Vec::new();
+        let mut changed = Vec::new();
+        for entry in &journal.files {
+            let current = fs::read(&entry.path).await.ok();
+            let current_hash = current.as_deref().map(content_hash);
+            if current_hash.as_ref() == Some(&entry.written_hash) {
+                to_restore.push(entry);
+            } else if current_hash != entry.original_hash {
+                changed.push(entry.path.display().to_string());
+            }
+        }
+
+        if !changed.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Files changed since the last `{}` run, not undoing: {}",
+                journal.command,
+                changed.join(", ")
+            ));
+        }
+
+        for entry in &to_restore {
+            restore(&run_dir, entry).await?;
+        }
+        fs::remove_dir_all(&run_dir).await?;
+
+        Ok(to_restore.iter().map(|e| e.path.clone()).collect())
+    }
+
+    /// Most recent run directory; names are zero-padded timestamps
+    async fn last_run(project_dir: &Path) -> anyhow::Result<Option<PathBuf>> {
+        let root = Journal::root(project_dir);
+        if !root.is_dir() {
+            return Ok(None);
+        }
+
+        let mut runs = Vec::new();
+        let mut entries = fs::read_dir(&root).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.path().join(Journal::FILE_NAME).is_file() {
+                runs.push(entry.path());
+            }
+        }
+        runs.sort();
+        Ok(runs.pop())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::journal::JournaledWriter;
+
+    #[tokio::test]
+    async fn test_undo_restores_last_run() {
+        let dir = std::env::temp_dir().join(format!("langsmith-undo-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let app = dir.join("App.jsx");
+        let created = dir.join("App.i18n.jsx");
+        std::fs::write(&app, "<p>Hello</p>").unwrap();
+
+        let mut writer = JournaledWriter::begin(&dir, "replace").await.unwrap();
+        writer.write(&app, "<p>{t(\"hello\")}</p>").await.unwrap();
+        writer.write(&created, "new").await.unwrap();
+        writer.finish().await.unwrap();
+
+        let restored = UndoReplaceUseCase::execute(&dir).await.unwrap();
+        assert_eq!(restored.len(), 2);
+        assert_eq!(std::fs::read_to_string(&app).unwrap(), "<p>Hello</p>");
+        assert!(!created.exists());
+        assert!(UndoReplaceUseCase::execute(&dir).await.is_err());
+
+        // Edited after the run: refuse rather than lose the edit
+        let mut writer = JournaledWriter::begin(&dir, "replace").await.unwrap();
+        writer.write(&app, "<p>{t(\"hello\")}</p>").await.unwrap();
+        writer.finish().await.unwrap();
+        std::fs::write(&app, "<p>{t(\"hello\")}!</p>").unwrap();
+        let err = UndoReplaceUseCase::execute(&dir).await.unwrap_err();
+        assert!(err.to_string().contains("App.jsx"), "{}", err);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_undo_from_another_directory_of_the_repository() {
+        use crate::domain::ports::VersionControl;
+        use crate::infrastructure::GitRepository;
+
+        let dir = std::env::temp_dir().join(format!("langsmith-undo-cwd-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::create_dir_all(dir.join("docs")).unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .arg("-C")
+                .arg(&dir)
+                .args(args)
+                .output()
+                .unwrap()
+        };
+        assert!(git(&["init", "-q"]).status.success());
+        let app = dir.join("src/App.jsx");
+        std::fs::write(&app, "<p>Hello</p>").unwrap();
+
+        // `replace` run from src/, `undo` from docs/
+        let from_src = GitRepository::discover(&dir.join("src"))
+            .await
+            .unwrap()
+            .unwrap();
+        let mut writer = JournaledWriter::begin(from_src.root(), "replace")
+            .await
+            .unwrap();
+        writer.write(&app, "<p>{t(\"hello\")}</p>").await.unwrap();
+        writer.finish().await.unwrap();
+
+        // The journal and its backups stay out of git
+        let status = String::from_utf8_lossy(&git(&["status", "--porcelain"]).stdout).to_string();
+        assert!(!status.contains(".langsmith"), "{}", status);
+
+        let from_docs = GitRepository::discover(&dir.join("docs"))
+            .await
+            .unwrap()
+            .unwrap();
+        let restored = UndoReplaceUseCase::execute(from_docs.root()).await.unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(std::fs::read_to_string(&app).unwrap(), "<p>Hello</p>");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_only_the_last_runs_are_kept() {
+        let dir = std::env::temp_dir().join(format!("langsmith-undo-keep-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let app = dir.join("App.jsx");
+
+        for run in 0..Journal::KEPT_RUNS + 2 {
+            let mut writer = JournaledWriter::begin(&dir, "replace").await.unwrap();
+            writer.write(&app, &run.to_string()).await.unwrap();
+            writer.finish().await.unwrap();
+            // Runs are named by the millisecond they start
+            tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        }
+
+        let runs = std::fs::read_dir(Journal::root(&dir)).unwrap().count();
+        assert_eq!(runs, Journal::KEPT_RUNS);
+        UndoReplaceUseCase::execute(&dir).await.unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&app).unwrap(),
+            Journal::KEPT_RUNS.to_string()
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,5 +1,6 @@
 use crate::application::ExtractStringsUseCase;
 use crate::cli::presenter::Presenter;
+use crate::domain::models::{CatalogFormat, PluralFormat};
 use crate::infrastructure::{FileSystemScanner, FileSystemWriter, SwcStringExtractor};
 use clap::Parser;
 use std::path::PathBuf;
@@ -17,6 +18,14 @@ pub struct ExtractCmd {
     /// Base language code
     #[arg(short, long, default_value = "fr")]
     pub lang: String,
+
+    /// How plural messages are written (icu, i18next)
+    #[arg(long, value_name = "FORMAT", default_value = "icu")]
+    pub plural_format: String,
+
+    /// How the catalog is written (flat, formatjs for react-intl's extracted messages)
+    #[arg(long, value_name = "FORMAT", default_value = "flat")]
+    pub catalog_format: String,
 }
 
 impl ExtractCmd {
@@ -37,6 +46,8 @@ impl ExtractCmd {
         Presenter::info(format!("Scanning: {:?}", self.source));
         Presenter::info(format!("Output: {:?}", self.output));
         Presenter::info(format!("Base language: {}", self.lang));
+        let plural_format = PluralFormat::from_str(&self.plural_format)?;
+        let catalog_format = CatalogFormat::from_str(&self.catalog_format)?;
 
         // Initialize infrastructure
         let scanner = FileSystemScanner;
@@ -48,6 +59,8 @@ impl ExtractCmd {
             &self.source,
             &self.output,
             &self.lang,
+            plural_format,
+            catalog_format,
             &scanner,
             &extractor,
             &writer,
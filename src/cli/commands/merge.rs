@@ -1,4 +1,8 @@
+use crate::application::GitGuard;
 use crate::application::merge_i18n::MergeI18nUseCase;
+use crate::domain::models::GitSafety;
+use crate::domain::ports::VersionControl;
+use crate::infrastructure::GitRepository;
 use clap::Parser;
 use owo_colors::OwoColorize;
 use std::path::PathBuf;
@@ -12,6 +16,18 @@ pub struct MergeCmd {
     /// Actually perform the merge (without this, only shows preview)
     #[arg(long)]
     pub confirm: bool,
+
+    /// Run even if the files to rewrite have uncommitted git changes
+    #[arg(long)]
+    pub force: bool,
+
+    /// Create and switch to this git branch before writing
+    #[arg(long, value_name = "NAME")]
+    pub branch: Option<String>,
+
+    /// Commit the rewritten files, and only them, afterwards
+    #[arg(long)]
+    pub commit: bool,
 }
 
 impl MergeCmd {
@@ -49,7 +65,16 @@ impl MergeCmd {
             "⏳".cyan(),
             summary.files_to_merge.len()
         );
-        let result = MergeI18nUseCase::execute_merge(&summary).await?;
+        let repo = GitRepository::discover(&self.directory).await?;
+        let git = GitGuard::new(
+            repo.as_ref().map(|r| r as &dyn VersionControl),
+            GitSafety {
+                force: self.force,
+                branch: self.branch.clone(),
+                commit: self.commit,
+            },
+        );
+        let result = MergeI18nUseCase::execute_merge(&summary, &git).await?;
 
         // Show results
         println!("\n  {} Merge Complete!", "✓".green());
@@ -1,12 +1,15 @@
 pub mod extract;
 pub mod merge;
 pub mod replace;
+pub mod review;
 pub mod setup;
 pub mod translate;
+pub mod undo;
 
 use clap::Subcommand;
 
 #[derive(Subcommand, Debug)]
+#[allow(clippy::large_enum_variant)] // parsed once at startup
 pub enum Command {
     /// Interactive setup wizard (recommended for first-time users)
     Setup(setup::SetupCmd),
@@ -14,8 +17,12 @@ pub enum Command {
     Extract(extract::ExtractCmd),
     /// Translate extracted strings to target languages
     Translate(translate::TranslateCmd),
+    /// List machine, reviewed and stale translations and approve them
+    Review(review::ReviewCmd),
     /// Replace hardcoded strings with translation function calls
     Replace(replace::ReplaceCmd),
     /// Merge .i18n.* files back to original files
     Merge(merge::MergeCmd),
+    /// Restore the files written by the last replace run
+    Undo(undo::UndoCmd),
 }
@@ -1,5 +1,7 @@
+use crate::application::GitGuard;
 use crate::application::replace_strings::ReplaceStringsUseCase;
-use crate::domain::models::ReplacementStrategy;
+use crate::domain::models::{GitSafety, ReplacementStrategy};
+use crate::domain::ports::VersionControl;
 use crate::infrastructure::*;
 use clap::Parser;
 use std::path::PathBuf;
@@ -14,17 +16,51 @@ pub struct ReplaceCmd {
     #[arg(short, long, value_name = "FILE")]
     pub translations: PathBuf,
 
-    /// Replacement strategy (react-i18n, vue-i18n, generic)
+    /// Replacement strategy (react-i18n, vue-i18n, generic, react-intl, next-intl, lingui, or a [strategies.<name>] entry)
     #[arg(short, long, default_value = "react-i18n")]
     pub strategy: String,
 
+    /// With react-intl, collect each file's messages in a `defineMessages` block
+    #[arg(long)]
+    pub define_messages: bool,
+
+    /// With next-intl, the namespace passed to useTranslations; keys are relative to it
+    #[arg(long, value_name = "NAME")]
+    pub namespace: Option<String>,
+
+    /// Project config file (defaults to ./langsmith.toml if present)
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
     /// Preview changes without writing files
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Write the proposed changes to a patch file for `git apply`: new .i18n.* files, or
+    /// edits of the originals with --in-place; like --dry-run, nothing else is written
+    #[arg(long, value_name = "FILE")]
+    pub diff_output: Option<PathBuf>,
+
+    /// Also rewrite files that do not parse before replacement, whose rewrite cannot be
+    /// checked; they are skipped and reported otherwise
+    #[arg(long)]
+    pub write_unchecked: bool,
+
     /// Replace in original files (destructive - replaces instead of creating .i18n.* files)
     #[arg(long)]
     pub in_place: bool,
+
+    /// Run even if the files to rewrite have uncommitted git changes
+    #[arg(long)]
+    pub force: bool,
+
+    /// Create and switch to this git branch before writing
+    #[arg(long, value_name = "NAME")]
+    pub branch: Option<String>,
+
+    /// Commit the rewritten files, and only them, afterwards
+    #[arg(long)]
+    pub commit: bool,
 }
 
 impl ReplaceCmd {
@@ -42,27 +78,104 @@ impl ReplaceCmd {
             println!("  ⚠ IN-PLACE MODE - Original files will be modified\n");
         }
 
-        let strategy = ReplacementStrategy::from_str(&self.strategy)?;
+        let project_config = ConfigManager::load_project_config(self.config.as_deref())?;
+        let mut strategy = project_config.strategy(&self.strategy)?;
+        if self.define_messages {
+            match &mut strategy {
+                ReplacementStrategy::ReactIntl { define_messages } => *define_messages = true,
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "--define-messages needs the react-intl strategy"
+                    ));
+                }
+            }
+        }
+        if let Some(name) = &self.namespace {
+            match &mut strategy {
+                ReplacementStrategy::NextIntl { namespace } => *namespace = Some(name.clone()),
+                _ => return Err(anyhow::anyhow!("--namespace needs the next-intl strategy")),
+            }
+        }
 
         let scanner = FileSystemScanner;
         let extractor = SwcStringExtractor;
-        let replacer = RegexReplacer;
+        let replacer = SyntaxReplacer;
+        let hook_injector = ComponentHookInjector;
         let import_mgr = SimpleImportManager;
+        let validator = JsxSyntaxValidator;
+        let repo = GitRepository::discover(&self.source).await?;
+        // The journal sits at the top of the project, so `undo` finds it from any directory
+        let project_dir = match &repo {
+            Some(repo) => repo.root().to_path_buf(),
+            None => ConfigManager::project_dir(self.config.as_deref()),
+        };
+        let git = GitGuard::new(
+            repo.as_ref().map(|r| r as &dyn VersionControl),
+            GitSafety {
+                force: self.force,
+                branch: self.branch.clone(),
+                commit: self.commit,
+            },
+        );
 
-        ReplaceStringsUseCase::execute(
+        let summary = ReplaceStringsUseCase::execute(
             &self.source,
             &self.translations,
             strategy,
             self.dry_run,
             self.in_place,
+            self.diff_output.as_deref(),
+            self.write_unchecked,
+            &project_dir,
+            &git,
             &scanner,
             &extractor,
             &replacer,
+            &hook_injector,
             &import_mgr,
+            &validator,
         )
         .await?;
 
-        println!("\n✓ Replacement complete!");
+        println!(
+            "\n✓ Replacement complete! {} strings in {} files",
+            summary.strings, summary.files
+        );
+        if !summary.invalid.is_empty() {
+            println!(
+                "✗ {} files left unmodified, the rewritten code did not parse:",
+                summary.invalid.len()
+            );
+            for (path, error) in &summary.invalid {
+                println!("  {}:{}", path.display(), error);
+            }
+        }
+        if !summary.unreachable.is_empty() {
+            println!(
+                "⚠ {} strings left as they are, the strategy has no translation function there (outside components):",
+                summary.unreachable.len()
+            );
+            for (path, line, key) in &summary.unreachable {
+                println!("  {}:{} {}", path.display(), line, key);
+            }
+        }
+        if !summary.unchecked.is_empty() && self.write_unchecked {
+            println!(
+                "⚠ {} files rewritten without the syntax check, the original did not pass it; review them:",
+                summary.unchecked.len()
+            );
+            for (path, error) in &summary.unchecked {
+                println!("  {}:{}", path.display(), error);
+            }
+        } else if !summary.unchecked.is_empty() {
+            println!(
+                "✗ {} files left unmodified, the original did not parse so the rewrite could not be checked (--write-unchecked rewrites them anyway):",
+                summary.unchecked.len()
+            );
+            for (path, error) in &summary.unchecked {
+                println!("  {}:{}", path.display(), error);
+            }
+        }
 
         Ok(())
     }
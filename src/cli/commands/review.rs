@@ -0,0 +1,151 @@
+use crate::application::{ReviewEntry, ReviewTranslationsUseCase};
+use crate::cli::presenter::Presenter;
+use crate::domain::review::ReviewStatus;
+use clap::Parser;
+use dialoguer::Select;
+use owo_colors::OwoColorize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+pub struct ReviewCmd {
+    /// Path to source translation file (e.g., i18n/fr.json)
+    #[arg(value_name = "FILE")]
+    pub source: PathBuf,
+
+    /// Target language to review (e.g., de)
+    #[arg(short, long, value_name = "LANG")]
+    pub lang: String,
+
+    /// Mark these keys as reviewed (comma-separated)
+    #[arg(long, value_name = "KEYS", conflicts_with_all = ["approve_all", "interactive"])]
+    pub approve: Option<String>,
+
+    /// Mark every machine or edited key as reviewed (stale keys must be approved by name)
+    #[arg(long, conflicts_with = "interactive")]
+    pub approve_all: bool,
+
+    /// Step through keys needing review and approve them one by one
+    #[arg(short, long)]
+    pub interactive: bool,
+
+    /// List reviewed and untracked keys too
+    #[arg(long)]
+    pub all: bool,
+}
+
+impl ReviewCmd {
+    pub async fn run(self) -> anyhow::Result<()> {
+        Presenter::header("🔍 Langsmith - Translation Review");
+
+        if !self.source.is_file() {
+            Presenter::error(format!("Source file not found: {:?}", self.source));
+            return Err(anyhow::anyhow!("Source file not found"));
+        }
+
+        let entries = ReviewTranslationsUseCase::entries(&self.source, &self.lang)?;
+
+        let keys: Vec<String> = if let Some(keys) = &self.approve {
+            keys.split(',')
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty())
+                .collect()
+        } else if self.approve_all {
+            entries
+                .iter()
+                .filter(|e| matches!(e.status, ReviewStatus::Machine | ReviewStatus::Edited))
+                .map(|e| e.key.clone())
+                .collect()
+        } else if self.interactive {
+            Self::step_through(&entries)?
+        } else {
+            self.print(&entries);
+            return Ok(());
+        };
+
+        if keys.is_empty() {
+            Presenter::info("Nothing to approve");
+            return Ok(());
+        }
+
+        let approved = ReviewTranslationsUseCase::approve(&self.source, &self.lang, &keys)?;
+        Presenter::success(format!("Marked {} keys as reviewed", approved));
+        Ok(())
+    }
+
+    fn print(&self, entries: &[ReviewEntry]) {
+        let mut counts: BTreeMap<ReviewStatus, usize> = BTreeMap::new();
+        for entry in entries {
+            *counts.entry(entry.status).or_default() += 1;
+        }
+
+        println!(
+            "{}",
+            format!("{} ({} keys)", self.lang, entries.len()).bold()
+        );
+        for (status, count) in &counts {
+            println!("  {:<10} {}", status.label(), count);
+        }
+        println!();
+
+        for entry in entries
+            .iter()
+            .filter(|e| self.all || e.status.needs_review())
+        {
+            let label = match entry.status {
+                ReviewStatus::Stale => entry.status.label().red().to_string(),
+                ReviewStatus::Machine | ReviewStatus::Edited => {
+                    entry.status.label().yellow().to_string()
+                }
+                _ => entry.status.label().green().to_string(),
+            };
+            println!("  [{}] {}", label, entry.key.bold());
+            Presenter::table_row("    source", &entry.source);
+            Presenter::table_row("    value", &entry.value);
+        }
+
+        let pending = counts
+            .iter()
+            .filter(|(status, _)| status.needs_review())
+            .map(|(_, count)| count)
+            .sum::<usize>();
+        if pending > 0 {
+            Presenter::info(format!(
+                "{} keys need review; approve with --approve <keys>, --approve-all or --interactive",
+                pending
+            ));
+        }
+    }
+
+    /// Ask about each key needing review; returns the approved keys
+    fn step_through(entries: &[ReviewEntry]) -> anyhow::Result<Vec<String>> {
+        let pending: Vec<&ReviewEntry> =
+            entries.iter().filter(|e| e.status.needs_review()).collect();
+        let mut approved = Vec::new();
+
+        for (i, entry) in pending.iter().enumerate() {
+            println!(
+                "\n{} [{}] {}",
+                format!("{}/{}", i + 1, pending.len()).dimmed(),
+                entry.status.label(),
+                entry.key.bold()
+            );
+            Presenter::table_row("  source", &entry.source);
+            Presenter::table_row("  value", &entry.value);
+
+            let choice = Select::new()
+                .with_prompt("Approve this translation?")
+                .items(&["Approve", "Skip", "Stop reviewing"])
+                .default(0)
+                .interact()?;
+
+            match choice {
+                0 => approved.push(entry.key.clone()),
+                1 => {}
+                _ => break,
+            }
+        }
+
+        Ok(approved)
+    }
+}
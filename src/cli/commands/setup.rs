@@ -31,6 +31,13 @@ impl SetupCmd {
             source: config.source_dir.clone(),
             output: config.output_dir.clone(),
             lang: "fr".to_string(),
+            // react-i18next expects its own `_one`/`_other` plural keys
+            plural_format: match config.framework {
+                Framework::React => "i18next",
+                _ => "icu",
+            }
+            .to_string(),
+            catalog_format: "flat".to_string(),
         };
 
         extract_cmd.run().await?;
@@ -40,18 +47,37 @@ impl SetupCmd {
         if !matches!(config.api_choice, TranslationApi::Skip) {
             println!("\n{}", "Step 2/4: Translating...".bold());
 
-            let to_langs = config.target_languages.join(",");
+            // Extraction writes French; translating into it again would be refused
+            let to_langs = config
+                .target_languages
+                .iter()
+                .filter(|lang| lang.as_str() != "fr")
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(",");
             let source_file = config.output_dir.join("fr.json");
 
             let translate_cmd = translate::TranslateCmd {
                 source: source_file,
                 to: to_langs,
-                api: match config.api_choice {
+                from: Some("fr".to_string()),
+                api: Some(match config.api_choice {
                     TranslationApi::DeepL => "deepl".to_string(),
                     TranslationApi::OpenAI => "openai".to_string(),
                     _ => "deepl".to_string(),
-                },
+                }),
+                config: None,
                 api_key: config.api_key.clone(),
+                base_url: None,
+                model: None,
+                glossary: None,
+                context: None,
+                batch_size: 1,
+                incremental: false,
+                estimate: false,
+                max_chars: None,
+                verify: false,
+                verify_report: None,
             };
 
             let translate_spinner = reporter.create_spinner("Translating to target languages");
@@ -68,10 +94,11 @@ impl SetupCmd {
         println!("\n{}", "Step 3/4: Replacing strings...".bold());
         let replace_spinner = reporter.create_spinner("Generating i18n files");
 
-        let strategy = match config.framework {
-            Framework::React => "react-i18n",
-            Framework::Vue => "vue-i18n",
-            _ => "generic",
+        let strategy = match (&config.strategy, &config.framework) {
+            (Some(custom), _) => custom.as_str(),
+            (None, Framework::React) => "react-i18n",
+            (None, Framework::Vue) => "vue-i18n",
+            (None, _) => "generic",
         };
 
         let translation_file = config.output_dir.join("fr.json");
@@ -80,8 +107,16 @@ impl SetupCmd {
             source: config.source_dir.clone(),
             translations: translation_file,
             strategy: strategy.to_string(),
+            define_messages: false,
+            namespace: None,
+            config: None,
             dry_run: false,
+            diff_output: None,
+            write_unchecked: false,
             in_place: false,
+            force: false,
+            branch: None,
+            commit: false,
         };
 
         replace_cmd.run().await?;
@@ -1,7 +1,10 @@
-use crate::application::TranslateKeysUseCase;
+use crate::application::{TranslateKeysUseCase, TranslateOptions};
 use crate::cli::presenter::Presenter;
-use crate::infrastructure::{ConfigManager, DeepLTranslator, OpenAITranslator};
+use crate::cli::progress::{EstimateSummary, TranslationSummary};
+use crate::domain::models::{CatalogMetadata, KeyContext};
+use crate::infrastructure::{ConfigManager, GlossaryLoader, ProviderOverrides, TranslatorFactory};
 use clap::Parser;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -14,13 +17,69 @@ pub struct TranslateCmd {
     #[arg(short, long, value_name = "LANGS")]
     pub to: String,
 
-    /// Translation API provider (deepl or openai)
-    #[arg(short, long, default_value = "deepl")]
-    pub api: String,
+    /// Source language (defaults to the source filename, e.g., fr.json -> fr)
+    #[arg(short, long, value_name = "LANG")]
+    pub from: Option<String>,
+
+    /// Translation API provider (deepl, openai, openai-compatible, libretranslate, google, azure, anthropic or pseudo).
+    /// A comma-separated list (e.g., deepl,openai) is a fallback chain.
+    /// Defaults to the [translate] section of langsmith.toml, then deepl.
+    #[arg(short, long, value_name = "PROVIDERS")]
+    pub api: Option<String>,
 
     /// API key (overrides environment variable)
     #[arg(long, value_name = "KEY")]
     pub api_key: Option<String>,
+
+    /// API base URL for self-hosted servers (e.g., http://localhost:11434/v1)
+    #[arg(long, value_name = "URL")]
+    pub base_url: Option<String>,
+
+    /// Model name for LLM providers (e.g., gpt-4o-mini, llama3.1)
+    #[arg(long, value_name = "NAME")]
+    pub model: Option<String>,
+
+    /// Project config file (defaults to ./langsmith.toml if present)
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Glossary file with enforced terms (TOML or JSON)
+    #[arg(short, long, value_name = "FILE")]
+    pub glossary: Option<PathBuf>,
+
+    /// Key context file (JSON map of key -> file_path/component/element/comment/max_length)
+    ///
+    /// Not needed for file and component context: `extract` writes them to `<lang>.meta.json`
+    /// next to the catalog, which is loaded automatically. Entries here replace that context
+    /// key by key, e.g.
+    /// `{"save": {"component": "SettingsForm", "element": "button", "max_length": 12}}`.
+    /// Every field is optional.
+    #[arg(long, value_name = "FILE")]
+    pub context: Option<PathBuf>,
+
+    /// Number of keys sent per request (OpenAI translates a batch as one JSON object)
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    pub batch_size: usize,
+
+    /// Keep existing translations and only translate keys missing from the target files
+    #[arg(long)]
+    pub incremental: bool,
+
+    /// Print expected characters, tokens and cost per language without translating
+    #[arg(long)]
+    pub estimate: bool,
+
+    /// Stop translating once this many source characters have been sent
+    #[arg(long, value_name = "N")]
+    pub max_chars: Option<usize>,
+
+    /// Back-translate new translations and flag suspicious ones (doubles API usage)
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Where to write the verification report (defaults to <source dir>/.langsmith/verify-report.json)
+    #[arg(long, value_name = "FILE", requires = "verify")]
+    pub verify_report: Option<PathBuf>,
 }
 
 impl TranslateCmd {
@@ -39,14 +98,55 @@ impl TranslateCmd {
         }
 
         Presenter::info(format!("Source: {:?}", self.source));
+        if let Some(from) = &self.from {
+            Presenter::info(format!("Source language: {}", from));
+        }
         Presenter::info(format!("Target languages: {}", self.to));
-        Presenter::info(format!("API: {}", self.api));
+        let project_config = ConfigManager::load_project_config(self.config.as_deref())?;
+
+        let glossary = match &self.glossary {
+            Some(path) => {
+                Presenter::info(format!("Glossary: {:?}", path));
+                Some(GlossaryLoader::load(path)?)
+            }
+            None => None,
+        };
+
+        // Context recorded by `extract`, then per-key overrides from --context
+        let metadata_path = CatalogMetadata::path_for(&self.source);
+        let mut contexts = if metadata_path.is_file() {
+            Presenter::info(format!("Key metadata: {:?}", metadata_path));
+            serde_json::from_str::<CatalogMetadata>(&std::fs::read_to_string(&metadata_path)?)
+                .map_err(|e| anyhow::anyhow!("Invalid metadata file {:?}: {}", metadata_path, e))?
+                .contexts()
+        } else {
+            HashMap::new()
+        };
+        if let Some(path) = &self.context {
+            let overrides: HashMap<String, KeyContext> =
+                serde_json::from_str(&std::fs::read_to_string(path)?)
+                    .map_err(|e| anyhow::anyhow!("Invalid context file {:?}: {}", path, e))?;
+            contexts.extend(overrides);
+        }
 
-        // Get API configuration
-        let api_config = ConfigManager::get_api_config(&self.api, self.api_key.as_deref())?;
+        let options = TranslateOptions {
+            source_lang: self.from.clone(),
+            glossary: glossary.clone(),
+            contexts,
+            batch_size: self.batch_size,
+            incremental: self.incremental,
+            max_chars: self.max_chars,
+            styles: project_config.styles.clone(),
+            verify: self.verify,
+        };
 
         // Parse target languages
-        let target_langs: Vec<&str> = self.to.split(',').map(|s| s.trim()).collect();
+        let target_langs: Vec<&str> = self
+            .to
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
         if target_langs.is_empty() {
             Presenter::error("No target languages specified");
             return Err(anyhow::anyhow!("No target languages specified"));
@@ -54,17 +154,87 @@ impl TranslateCmd {
 
         Presenter::info(format!("Translating to: {}", target_langs.join(", ")));
 
-        // Execute translation based on provider
-        match api_config.provider {
-            crate::infrastructure::ApiProvider::DeepL => {
-                let translator = DeepLTranslator::new(api_config.api_key);
-                TranslateKeysUseCase::execute(&self.source, &target_langs, &translator).await?;
+        // Build translator: --api chain first, then project routing, then DeepL
+        let overrides = ProviderOverrides {
+            api_key: self.api_key.clone(),
+            base_url: self.base_url.clone(),
+            model: self.model.clone(),
+        };
+        let glossary = glossary.unwrap_or_default();
+
+        let translator = match &self.api {
+            Some(api) => {
+                let providers: Vec<String> = api.split(',').map(|s| s.trim().to_string()).collect();
+                Presenter::info(format!("API: {}", providers.join(" → ")));
+                TranslatorFactory::create_chain(&providers, &overrides, &glossary)?
+            }
+            None if !project_config.translate.is_empty() => {
+                Presenter::info("API: routed by project config");
+                TranslatorFactory::create_routed(&project_config.translate, &overrides, &glossary)?
+            }
+            None => {
+                Presenter::info("API: deepl");
+                TranslatorFactory::create_chain(&["deepl".to_string()], &overrides, &glossary)?
+            }
+        };
+
+        if self.estimate {
+            let estimate = TranslateKeysUseCase::estimate(
+                &self.source,
+                &target_langs,
+                translator.as_ref(),
+                &options,
+            )
+            .await?;
+
+            EstimateSummary {
+                estimate,
+                max_chars: self.max_chars,
             }
-            crate::infrastructure::ApiProvider::OpenAI => {
-                let translator = OpenAITranslator::new(api_config.api_key);
-                TranslateKeysUseCase::execute(&self.source, &target_langs, &translator).await?;
+            .print();
+            return Ok(());
+        }
+
+        let report = TranslateKeysUseCase::execute(
+            &self.source,
+            &target_langs,
+            translator.as_ref(),
+            &options,
+        )
+        .await?;
+
+        if self.verify {
+            let path = self.verify_report.clone().unwrap_or_else(|| {
+                self.source
+                    .with_file_name(".langsmith")
+                    .join("verify-report.json")
+            });
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
             }
+            let json = serde_json::json!({
+                "verified": report.verified,
+                "flagged": report.flagged,
+            });
+            std::fs::write(&path, serde_json::to_string_pretty(&json)?)?;
+            Presenter::info(format!("Verification report: {:?}", path));
+        }
+
+        TranslationSummary {
+            total: report.total,
+            successful: report.successful,
+            failed: report.failed,
+            reused: report.reused,
+            protected: report.protected,
+            skipped: report.skipped,
+            characters: report.characters,
+            budget_exhausted: report.budget_exhausted,
+            languages: report.languages,
+            glossary_violations: report.glossary_violations,
+            verified: report.verified,
+            flagged: report.flagged,
         }
+        .print();
 
         Presenter::success("Translation complete!");
         Ok(())
@@ -0,0 +1,32 @@
+use crate::application::UndoReplaceUseCase;
+use crate::cli::presenter::Presenter;
+use crate::domain::ports::VersionControl;
+use crate::infrastructure::*;
+use clap::Parser;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser, Debug)]
+pub struct UndoCmd {
+    /// Project config file, whose directory holds the journal outside a git repository
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+}
+
+impl UndoCmd {
+    pub async fn run(self) -> anyhow::Result<()> {
+        Presenter::header("↩ Langsmith - Undo");
+
+        // Same project directory as `replace`: the top of the git repository
+        let project_dir = match GitRepository::discover(Path::new(".")).await? {
+            Some(repo) => repo.root().to_path_buf(),
+            None => ConfigManager::project_dir(self.config.as_deref()),
+        };
+        let restored = UndoReplaceUseCase::execute(&project_dir).await?;
+        for path in &restored {
+            Presenter::info(format!("Restored {}", path.display()));
+        }
+        Presenter::success(format!("Undid the last run ({} files)", restored.len()));
+
+        Ok(())
+    }
+}
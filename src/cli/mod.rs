@@ -22,8 +22,10 @@ impl Cli {
             Command::Setup(cmd) => cmd.run().await,
             Command::Extract(cmd) => cmd.run().await,
             Command::Translate(cmd) => cmd.run().await,
+            Command::Review(cmd) => cmd.run().await,
             Command::Replace(cmd) => cmd.run().await,
             Command::Merge(cmd) => cmd.run().await,
+            Command::Undo(cmd) => cmd.run().await,
         }
     }
 }
@@ -1,3 +1,6 @@
+use crate::application::CostEstimate;
+use crate::domain::models::GlossaryViolation;
+use crate::domain::quality::QualityCheck;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
 use std::time::Duration;
@@ -91,7 +94,15 @@ pub struct TranslationSummary {
     pub total: usize,
     pub successful: usize,
     pub failed: usize,
+    pub reused: usize,
+    pub protected: usize,
+    pub skipped: usize,
+    pub characters: usize,
+    pub budget_exhausted: bool,
     pub languages: Vec<String>,
+    pub glossary_violations: Vec<GlossaryViolation>,
+    pub verified: usize,
+    pub flagged: Vec<QualityCheck>,
 }
 
 impl TranslationSummary {
@@ -102,7 +113,148 @@ impl TranslationSummary {
         if self.failed > 0 {
             println!("  {} Failed", self.failed.to_string().red());
         }
+        if self.reused > 0 {
+            println!("  {} Kept from existing files", self.reused);
+        }
+        if self.protected > 0 {
+            println!(
+                "  {} Kept because they were reviewed or edited by hand",
+                self.protected
+            );
+        }
+        println!("  {} Characters sent", self.characters);
+        if self.budget_exhausted {
+            println!(
+                "  {} Character budget reached, {} keys left untranslated (rerun with --incremental)",
+                "⚠".yellow(),
+                self.skipped.to_string().yellow()
+            );
+        }
         println!("  Languages: {}", self.languages.join(", "));
+
+        if !self.glossary_violations.is_empty() {
+            println!(
+                "\n  {} Glossary violations:",
+                self.glossary_violations.len().to_string().yellow()
+            );
+            for violation in &self.glossary_violations {
+                println!(
+                    "    {} [{}] {}: \"{}\" should be \"{}\"",
+                    "⚠".yellow(),
+                    violation.target_lang,
+                    violation.key,
+                    violation.term,
+                    violation.expected
+                );
+            }
+        }
+
+        if self.verified > 0 {
+            println!(
+                "\n  {} Verified by back-translation, {} flagged",
+                self.verified,
+                if self.flagged.is_empty() {
+                    "0".green().to_string()
+                } else {
+                    self.flagged.len().to_string().yellow().to_string()
+                }
+            );
+            for check in &self.flagged {
+                let issues: Vec<&str> = check.issues.iter().map(|i| i.describe()).collect();
+                println!(
+                    "    {} [{}] {}: {}",
+                    "⚠".yellow(),
+                    check.target_lang,
+                    check.key,
+                    issues.join(", ")
+                );
+                if let Some(back) = &check.back_translation {
+                    println!(
+                        "      \"{}\" → \"{}\" → \"{}\" ({:.0}%)",
+                        check.source,
+                        check.translation,
+                        back,
+                        check.similarity.unwrap_or_default() * 100.0
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Expected usage printed by `translate --estimate`
+pub struct EstimateSummary {
+    pub estimate: CostEstimate,
+    pub max_chars: Option<usize>,
+}
+
+impl EstimateSummary {
+    pub fn print(&self) {
+        println!("\n{}", "💰 Cost Estimate:".bold());
+
+        for language in &self.estimate.languages {
+            let tokens = language
+                .usage
+                .tokens
+                .map(|t| format!(", ~{} tokens", t))
+                .unwrap_or_default();
+            println!(
+                "  {} via {}: {} keys, {} chars{} → {}",
+                language.target.bold(),
+                language.provider.as_deref().unwrap_or("unknown provider"),
+                language.keys,
+                language.usage.characters,
+                tokens,
+                format_cost(language.usage.cost)
+            );
+            if language.cached > 0 || language.reused > 0 {
+                println!(
+                    "    └─ {} duplicates reuse a translation, {} already translated",
+                    language.cached, language.reused
+                );
+            }
+        }
+
+        let total_characters = self.estimate.total_characters();
+        println!(
+            "\n  Total: {} chars → {}",
+            total_characters.to_string().green(),
+            format_cost(self.estimate.total_cost())
+        );
+
+        if let Some(max_chars) = self.max_chars.filter(|max| total_characters > *max) {
+            println!(
+                "  {} Exceeds --max-chars {}, the run will stop early",
+                "⚠".yellow(),
+                max_chars
+            );
+        }
+
+        for quota in &self.estimate.quotas {
+            match quota.remaining() {
+                Some(remaining) => {
+                    let line = format!(
+                        "  {} quota: {} of {} characters remaining",
+                        quota.provider,
+                        remaining,
+                        quota.limit.unwrap_or_default()
+                    );
+                    if (remaining as usize) < total_characters {
+                        println!("{} {}", line.yellow(), "(not enough for this run)".yellow());
+                    } else {
+                        println!("{}", line);
+                    }
+                }
+                None => println!("  {} quota: {} characters used", quota.provider, quota.used),
+            }
+        }
+    }
+}
+
+fn format_cost(cost: Option<f64>) -> String {
+    match cost {
+        Some(cost) => format!("${:.4}", cost),
+        None => "cost unknown".to_string(),
     }
 }
 
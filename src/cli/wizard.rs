@@ -1,3 +1,4 @@
+use crate::infrastructure::ConfigManager;
 use anyhow::Result;
 use dialoguer::{Confirm, Input, MultiSelect, Select};
 use owo_colors::OwoColorize;
@@ -5,6 +6,8 @@ use std::path::PathBuf;
 
 pub struct WizardConfig {
     pub framework: Framework,
+    /// A `[strategies.<name>]` entry of langsmith.toml, used instead of the framework's
+    pub strategy: Option<String>,
     pub source_dir: PathBuf,
     pub output_dir: PathBuf,
     pub target_languages: Vec<String>,
@@ -56,6 +59,7 @@ impl Wizard {
         Self::print_welcome();
 
         let framework = Self::ask_framework()?;
+        let strategy = Self::ask_strategy(&framework)?;
         let source_dir = Self::ask_source_dir()?;
         let output_dir = Self::ask_output_dir()?;
         let target_languages = Self::ask_target_languages()?;
@@ -64,6 +68,7 @@ impl Wizard {
 
         let config = WizardConfig {
             framework,
+            strategy,
             source_dir,
             output_dir,
             target_languages,
@@ -102,6 +107,30 @@ impl Wizard {
         Ok(framework)
     }
 
+    /// Offer the custom strategies of langsmith.toml, if it defines any
+    fn ask_strategy(framework: &Framework) -> Result<Option<String>> {
+        let config = ConfigManager::load_project_config(None)?;
+        let mut names: Vec<&String> = config.strategies.keys().collect();
+        if names.is_empty() {
+            return Ok(None);
+        }
+        names.sort();
+
+        let mut choices = vec![format!("Built-in {} strategy", framework)];
+        choices.extend(
+            names
+                .iter()
+                .map(|name| format!("{} (langsmith.toml)", name)),
+        );
+        let selection = Select::new()
+            .with_prompt("How should strings be replaced?")
+            .items(&choices)
+            .default(0)
+            .interact()?;
+
+        Ok(selection.checked_sub(1).map(|i| names[i].to_string()))
+    }
+
     fn ask_source_dir() -> Result<PathBuf> {
         let dir: String = Input::new()
             .with_prompt("Where are your source files?")
@@ -197,6 +226,9 @@ impl Wizard {
         // Show summary
         println!("\n{}", "📋 Configuration Summary:".bold());
         println!("  Framework: {}", config.framework);
+        if let Some(strategy) = &config.strategy {
+            println!("  Strategy: {}", strategy);
+        }
         println!("  Source: {}", config.source_dir.display());
         println!("  Output: {}", config.output_dir.display());
         println!("  Languages: {}", config.target_languages.join(", "));
@@ -0,0 +1,211 @@
+use super::icu::{Message, Part};
+use super::plural::PluralCategory;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+
+/// Plural forms stored the i18next way: one key per CLDR category
+/// e.g., "items_one": "{{count}} item", "items_other": "{{count}} items"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluralGroup {
+    pub base: String,
+    pub forms: BTreeMap<PluralCategory, String>,
+}
+
+/// Split "items_few" into ("items", Few)
+pub fn split_plural_key(key: &str) -> Option<(&str, PluralCategory)> {
+    let (base, suffix) = key.rsplit_once('_')?;
+    let category = PluralCategory::parse(suffix)?;
+    (!base.is_empty()).then_some((base, category))
+}
+
+/// Plural groups of a catalog, sorted by base key; a group needs an `_other` form
+pub fn plural_groups(catalog: &HashMap<String, String>) -> Vec<PluralGroup> {
+    let mut groups: BTreeMap<&str, BTreeMap<PluralCategory, String>> = BTreeMap::new();
+    for (key, value) in catalog {
+        if let Some((base, category)) = split_plural_key(key) {
+            groups
+                .entry(base)
+                .or_default()
+                .insert(category, value.clone());
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, forms)| forms.contains_key(&PluralCategory::Other))
+        .map(|(base, forms)| PluralGroup {
+            base: base.to_string(),
+            forms,
+        })
+        .collect()
+}
+
+/// Whether a catalog has `key` itself or i18next plural forms of it
+pub fn has_key(catalog: &HashMap<String, String>, key: &str) -> bool {
+    catalog.contains_key(key)
+        || catalog.contains_key(&format!("{}_{}", key, PluralCategory::Other.as_str()))
+}
+
+fn interpolation_re() -> Regex {
+    Regex::new(r"\{\{\s*([\w.]+)\s*\}\}").expect("valid interpolation regex")
+}
+
+impl PluralGroup {
+    pub fn key(&self, category: PluralCategory) -> String {
+        format!("{}_{}", self.base, category.as_str())
+    }
+
+    /// Text of a form, falling back to `other` like i18next does
+    pub fn source_for(&self, category: PluralCategory) -> &str {
+        self.forms
+            .get(&category)
+            .or_else(|| self.forms.get(&PluralCategory::Other))
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    /// The equivalent ICU plural: `{{count}}` becomes `#`, other `{{x}}` become `{x}`
+    pub fn to_message(&self) -> Message {
+        let re = interpolation_re();
+        let options = self
+            .forms
+            .iter()
+            .map(|(category, text)| {
+                let mut parts = Vec::new();
+                let mut last = 0;
+                for cap in re.captures_iter(text) {
+                    let Some(whole) = cap.get(0) else {
+                        continue;
+                    };
+                    if whole.start() > last {
+                        parts.push(Part::Text(text[last..whole.start()].to_string()));
+                    }
+                    parts.push(match &cap[1] {
+                        "count" => Part::Pound,
+                        name => Part::Argument {
+                            name: name.to_string(),
+                            format: None,
+                        },
+                    });
+                    last = whole.end();
+                }
+                if last < text.len() {
+                    parts.push(Part::Text(text[last..].to_string()));
+                }
+                (category.as_str().to_string(), Message { parts })
+            })
+            .collect();
+
+        Message {
+            parts: vec![Part::Plural {
+                name: "count".to_string(),
+                ordinal: false,
+                offset: 0,
+                options,
+            }],
+        }
+    }
+
+    /// Forms of a message made of a single plural; its number is always called `count`
+    pub fn from_message(base: &str, message: &Message) -> anyhow::Result<Self> {
+        let [
+            Part::Plural {
+                ordinal: false,
+                offset: 0,
+                options,
+                ..
+            },
+        ] = message.parts.as_slice()
+        else {
+            return Err(anyhow::anyhow!(
+                "only a message made of a single plural can be written as i18next keys"
+            ));
+        };
+
+        let mut forms = BTreeMap::new();
+        for (selector, option) in options {
+            let category = PluralCategory::parse(selector).ok_or_else(|| {
+                anyhow::anyhow!("exact match '{}' has no i18next equivalent", selector)
+            })?;
+            let mut text = String::new();
+            for part in &option.parts {
+                match part {
+                    Part::Text(t) => text.push_str(t),
+                    Part::Pound => text.push_str("{{count}}"),
+                    Part::Argument { name, .. } => text.push_str(&format!("{{{{{}}}}}", name)),
+                    _ => {
+                        return Err(anyhow::anyhow!(
+                            "nested plural or select has no i18next equivalent"
+                        ));
+                    }
+                }
+            }
+            forms.insert(category, text);
+        }
+
+        Ok(Self {
+            base: base.to_string(),
+            forms,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groups_and_icu_roundtrip() {
+        let catalog: HashMap<String, String> = [
+            ("items_one", "{{count}} item in {{folder}}"),
+            ("items_other", "{{count}} items in {{folder}}"),
+            ("orphan_one", "One"),
+            ("save", "Save"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        let groups = plural_groups(&catalog);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].base, "items");
+        assert!(has_key(&catalog, "items"));
+        assert!(!has_key(&catalog, "orphan"));
+
+        let message = groups[0].to_message();
+        assert_eq!(
+            message.to_string(),
+            "{count, plural, one {# item in {folder}} other {# items in {folder}}}"
+        );
+        assert_eq!(
+            PluralGroup::from_message("items", &message).unwrap(),
+            groups[0]
+        );
+        assert_eq!(
+            groups[0].source_for(PluralCategory::Few),
+            "{{count}} items in {{folder}}"
+        );
+    }
+
+    #[test]
+    fn test_from_message_rejects_what_i18next_cannot_express() {
+        for text in [
+            "{n, plural, =0 {None} other {# items}}",
+            "You have {n, plural, one {# item} other {# items}}",
+            "{g, select, other {x}}",
+        ] {
+            let message = Message::parse(text).unwrap();
+            assert!(
+                PluralGroup::from_message("k", &message).is_err(),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            split_plural_key("items_many"),
+            Some(("items", PluralCategory::Many))
+        );
+        assert_eq!(split_plural_key("_one"), None);
+        assert_eq!(split_plural_key("save_changes"), None);
+    }
+}
@@ -0,0 +1,776 @@
+use super::locale::Locale;
+use super::plural::{PluralCategory, PluralRules};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A parsed ICU MessageFormat message
+/// e.g., "{count, plural, one {# item} other {# items}}"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Part {
+    Text(String),
+    /// `{name}` or a formatted argument such as `{price, number, currency}`
+    Argument {
+        name: String,
+        format: Option<String>,
+    },
+    /// `#`: the number of the innermost plural
+    Pound,
+    Plural {
+        name: String,
+        ordinal: bool,
+        offset: u32,
+        options: Vec<(String, Message)>,
+    },
+    Select {
+        name: String,
+        options: Vec<(String, Message)>,
+    },
+}
+
+/// Whether a catalog value uses plural or select syntax and must be handled as ICU
+pub fn is_icu_message(text: &str) -> bool {
+    Regex::new(r"\{\s*[\p{L}\w]+\s*,\s*(plural|selectordinal|select)\s*,")
+        .expect("valid ICU detection regex")
+        .is_match(text)
+}
+
+impl Message {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut parser = Parser {
+            chars: text.chars().collect(),
+            pos: 0,
+        };
+        let message = parser
+            .message(false, false)
+            .map_err(|e| anyhow::anyhow!("Invalid ICU message at {}: {}", parser.pos, e))?;
+        Ok(message)
+    }
+
+    /// Formatted arguments by name, to restore `{price, number}` after translation
+    fn formats(&self, formats: &mut HashMap<String, String>) {
+        for part in &self.parts {
+            match part {
+                Part::Argument {
+                    name,
+                    format: Some(format),
+                } => {
+                    formats.insert(name.clone(), format.clone());
+                }
+                Part::Plural { options, .. } | Part::Select { options, .. } => {
+                    options.iter().for_each(|(_, m)| m.formats(formats))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, in_plural: bool) -> fmt::Result {
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => {
+                    for c in text.chars() {
+                        match c {
+                            '\'' => write!(f, "''")?,
+                            '{' | '}' => write!(f, "'{}'", c)?,
+                            '#' if in_plural => write!(f, "'#'")?,
+                            c => write!(f, "{}", c)?,
+                        }
+                    }
+                }
+                Part::Argument { name, format } => match format {
+                    Some(format) => write!(f, "{{{}, {}}}", name, format)?,
+                    None => write!(f, "{{{}}}", name)?,
+                },
+                Part::Pound => write!(f, "#")?,
+                Part::Plural {
+                    name,
+                    ordinal,
+                    offset,
+                    options,
+                } => {
+                    let kind = if *ordinal { "selectordinal" } else { "plural" };
+                    write!(f, "{{{}, {},", name, kind)?;
+                    if *offset > 0 {
+                        write!(f, " offset:{}", offset)?;
+                    }
+                    for (selector, message) in options {
+                        write!(f, " {} {{", selector)?;
+                        message.write(f, true)?;
+                        write!(f, "}}")?;
+                    }
+                    write!(f, "}}")?;
+                }
+                Part::Select { name, options } => {
+                    write!(f, "{{{}, select,", name)?;
+                    for (selector, message) in options {
+                        write!(f, " {} {{", selector)?;
+                        message.write(f, in_plural)?;
+                        write!(f, "}}")?;
+                    }
+                    write!(f, "}}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, false)
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(format!("expected '{}', found '{}'", expected, c)),
+            None => Err(format!("expected '{}', found end of message", expected)),
+        }
+    }
+
+    fn word(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '=' || c == ':' || c == '-')
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Parse text and arguments until the end, or until the '}' closing a nested message
+    fn message(&mut self, nested: bool, in_plural: bool) -> Result<Message, String> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '}' if nested => break,
+                '}' => return Err("unmatched '}'".to_string()),
+                '{' => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(self.argument(in_plural)?);
+                }
+                '#' if in_plural => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Pound);
+                    self.pos += 1;
+                }
+                '\'' => {
+                    self.pos += 1;
+                    match self.peek() {
+                        // '' is a literal apostrophe
+                        Some('\'') => {
+                            text.push('\'');
+                            self.pos += 1;
+                        }
+                        // '{...}' quotes syntax characters until the next lone apostrophe
+                        Some(next) if next == '{' || next == '}' || (next == '#' && in_plural) => {
+                            while let Some(quoted) = self.peek() {
+                                self.pos += 1;
+                                if quoted == '\'' {
+                                    if self.peek() == Some('\'') {
+                                        text.push('\'');
+                                        self.pos += 1;
+                                        continue;
+                                    }
+                                    break;
+                                }
+                                text.push(quoted);
+                            }
+                        }
+                        _ => text.push('\''),
+                    }
+                }
+                c => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+
+        if nested && self.peek().is_none() {
+            return Err("unclosed '{'".to_string());
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Message { parts })
+    }
+
+    fn argument(&mut self, in_plural: bool) -> Result<Part, String> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let name = self.word();
+        if name.is_empty() || name.contains(['=', ':']) {
+            return Err("expected an argument name".to_string());
+        }
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Part::Argument { name, format: None });
+        }
+        self.expect(',')?;
+        self.skip_whitespace();
+        let kind = self.word();
+        self.skip_whitespace();
+
+        match kind.as_str() {
+            "plural" | "selectordinal" | "select" => {
+                self.expect(',')?;
+                let plural = kind != "select";
+                let (offset, options) = self.options(&name, plural, plural || in_plural)?;
+                Ok(if plural {
+                    Part::Plural {
+                        name,
+                        ordinal: kind == "selectordinal",
+                        offset,
+                        options,
+                    }
+                } else {
+                    Part::Select { name, options }
+                })
+            }
+            "" => Err(format!("missing type for argument '{}'", name)),
+            _ => {
+                let mut format = kind;
+                if self.peek() == Some(',') {
+                    self.pos += 1;
+                    let start = self.pos;
+                    while self.peek().is_some_and(|c| c != '}') {
+                        self.pos += 1;
+                    }
+                    let style: String = self.chars[start..self.pos].iter().collect();
+                    format = format!("{}, {}", format, style.trim());
+                }
+                self.expect('}')?;
+                Ok(Part::Argument {
+                    name,
+                    format: Some(format),
+                })
+            }
+        }
+    }
+
+    fn options(
+        &mut self,
+        name: &str,
+        plural: bool,
+        in_plural: bool,
+    ) -> Result<(u32, Vec<(String, Message)>), String> {
+        let mut offset = 0;
+        let mut options: Vec<(String, Message)> = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.pos += 1;
+                break;
+            }
+            let selector = self.word();
+            if selector.is_empty() {
+                return Err(format!("expected a selector in '{}'", name));
+            }
+
+            if let Some(value) = selector.strip_prefix("offset:") {
+                if !plural || !options.is_empty() {
+                    return Err("offset must come first in a plural".to_string());
+                }
+                offset = value.parse().map_err(|_| "invalid offset".to_string())?;
+                continue;
+            }
+            if plural
+                && PluralCategory::parse(&selector).is_none()
+                && selector
+                    .strip_prefix('=')
+                    .is_none_or(|n| n.parse::<u64>().is_err())
+            {
+                return Err(format!("invalid plural selector '{}'", selector));
+            }
+            if options.iter().any(|(s, _)| *s == selector) {
+                return Err(format!("duplicate selector '{}' in '{}'", selector, name));
+            }
+
+            self.skip_whitespace();
+            self.expect('{')?;
+            let message = self.message(true, in_plural)?;
+            self.expect('}')?;
+            options.push((selector, message));
+        }
+
+        if !options.iter().any(|(s, _)| s == "other") {
+            return Err(format!("'{}' has no 'other' option", name));
+        }
+        Ok((offset, options))
+    }
+}
+
+/// A plural or select argument the message varies on
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Dimension {
+    name: String,
+    /// Plural offset; None for select
+    plural_offset: Option<u32>,
+    /// Options the translated message will have
+    selectors: Vec<String>,
+}
+
+/// One sentence to translate: the source message rendered for one combination of options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    /// Selector per dimension, e.g. ["female", "few"]
+    pub selectors: Vec<String>,
+    /// Number substituted for the plural argument
+    pub number: Option<String>,
+    pub text: String,
+}
+
+/// A plural/select message split into plain sentences for a target language
+///
+/// Plural options follow the target's CLDR categories: each category gets a
+/// sentence with an example number ("5 items" for Russian many), which lets
+/// any provider produce the right grammatical form. Translations are put back
+/// together with `assemble`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expansion {
+    dimensions: Vec<Dimension>,
+    pub variants: Vec<Variant>,
+    formats: HashMap<String, String>,
+}
+
+impl Message {
+    /// Split into one sentence per target plural category and select option
+    ///
+    /// Supports any number of select arguments and one plural argument;
+    /// `selectordinal` is not supported.
+    pub fn expand(&self, source: Option<&Locale>, target: &Locale) -> anyhow::Result<Expansion> {
+        let mut dimensions: Vec<Dimension> = Vec::new();
+        self.collect_dimensions(&mut dimensions)?;
+
+        let target_rules = PluralRules::for_locale(target);
+        for dimension in dimensions.iter_mut() {
+            if dimension.plural_offset.is_some() {
+                // Explicit =N options are kept, categories follow the target language
+                dimension.selectors.retain(|s| s.starts_with('='));
+                dimension.selectors.extend(
+                    target_rules
+                        .categories()
+                        .iter()
+                        .map(|c| c.as_str().to_string()),
+                );
+            } else if let Some(i) = dimension.selectors.iter().position(|s| s == "other") {
+                let other = dimension.selectors.remove(i);
+                dimension.selectors.push(other);
+            }
+        }
+        // Select arguments outside, the plural innermost so '#' refers to it
+        dimensions.sort_by_key(|d| d.plural_offset.is_some());
+
+        let source_rules = source
+            .map(PluralRules::for_locale)
+            .unwrap_or(PluralRules::OneOther);
+
+        let mut combinations: Vec<Vec<String>> = vec![Vec::new()];
+        for dimension in &dimensions {
+            combinations = combinations
+                .into_iter()
+                .flat_map(|prefix| {
+                    dimension.selectors.iter().map(move |selector| {
+                        let mut combination = prefix.clone();
+                        combination.push(selector.clone());
+                        combination
+                    })
+                })
+                .collect();
+        }
+
+        let variants = combinations
+            .into_iter()
+            .map(|selectors| {
+                let choice: HashMap<&str, &str> = dimensions
+                    .iter()
+                    .zip(&selectors)
+                    .map(|(d, s)| (d.name.as_str(), s.as_str()))
+                    .collect();
+                let number = dimensions
+                    .iter()
+                    .zip(&selectors)
+                    .find(|(d, _)| d.plural_offset.is_some())
+                    .map(|(_, selector)| match selector.strip_prefix('=') {
+                        Some(exact) => exact.to_string(),
+                        None => PluralCategory::parse(selector)
+                            .and_then(|c| target_rules.example(c))
+                            .unwrap_or("5")
+                            .to_string(),
+                    });
+
+                let mut text = String::new();
+                self.render(&choice, number.as_deref(), source_rules, &mut text);
+                Variant {
+                    selectors,
+                    number,
+                    text,
+                }
+            })
+            .collect();
+
+        let mut formats = HashMap::new();
+        self.formats(&mut formats);
+
+        Ok(Expansion {
+            dimensions,
+            variants,
+            formats,
+        })
+    }
+
+    fn collect_dimensions(&self, dimensions: &mut Vec<Dimension>) -> anyhow::Result<()> {
+        for part in &self.parts {
+            let (name, offset, options) = match part {
+                Part::Plural { ordinal: true, .. } => {
+                    return Err(anyhow::anyhow!(
+                        "selectordinal messages cannot be split for translation"
+                    ));
+                }
+                Part::Plural {
+                    name,
+                    offset,
+                    options,
+                    ..
+                } => (name, Some(*offset), options),
+                Part::Select { name, options } => (name, None, options),
+                _ => continue,
+            };
+
+            match dimensions.iter_mut().find(|d| d.name == *name) {
+                Some(existing) if existing.plural_offset != offset => {
+                    return Err(anyhow::anyhow!(
+                        "'{}' is used with different plural/select settings",
+                        name
+                    ));
+                }
+                Some(existing) => {
+                    for (selector, _) in options {
+                        if !existing.selectors.contains(selector) {
+                            existing.selectors.push(selector.clone());
+                        }
+                    }
+                }
+                None => {
+                    if offset.is_some() && dimensions.iter().any(|d| d.plural_offset.is_some()) {
+                        return Err(anyhow::anyhow!(
+                            "only one plural argument per message can be split for translation"
+                        ));
+                    }
+                    dimensions.push(Dimension {
+                        name: name.clone(),
+                        plural_offset: offset,
+                        selectors: options.iter().map(|(s, _)| s.clone()).collect(),
+                    });
+                }
+            }
+
+            for (_, message) in options {
+                message.collect_dimensions(dimensions)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Render the sentence for chosen select options and plural number
+    fn render(
+        &self,
+        choice: &HashMap<&str, &str>,
+        number: Option<&str>,
+        rules: PluralRules,
+        out: &mut String,
+    ) {
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => out.push_str(text),
+                Part::Argument { name, .. } => out.push_str(&format!("{{{}}}", name)),
+                Part::Pound => out.push_str(number.unwrap_or("#")),
+                Part::Plural {
+                    offset, options, ..
+                } => {
+                    let number = number.unwrap_or("5");
+                    let exact = format!("={}", number);
+                    let shown = match (number.parse::<u64>(), *offset) {
+                        (Ok(n), offset) if offset > 0 => {
+                            n.saturating_sub(offset as u64).to_string()
+                        }
+                        _ => number.to_string(),
+                    };
+                    let category = rules.category(&shown);
+                    let option = options
+                        .iter()
+                        .find(|(s, _)| *s == exact)
+                        .or_else(|| options.iter().find(|(s, _)| *s == category.as_str()))
+                        .or_else(|| options.iter().find(|(s, _)| s == "other"));
+                    if let Some((_, message)) = option {
+                        message.render(choice, Some(&shown), rules, out);
+                    }
+                }
+                Part::Select { name, options } => {
+                    let selected = choice.get(name.as_str()).copied().unwrap_or("other");
+                    let option = options
+                        .iter()
+                        .find(|(s, _)| s == selected)
+                        .or_else(|| options.iter().find(|(s, _)| s == "other"));
+                    if let Some((_, message)) = option {
+                        message.render(choice, number, rules, out);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Expansion {
+    /// Build the target message from one translation per variant, in the same order
+    pub fn assemble(&self, translations: &[String]) -> anyhow::Result<Message> {
+        if translations.len() != self.variants.len() {
+            return Err(anyhow::anyhow!(
+                "expected {} translations, got {}",
+                self.variants.len(),
+                translations.len()
+            ));
+        }
+
+        let leaves: Vec<Message> = self
+            .variants
+            .iter()
+            .zip(translations)
+            .map(|(variant, translation)| self.leaf(variant, translation))
+            .collect::<anyhow::Result<_>>()?;
+
+        let mut leaves = leaves.into_iter();
+        Ok(self.build(0, &mut leaves))
+    }
+
+    /// Turn a translated sentence back into message parts: the example number becomes '#'
+    fn leaf(&self, variant: &Variant, translation: &str) -> anyhow::Result<Message> {
+        let mut text = translation.trim().replace('\'', "''").replace('#', "'#'");
+
+        if let Some(number) = variant
+            .number
+            .as_deref()
+            .filter(|_| !variant.selectors.last().is_some_and(|s| s.starts_with('=')))
+        {
+            let pattern: String = number
+                .chars()
+                .map(|c| match c {
+                    '.' => r"[.,]".to_string(),
+                    c => regex::escape(&c.to_string()),
+                })
+                .collect::<Vec<_>>()
+                .join(r"[\s\u{a0}\u{202f}.,']?");
+            // Not part of a longer number; \b would fail next to CJK letters
+            let re = Regex::new(&format!(r"(^|[^\p{{Nd}}.,]){}($|[^\p{{Nd}}])", pattern))
+                .expect("valid number regex");
+            text = re.replacen(&text, 1, "${1}#${2}").to_string();
+        }
+
+        // Parsed as a plural option so the escaped '#' and the number's '#' are told apart
+        let wrapped = Message::parse(&format!("{{n, plural, other {{{}}}}}", text))
+            .map_err(|e| anyhow::anyhow!("translation is not a valid message part: {}", e))?;
+        let Some(Part::Plural { mut options, .. }) = wrapped.parts.into_iter().next() else {
+            return Err(anyhow::anyhow!("translation is not a valid message part"));
+        };
+        let mut message = options.remove(0).1;
+
+        for part in message.parts.iter_mut() {
+            if let Part::Argument { name, format } = part
+                && format.is_none()
+            {
+                *format = self.formats.get(name).cloned();
+            }
+        }
+        Ok(message)
+    }
+
+    fn build(&self, depth: usize, leaves: &mut impl Iterator<Item = Message>) -> Message {
+        let Some(dimension) = self.dimensions.get(depth) else {
+            return leaves.next().unwrap_or(Message { parts: Vec::new() });
+        };
+
+        let options = dimension
+            .selectors
+            .iter()
+            .map(|selector| (selector.clone(), self.build(depth + 1, leaves)))
+            .collect();
+
+        let part = match dimension.plural_offset {
+            Some(offset) => Part::Plural {
+                name: dimension.name.clone(),
+                ordinal: false,
+                offset,
+                options,
+            },
+            None => Part::Select {
+                name: dimension.name.clone(),
+                options,
+            },
+        };
+        Message { parts: vec![part] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(tag: &str) -> Locale {
+        Locale::parse(tag).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_print_roundtrip() {
+        for text in [
+            "Hello {name}",
+            "{count, plural, =0 {No items} one {# item} other {# items}}",
+            "{gender, select, female {She has {count, plural, one {# cat} other {# cats}}} other {They have pets}}",
+            "Total: {price, number, currency}",
+            "{n, plural, offset:1 one {You and someone} other {You and # others}}",
+            "It''s '{'literal'}' text",
+        ] {
+            let message = Message::parse(text).unwrap();
+            assert_eq!(Message::parse(&message.to_string()).unwrap(), message);
+        }
+
+        assert_eq!(
+            Message::parse("It''s '{'x'}'").unwrap().parts,
+            vec![Part::Text("It's {x}".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_messages() {
+        assert!(Message::parse("{count, plural, one {# item}}").is_err());
+        assert!(Message::parse("{count, plural, one {# item} other {# items}").is_err());
+        assert!(Message::parse("{count, plural, single {x} other {y}}").is_err());
+        assert!(Message::parse("{count, plural, one {a} one {b} other {c}}").is_err());
+        assert!(Message::parse("Hello }").is_err());
+        assert!(Message::parse("{, select, other {x}}").is_err());
+    }
+
+    #[test]
+    fn test_is_icu_message() {
+        assert!(is_icu_message("{count, plural, one {#} other {#}}"));
+        assert!(is_icu_message("Hi {g, select, other {you}}"));
+        assert!(!is_icu_message("Hello {name}"));
+        assert!(!is_icu_message("Hello {{name}}"));
+    }
+
+    #[test]
+    fn test_expand_uses_target_categories() {
+        let message =
+            Message::parse("{count, plural, =0 {No items} one {# item} other {# items}}").unwrap();
+        let expansion = message.expand(Some(&locale("en")), &locale("ru")).unwrap();
+
+        let texts: Vec<(&str, &str)> = expansion
+            .variants
+            .iter()
+            .map(|v| (v.selectors[0].as_str(), v.text.as_str()))
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                ("=0", "No items"),
+                ("one", "1 item"),
+                ("few", "2 items"),
+                ("many", "5 items"),
+                ("other", "1.5 items"),
+            ]
+        );
+
+        let translated = expansion
+            .assemble(&[
+                "Нет предметов".to_string(),
+                "1 предмет".to_string(),
+                "2 предмета".to_string(),
+                "5 предметов".to_string(),
+                "1,5 предмета".to_string(),
+            ])
+            .unwrap();
+        assert_eq!(
+            translated.to_string(),
+            "{count, plural, =0 {Нет предметов} one {# предмет} few {# предмета} many {# предметов} other {# предмета}}"
+        );
+    }
+
+    #[test]
+    fn test_expand_select_and_sentence_around_plural() {
+        let message = Message::parse(
+            "{host} invited {guests, plural, one {one guest} other {# guests}} to {gender, select, female {her} other {their}} party",
+        )
+        .unwrap();
+        let expansion = message.expand(Some(&locale("en")), &locale("ja")).unwrap();
+
+        let texts: Vec<&str> = expansion.variants.iter().map(|v| v.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "{host} invited 2 guests to her party",
+                "{host} invited 2 guests to their party",
+            ]
+        );
+
+        let translated = expansion
+            .assemble(&[
+                "{host}さんが2人を彼女のパーティーに招待しました".to_string(),
+                "{host}さんが2人をパーティーに招待しました".to_string(),
+            ])
+            .unwrap();
+        assert_eq!(
+            translated.to_string(),
+            "{gender, select, female {{guests, plural, other {{host}さんが#人を彼女のパーティーに招待しました}}} other {{guests, plural, other {{host}さんが#人をパーティーに招待しました}}}}"
+        );
+    }
+
+    #[test]
+    fn test_expand_rejects_unsupported_messages() {
+        let two_plurals = Message::parse(
+            "{a, plural, one {# a} other {# as}} and {b, plural, one {# b} other {# bs}}",
+        )
+        .unwrap();
+        assert!(two_plurals.expand(None, &locale("de")).is_err());
+
+        let ordinal =
+            Message::parse("{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}")
+                .unwrap();
+        assert!(ordinal.expand(None, &locale("de")).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// A file written by a run and what it held before
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub path: PathBuf,
+    /// Hash of the content before the run; None when the run created the file
+    pub original_hash: Option<String>,
+    /// Copy of the original content, relative to the run directory
+    pub backup: Option<String>,
+    /// Hash of the content the run wrote
+    pub written_hash: String,
+}
+
+/// Files written by one run, stored in `.langsmith/journal/<run>/journal.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Journal {
+    pub command: String,
+    /// Unix time in milliseconds, which also names the run directory
+    pub created_at: u128,
+    pub files: Vec<JournalEntry>,
+}
+
+impl Journal {
+    pub const FILE_NAME: &'static str = "journal.json";
+    /// Runs kept for `undo`; older ones are dropped when a run finishes
+    pub const KEPT_RUNS: usize = 10;
+
+    /// Directory holding one subdirectory per run, e.g. "." -> "./.langsmith/journal"
+    pub fn root(project_dir: &Path) -> PathBuf {
+        project_dir.join(".langsmith").join("journal")
+    }
+}
+
+/// SHA-256 of file content, hex encoded
+pub fn content_hash(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
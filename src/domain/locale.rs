@@ -0,0 +1,268 @@
+use std::fmt;
+
+/// A BCP-47 language tag: language[-script][-region][-variant...]
+///
+/// Parsing accepts "_" as separator and any casing, and canonicalizes the
+/// subtags ("PT_br" -> "pt-BR", "zh-hant-tw" -> "zh-Hant-TW").
+/// Extension and private-use subtags ("-u-...", "-x-...") are not supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// ISO 639 language, lowercase ("pt")
+    pub language: String,
+    /// ISO 15924 script, titlecase ("Hant")
+    pub script: Option<String>,
+    /// ISO 3166 region or UN M.49 area, uppercase ("BR", "419")
+    pub region: Option<String>,
+    /// Registered variants, lowercase ("valencia")
+    pub variants: Vec<String>,
+}
+
+impl Locale {
+    pub fn parse(tag: &str) -> anyhow::Result<Self> {
+        let invalid = |reason: &str| anyhow::anyhow!("Invalid locale '{}': {}", tag, reason);
+
+        let mut subtags = tag.trim().split(['-', '_']).peekable();
+
+        let language = subtags.next().unwrap_or_default();
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(invalid("language must be 2 or 3 letters"));
+        }
+
+        let mut locale = Self {
+            language: language.to_ascii_lowercase(),
+            script: None,
+            region: None,
+            variants: Vec::new(),
+        };
+
+        if let Some(script) = subtags.next_if(|s| is_script(s)) {
+            locale.script = Some(titlecase(script));
+        }
+
+        if let Some(region) = subtags.next_if(|s| is_region(s)) {
+            locale.region = Some(region.to_ascii_uppercase());
+        }
+
+        for subtag in subtags {
+            if subtag.len() == 1 {
+                return Err(invalid("extension subtags are not supported"));
+            }
+            if !is_variant(subtag) {
+                return Err(invalid(&format!("unexpected subtag '{}'", subtag)));
+            }
+            locale.variants.push(subtag.to_ascii_lowercase());
+        }
+
+        Ok(locale)
+    }
+
+    /// Canonical tag, also used as the catalog filename stem ("pt-BR" -> pt-BR.json)
+    pub fn tag(&self) -> String {
+        self.to_string()
+    }
+
+    /// Script, inferred for Chinese when only a region is given
+    /// e.g., "zh-TW" -> "Hant", "zh" -> "Hans"
+    pub fn script_or_default(&self) -> Option<&str> {
+        if let Some(script) = &self.script {
+            return Some(script);
+        }
+        match (self.language.as_str(), self.region.as_deref()) {
+            ("zh", Some("TW" | "HK" | "MO")) => Some("Hant"),
+            ("zh", _) => Some("Hans"),
+            _ => None,
+        }
+    }
+
+    /// Human-readable name for prompts and messages
+    /// e.g., "de" -> "German", "pt-BR" -> "Portuguese (Brazil)", "zh-Hant" -> "Chinese (Traditional)"
+    pub fn display_name(&self) -> String {
+        let language = language_name(&self.language)
+            .map(str::to_string)
+            .unwrap_or_else(|| self.language.to_uppercase());
+
+        let mut qualifiers = Vec::new();
+        if let Some(script) = &self.script {
+            qualifiers.push(script_name(script).unwrap_or(script).to_string());
+        }
+        if let Some(region) = &self.region {
+            qualifiers.push(region_name(region).unwrap_or(region).to_string());
+        }
+
+        if qualifiers.is_empty() {
+            language
+        } else {
+            format!("{} ({})", language, qualifiers.join(", "))
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.language)?;
+        if let Some(script) = &self.script {
+            write!(f, "-{}", script)?;
+        }
+        if let Some(region) = &self.region {
+            write!(f, "-{}", region)?;
+        }
+        for variant in &self.variants {
+            write!(f, "-{}", variant)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_script(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_region(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_variant(subtag: &str) -> bool {
+    subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        && ((5..=8).contains(&subtag.len())
+            || (subtag.len() == 4 && subtag.starts_with(|c: char| c.is_ascii_digit())))
+}
+
+fn titlecase(subtag: &str) -> String {
+    let lower = subtag.to_ascii_lowercase();
+    let mut chars = lower.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+fn language_name(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "ar" => "Arabic",
+        "bg" => "Bulgarian",
+        "cs" => "Czech",
+        "da" => "Danish",
+        "de" => "German",
+        "el" => "Greek",
+        "en" => "English",
+        "es" => "Spanish",
+        "et" => "Estonian",
+        "fi" => "Finnish",
+        "fr" => "French",
+        "he" => "Hebrew",
+        "hi" => "Hindi",
+        "hu" => "Hungarian",
+        "id" => "Indonesian",
+        "it" => "Italian",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "lt" => "Lithuanian",
+        "lv" => "Latvian",
+        "nb" => "Norwegian Bokmål",
+        "nl" => "Dutch",
+        "pl" => "Polish",
+        "pt" => "Portuguese",
+        "ro" => "Romanian",
+        "ru" => "Russian",
+        "sk" => "Slovak",
+        "sl" => "Slovenian",
+        "sr" => "Serbian",
+        "sv" => "Swedish",
+        "th" => "Thai",
+        "tr" => "Turkish",
+        "uk" => "Ukrainian",
+        "vi" => "Vietnamese",
+        "zh" => "Chinese",
+        _ => return None,
+    })
+}
+
+fn script_name(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "Hans" => "Simplified",
+        "Hant" => "Traditional",
+        "Latn" => "Latin",
+        "Cyrl" => "Cyrillic",
+        _ => return None,
+    })
+}
+
+fn region_name(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "AT" => "Austria",
+        "AU" => "Australia",
+        "BE" => "Belgium",
+        "BR" => "Brazil",
+        "CA" => "Canada",
+        "CH" => "Switzerland",
+        "CN" => "China",
+        "DE" => "Germany",
+        "ES" => "Spain",
+        "FR" => "France",
+        "GB" => "United Kingdom",
+        "HK" => "Hong Kong",
+        "IN" => "India",
+        "MX" => "Mexico",
+        "PT" => "Portugal",
+        "TW" => "Taiwan",
+        "US" => "United States",
+        "419" => "Latin America",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_canonicalizes() {
+        assert_eq!(Locale::parse("EN").unwrap().tag(), "en");
+        assert_eq!(Locale::parse("pt_br").unwrap().tag(), "pt-BR");
+        assert_eq!(Locale::parse("zh-hant-tw").unwrap().tag(), "zh-Hant-TW");
+        assert_eq!(Locale::parse("es-419").unwrap().tag(), "es-419");
+        assert_eq!(
+            Locale::parse("ca-ES-VALENCIA").unwrap().tag(),
+            "ca-ES-valencia"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_tags() {
+        assert!(Locale::parse("").is_err());
+        assert!(Locale::parse("english").is_err());
+        assert!(Locale::parse("en-").is_err());
+        assert!(Locale::parse("de-x-formal").is_err());
+        assert!(Locale::parse("messages").is_err());
+    }
+
+    #[test]
+    fn test_script_inference() {
+        let script = |tag: &str| {
+            Locale::parse(tag)
+                .unwrap()
+                .script_or_default()
+                .map(str::to_string)
+        };
+        assert_eq!(script("zh").as_deref(), Some("Hans"));
+        assert_eq!(script("zh-TW").as_deref(), Some("Hant"));
+        assert_eq!(script("zh-Hant").as_deref(), Some("Hant"));
+        assert_eq!(script("de"), None);
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(Locale::parse("de").unwrap().display_name(), "German");
+        assert_eq!(
+            Locale::parse("pt-BR").unwrap().display_name(),
+            "Portuguese (Brazil)"
+        );
+        assert_eq!(
+            Locale::parse("zh-Hant").unwrap().display_name(),
+            "Chinese (Traditional)"
+        );
+        assert_eq!(Locale::parse("xh").unwrap().display_name(), "XH");
+    }
+}
@@ -1,6 +1,15 @@
+pub mod i18next;
+pub mod icu;
+pub mod journal;
+pub mod locale;
 pub mod models;
+pub mod plural;
 pub mod ports;
+pub mod quality;
+pub mod review;
 
+#[allow(unused_imports)]
+pub use locale::Locale;
 #[allow(unused_imports)]
 pub use models::*;
 #[allow(unused_imports)]
@@ -1,5 +1,9 @@
+use super::icu::{Message, Part};
+use super::locale::Locale;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 /// Represents a translatable string extracted from code
 #[allow(dead_code)]
@@ -9,6 +13,9 @@ pub struct TranslationKey {
     pub source: String,    // Original string: "Login"
     pub file_path: String, // Where it was found
     pub line: usize,
+    pub component: Option<String>, // Enclosing React component, e.g. "SettingsForm"
+    pub element: Option<String>,   // Enclosing JSX element, e.g. "button"
+    pub comment: Option<String>,   // Developer note from an `// i18n:` comment
 }
 
 /// Represents a language and its translations
@@ -36,6 +43,35 @@ impl LanguageFile {
     pub fn get(&self, key: &str) -> Option<&String> {
         self.translations.get(key)
     }
+
+    /// Parse a catalog written in either `CatalogFormat`
+    pub fn parse(content: &str) -> anyhow::Result<Self> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Entry {
+            Text(String),
+            Extracted(ExtractedMessage),
+        }
+
+        let entries: HashMap<String, Entry> = serde_json::from_str(content)?;
+        let translations = entries
+            .into_iter()
+            .map(|(key, entry)| match entry {
+                Entry::Text(text) => (key, text),
+                Entry::Extracted(message) => (key, message.default_message),
+            })
+            .collect();
+        Ok(Self { translations })
+    }
+}
+
+/// Entry of a FormatJS extracted-messages catalog
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtractedMessage {
+    pub default_message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// Supported file types for extraction
@@ -92,6 +128,33 @@ pub struct TranslationKeyWithPosition {
     pub start_byte: usize, // Position in file (bytes)
     pub end_byte: usize,
     pub quote_type: QuoteType,
+    /// Runtime values of a plural message as (argument, expression), the count first
+    pub params: Vec<(String, String)>,
+}
+
+/// Where and why a rewritten file no longer parses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// How commands that rewrite source files treat a git working tree
+#[derive(Debug, Clone, Default)]
+pub struct GitSafety {
+    /// Write even if the affected files have uncommitted changes
+    pub force: bool,
+    /// Create and switch to this branch before writing
+    pub branch: Option<String>,
+    /// Commit the written files, and only them, afterwards
+    pub commit: bool,
 }
 
 /// Type of quotes used in string literals
@@ -102,14 +165,103 @@ pub enum QuoteType {
     Single,   // 'text'
     Template, // `text`
     JsxText,  // <div>text</div>
+    Plural,   // count === 1 ? "item" : "items"
 }
 
 /// Strategy for code replacement (how to generate translation calls)
 #[derive(Debug, Clone)]
 pub enum ReplacementStrategy {
-    ReactI18n, // {t("key")} with react-i18next
-    VueI18n,   // {{ $t('key') }} with vue-i18n
-    Generic,   // t("key") with generic import
+    ReactI18n,              // {t("key")} with react-i18next
+    VueI18n,                // {{ $t('key') }} with vue-i18n
+    Generic,                // t("key") with generic import
+    Custom(CustomStrategy), // project templates from langsmith.toml
+    /// <FormattedMessage id="key" /> and intl.formatMessage({ id: "key" }) with react-intl;
+    /// with `define_messages`, descriptors come from a `defineMessages` block in each file
+    ReactIntl {
+        define_messages: bool,
+    },
+    /// t("key") with next-intl: `useTranslations` in client components and hooks,
+    /// `await getTranslations()` in async server functions; keys are relative to `namespace`
+    NextIntl {
+        namespace: Option<String>,
+    },
+    /// t`Text`, <Trans>Text</Trans> and plural() with Lingui macros; ids come from the source text
+    Lingui,
+}
+
+/// Where a translation call is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallContext {
+    Expression, // const label = t("key")
+    Attribute,  // <input placeholder={t("key")} />
+    JsxText,    // <p>{t("key")}</p>
+}
+
+/// Replacement strategy for a project's own i18n wrapper, `[strategies.<name>]` in langsmith.toml
+///
+/// Templates take `{key}` for the translation key and `{args}` for the options
+/// argument of runtime values, e.g. `, { name }`.
+///
+/// # Example
+/// ```toml
+/// [strategies.tr]
+/// import = "import { tr, useTr } from '@/lib/i18n';"
+/// expression = "tr('{key}'{args})"
+/// jsx = "{tr('{key}'{args})}"
+/// attribute = "{tr('{key}'{args})}"
+/// hook = "const { tr } = useTr();"   # optional, declared in components and hooks
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CustomStrategy {
+    pub import: String,
+    pub expression: String,
+    pub jsx: String,
+    pub attribute: String,
+    #[serde(default)]
+    pub hook: Option<String>,
+}
+
+impl CustomStrategy {
+    /// Check the templates can produce a call for every key
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (name, template) in [
+            ("expression", &self.expression),
+            ("jsx", &self.jsx),
+            ("attribute", &self.attribute),
+        ] {
+            if !template.contains("{key}") {
+                return Err(anyhow::anyhow!("`{}` template has no {{key}}", name));
+            }
+        }
+        if let Some(hook) = &self.hook {
+            if leading_call(&self.expression).is_none() {
+                return Err(anyhow::anyhow!(
+                    "`hook` needs an `expression` calling a plain function, like tr('{{key}}')"
+                ));
+            }
+            if leading_call(
+                hook.split_once('=')
+                    .map_or(hook.as_str(), |(_, value)| value),
+            )
+            .is_none()
+            {
+                return Err(anyhow::anyhow!(
+                    "`hook` must call a function, like const {{ tr }} = useTr();"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Name of the function a template calls first: `tr` in `{tr('{key}')}`
+fn leading_call(template: &str) -> Option<&str> {
+    let code = template.trim_start_matches(|c: char| c == '{' || c.is_whitespace());
+    let end = code
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(code.len());
+    let name = &code[..end];
+    (!name.is_empty() && code[end..].trim_start().starts_with('(')).then_some(name)
 }
 
 impl ReplacementStrategy {
@@ -119,34 +271,827 @@ impl ReplacementStrategy {
             "react-i18n" => Ok(Self::ReactI18n),
             "vue-i18n" => Ok(Self::VueI18n),
             "generic" => Ok(Self::Generic),
+            "react-intl" | "formatjs" => Ok(Self::ReactIntl {
+                define_messages: false,
+            }),
+            "next-intl" => Ok(Self::NextIntl { namespace: None }),
+            "lingui" => Ok(Self::Lingui),
             _ => Err(anyhow::anyhow!(
-                "Unknown strategy: {}. Supported: react-i18n, vue-i18n, generic",
+                "Unknown strategy: {}. Supported: react-i18n, vue-i18n, generic, react-intl, next-intl, lingui, or a [strategies.<name>] entry in langsmith.toml",
                 s
             )),
         }
     }
 
-    #[allow(dead_code)]
-    pub fn import_statement(&self) -> &str {
+    /// Imports the calls need; only the names a file uses are imported
+    pub fn import_statements(&self) -> Vec<&str> {
         match self {
-            Self::ReactI18n => "import { useTranslation } from 'react-i18next';",
-            Self::VueI18n => "import { useI18n } from 'vue-i18n';",
-            Self::Generic => "import { t } from './i18n';",
+            Self::ReactI18n => vec!["import { useTranslation } from 'react-i18next';"],
+            Self::VueI18n => vec!["import { useI18n } from 'vue-i18n';"],
+            Self::Generic => vec!["import { t } from './i18n';"],
+            Self::Custom(custom) => vec![&custom.import],
+            Self::ReactIntl { .. } => {
+                vec!["import { FormattedMessage, defineMessages, useIntl } from 'react-intl';"]
+            }
+            Self::NextIntl { .. } => vec![
+                "import { useTranslations } from 'next-intl';",
+                "import { getTranslations } from 'next-intl/server';",
+            ],
+            Self::Lingui => vec![
+                "import { Plural, Trans, useLingui } from '@lingui/react/macro';",
+                "import { plural, t } from '@lingui/core/macro';",
+            ],
         }
     }
 
-    #[allow(dead_code)]
-    pub fn translate_call(&self, key: &str, in_jsx: bool) -> String {
+    /// Name of the translation function the calls use
+    pub fn function(&self) -> &str {
+        match self {
+            Self::Custom(custom) => leading_call(&custom.expression).unwrap_or("t"),
+            Self::ReactIntl { .. } => "intl.formatMessage",
+            _ => "t",
+        }
+    }
+
+    /// Hook giving components their `t`, e.g. `useTranslation`
+    pub fn hook(&self) -> Option<&str> {
         match self {
+            Self::ReactI18n => Some("useTranslation"),
+            Self::VueI18n => Some("useI18n"),
+            Self::Generic => None,
+            Self::ReactIntl { .. } => Some("useIntl"),
+            Self::NextIntl { .. } => Some("useTranslations"),
+            Self::Lingui => Some("useLingui"),
+            Self::Custom(custom) => {
+                let hook = custom.hook.as_deref()?;
+                leading_call(hook.split_once('=').map_or(hook, |(_, value)| value))
+            }
+        }
+    }
+
+    /// Statement calling the hook, e.g. `const { t } = useTranslation();`
+    pub fn hook_declaration(&self) -> Option<String> {
+        match self {
+            Self::Custom(custom) => custom.hook.clone(),
+            Self::ReactIntl { .. } => Some("const intl = useIntl();".to_string()),
+            Self::NextIntl { namespace } => Some(format!(
+                "const t = useTranslations({});",
+                namespace.as_deref().map(js_string).unwrap_or_default()
+            )),
+            _ => self
+                .hook()
+                .map(|hook| format!("const {{ {} }} = {}();", self.function(), hook)),
+        }
+    }
+
+    /// Statement for async server functions, which cannot call hooks
+    pub fn async_declaration(&self) -> Option<String> {
+        match self {
+            Self::NextIntl { namespace } => Some(format!(
+                "const t = await getTranslations({});",
+                namespace.as_deref().map(js_string).unwrap_or_default()
+            )),
+            _ => None,
+        }
+    }
+
+    /// Shared i18n instance and its import, for calls outside components and hooks
+    pub fn instance(&self) -> Option<(&str, &str)> {
+        match self {
+            Self::ReactI18n => Some(("i18n", "import i18n from 'i18next';")),
+            _ => None,
+        }
+    }
+
+    /// Call for `key`; `source` is the default message for strategies that keep one in code
+    pub fn translate_call(
+        &self,
+        key: &str,
+        source: &str,
+        context: CallContext,
+        params: &[(String, String)],
+    ) -> String {
+        let args = self.call_args(params);
+        match self {
+            Self::NextIntl { .. } => {
+                if context == CallContext::Expression {
+                    format!("t({}{})", js_string(key), args)
+                } else {
+                    format!("{{t({}{})}}", js_string(key), args)
+                }
+            }
+            Self::Lingui => lingui_call(source, context, params),
             Self::ReactI18n => {
-                if in_jsx {
-                    format!("{{t(\"{}\")}}", key)
+                if context == CallContext::Expression {
+                    format!("t(\"{}\"{})", key, args)
                 } else {
-                    format!("t(\"{}\")", key)
+                    format!("{{t(\"{}\"{})}}", key, args)
                 }
             }
-            Self::VueI18n => format!("{{{{ $t('{}') }}}}", key),
-            Self::Generic => format!("t(\"{}\")", key),
+            Self::VueI18n => format!("{{{{ $t('{}'{}) }}}}", key, args),
+            Self::Generic => format!("t(\"{}\"{})", key, args),
+            Self::Custom(custom) => {
+                let template = match context {
+                    CallContext::Expression => &custom.expression,
+                    CallContext::Attribute => &custom.attribute,
+                    CallContext::JsxText => &custom.jsx,
+                };
+                template.replace("{key}", key).replace("{args}", &args)
+            }
+            Self::ReactIntl { define_messages } => {
+                let descriptor = if *define_messages {
+                    message_ref(key)
+                } else {
+                    format!(
+                        "{{ id: {}, defaultMessage: {} }}",
+                        js_string(key),
+                        js_string(source)
+                    )
+                };
+                match context {
+                    CallContext::JsxText => {
+                        let props = if *define_messages {
+                            format!("{{...{}}}", descriptor)
+                        } else {
+                            format!(
+                                "id={} defaultMessage={}",
+                                jsx_string(key),
+                                jsx_string(source)
+                            )
+                        };
+                        let values = self
+                            .values(params)
+                            .map(|values| format!(" values={{{}}}", values))
+                            .unwrap_or_default();
+                        format!("<FormattedMessage {}{} />", props, values)
+                    }
+                    CallContext::Expression => {
+                        format!("intl.formatMessage({}{})", descriptor, args)
+                    }
+                    CallContext::Attribute => {
+                        format!("{{intl.formatMessage({}{})}}", descriptor, args)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Colocated `defineMessages` block for the keys replaced in a file, if the strategy uses one
+    pub fn messages_block(&self, messages: &[(&str, &str)]) -> Option<String> {
+        if !matches!(
+            self,
+            Self::ReactIntl {
+                define_messages: true
+            }
+        ) || messages.is_empty()
+        {
+            return None;
+        }
+        let mut block = String::from("const messages = defineMessages({\n");
+        for (key, source) in messages {
+            let property = if is_identifier(key) {
+                key.to_string()
+            } else {
+                js_string(key)
+            };
+            block.push_str(&format!(
+                "  {}: {{\n    id: {},\n    defaultMessage: {},\n  }},\n",
+                property,
+                js_string(key),
+                js_string(source)
+            ));
+        }
+        block.push_str("});");
+        Some(block)
+    }
+
+    /// Options argument for runtime values, e.g. `, { count: items.length, name }`
+    fn call_args(&self, params: &[(String, String)]) -> String {
+        self.values(params)
+            .map(|values| format!(", {}", values))
+            .unwrap_or_default()
+    }
+
+    /// Object of runtime values, e.g. `{ count: items.length, name }`
+    fn values(&self, params: &[(String, String)]) -> Option<String> {
+        if params.is_empty() {
+            return None;
+        }
+        let fields: Vec<String> = params
+            .iter()
+            .enumerate()
+            .map(|(i, (name, expr))| {
+                // i18next picks the plural form from `count`
+                let name = match self {
+                    Self::ReactI18n if i == 0 => "count",
+                    _ => name.as_str(),
+                };
+                if name == expr {
+                    name.to_string()
+                } else {
+                    format!("{}: {}", name, expr)
+                }
+            })
+            .collect();
+        Some(format!("{{ {} }}", fields.join(", ")))
+    }
+}
+
+/// Lingui macro for a message; the source text is the message id
+fn lingui_call(source: &str, context: CallContext, params: &[(String, String)]) -> String {
+    if let Some((value, options)) = lingui_plural(source, params) {
+        return match context {
+            CallContext::JsxText => {
+                let props: Vec<String> = options
+                    .iter()
+                    .map(|(name, text)| {
+                        if text.contains("${") || text.contains(['"', '\\']) {
+                            format!("{}={{`{}`}}", name, text)
+                        } else {
+                            format!("{}=\"{}\"", name, text)
+                        }
+                    })
+                    .collect();
+                format!("<Plural value={{{}}} {} />", value, props.join(" "))
+            }
+            CallContext::Expression | CallContext::Attribute => {
+                let fields: Vec<String> = options
+                    .iter()
+                    .map(|(name, text)| format!("{}: `{}`", name, text))
+                    .collect();
+                let call = format!("plural({}, {{ {} }})", value, fields.join(", "));
+                if context == CallContext::Attribute {
+                    format!("{{{}}}", call)
+                } else {
+                    call
+                }
+            }
+        };
+    }
+
+    match context {
+        CallContext::Expression => format!("t`{}`", template_text(source)),
+        CallContext::Attribute => format!("{{t`{}`}}", template_text(source)),
+        CallContext::JsxText => format!("<Trans>{}</Trans>", source),
+    }
+}
+
+/// Counted expression and options of a message that is a single ICU plural,
+/// each option as template literal text: `other` -> `${name} has # items`
+fn lingui_plural(
+    source: &str,
+    params: &[(String, String)],
+) -> Option<(String, Vec<(String, String)>)> {
+    let message = Message::parse(source).ok()?;
+    let [
+        Part::Plural {
+            name,
+            ordinal: false,
+            offset: 0,
+            options,
+        },
+    ] = message.parts.as_slice()
+    else {
+        return None;
+    };
+    let expr = |name: &str| {
+        params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map_or(name.to_string(), |(_, expr)| expr.clone())
+    };
+
+    let mut rendered = Vec::new();
+    for (option, message) in options {
+        let mut text = String::new();
+        for part in &message.parts {
+            match part {
+                Part::Text(t) => text.push_str(&template_text(t)),
+                Part::Pound => text.push('#'),
+                Part::Argument { name, format: None } => {
+                    text.push_str(&format!("${{{}}}", expr(name)))
+                }
+                _ => return None,
+            }
         }
+        // `=0` is written `_0`
+        let option = match option.strip_prefix('=') {
+            Some(exact) => format!("_{}", exact),
+            None => option.clone(),
+        };
+        rendered.push((option, text));
+    }
+    Some((expr(name), rendered))
+}
+
+/// Text escaped for a template literal
+fn template_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+/// `messages.key`, or `messages["key"]` for keys that are not identifiers
+fn message_ref(key: &str) -> String {
+    if is_identifier(key) {
+        format!("messages.{}", key)
+    } else {
+        format!("messages[{}]", js_string(key))
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.starts_with(|c: char| c.is_ascii_digit())
+        && !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Double-quoted JavaScript string literal
+fn js_string(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_else(|_| format!("\"{}\"", text))
+}
+
+/// JSX attribute value: a plain string, or an expression when it needs escapes
+fn jsx_string(text: &str) -> String {
+    let literal = js_string(text);
+    if literal[1..literal.len() - 1] == *text {
+        literal
+    } else {
+        format!("{{{}}}", literal)
+    }
+}
+
+/// How plural messages are stored in catalogs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PluralFormat {
+    #[default]
+    Icu, // "items": "{count, plural, one {# item} other {# items}}"
+    I18next, // "items_one": "{{count}} item", "items_other": "{{count}} items"
+}
+
+impl PluralFormat {
+    pub fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "icu" => Ok(Self::Icu),
+            "i18next" => Ok(Self::I18next),
+            _ => Err(anyhow::anyhow!(
+                "Unknown plural format: {}. Supported: icu, i18next",
+                s
+            )),
+        }
+    }
+}
+
+/// How extracted catalogs are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CatalogFormat {
+    #[default]
+    Flat, // "welcome": "Welcome"
+    FormatJs, // "welcome": { "defaultMessage": "Welcome", "description": "..." }
+}
+
+impl CatalogFormat {
+    pub fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "flat" | "json" => Ok(Self::Flat),
+            "formatjs" | "react-intl" => Ok(Self::FormatJs),
+            _ => Err(anyhow::anyhow!(
+                "Unknown catalog format: {}. Supported: flat, formatjs",
+                s
+            )),
+        }
+    }
+}
+
+/// Project glossary: enforced term translations and do-not-translate terms
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Glossary {
+    /// Language the glossary terms are written in (required by DeepL glossaries)
+    #[serde(default)]
+    pub source_lang: Option<String>,
+    /// Terms that must appear verbatim in every translation (brand/product names)
+    #[serde(default)]
+    pub do_not_translate: Vec<String>,
+    /// Term -> (language code -> required translation)
+    #[serde(default)]
+    pub terms: HashMap<String, HashMap<String, String>>,
+}
+
+/// A translation that ignored a glossary entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryViolation {
+    pub key: String,
+    pub target_lang: String,
+    pub term: String,
+    pub expected: String,
+}
+
+impl Glossary {
+    pub fn is_empty(&self) -> bool {
+        self.do_not_translate.is_empty() && self.terms.is_empty()
+    }
+
+    /// All glossary entries for a target language, do-not-translate terms mapped to themselves
+    pub fn entries_for(&self, target_lang: &str) -> Vec<(String, String)> {
+        let mut entries: Vec<(String, String)> = self
+            .do_not_translate
+            .iter()
+            .map(|term| (term.clone(), term.clone()))
+            .collect();
+
+        for (term, translations) in &self.terms {
+            if let Some(expected) = Self::lookup_lang(translations, target_lang) {
+                entries.push((term.clone(), expected.clone()));
+            }
+        }
+
+        entries.sort();
+        entries.dedup_by(|a, b| a.0 == b.0);
+        entries
+    }
+
+    /// Glossary entries whose term occurs in the given source text
+    pub fn entries_in(&self, text: &str, target_lang: &str) -> Vec<(String, String)> {
+        self.entries_for(target_lang)
+            .into_iter()
+            .filter(|(term, _)| Self::mentions(text, term))
+            .collect()
+    }
+
+    /// Check a translated string against the glossary
+    pub fn check(
+        &self,
+        key: &str,
+        source: &str,
+        translated: &str,
+        target_lang: &str,
+    ) -> Vec<GlossaryViolation> {
+        self.entries_in(source, target_lang)
+            .into_iter()
+            .filter(|(_, expected)| !Self::mentions(translated, expected))
+            .map(|(term, expected)| GlossaryViolation {
+                key: key.to_string(),
+                target_lang: target_lang.to_string(),
+                term,
+                expected,
+            })
+            .collect()
+    }
+
+    /// Whether text contains the term as a whole word, ignoring case ("Pro" is not in "Profile")
+    fn mentions(text: &str, term: &str) -> bool {
+        let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        // \b only applies next to word characters, e.g. "C++" ends in a non-word character
+        let start = if is_word(term.chars().next()) {
+            r"\b"
+        } else {
+            ""
+        };
+        let end = if is_word(term.chars().last()) {
+            r"\b"
+        } else {
+            ""
+        };
+        Regex::new(&format!("(?i){}{}{}", start, regex::escape(term), end))
+            .map(|re| re.is_match(text))
+            .unwrap_or(false)
+    }
+
+    /// Find the translation for a language, falling back from "pt-BR" to "pt"
+    fn lookup_lang<'a>(
+        translations: &'a HashMap<String, String>,
+        target_lang: &str,
+    ) -> Option<&'a String> {
+        let normalize = |lang: &str| lang.to_lowercase().replace('_', "-");
+        let wanted = normalize(target_lang);
+        let primary = wanted.split('-').next().unwrap_or(&wanted).to_string();
+
+        translations
+            .iter()
+            .find(|(lang, _)| normalize(lang) == wanted)
+            .or_else(|| {
+                translations
+                    .iter()
+                    .find(|(lang, _)| normalize(lang) == primary)
+            })
+            .map(|(_, value)| value)
+    }
+}
+
+/// Where a key is used in the codebase, passed to translators as context
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyContext {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+}
+
+impl KeyContext {
+    pub fn is_empty(&self) -> bool {
+        self.file_path.is_none()
+            && self.component.is_none()
+            && self.element.is_none()
+            && self.comment.is_none()
+            && self.max_length.is_none()
+    }
+
+    /// One-line description for providers that take free-text context (DeepL, prompts)
+    /// e.g., "Label of the save button. Shown in <button> of SettingsForm."
+    pub fn describe(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(comment) = &self.comment {
+            parts.push(comment.trim_end_matches('.').to_string());
+        }
+        match (&self.element, &self.component) {
+            (Some(element), Some(component)) => {
+                parts.push(format!("Shown in <{}> of {}", element, component))
+            }
+            (Some(element), None) => parts.push(format!("Shown in <{}>", element)),
+            (None, Some(component)) => parts.push(format!("Used in {}", component)),
+            (None, None) => {}
+        }
+        if let Some(max_length) = self.max_length {
+            parts.push(format!("At most {} characters", max_length));
+        }
+
+        (!parts.is_empty()).then(|| format!("{}.", parts.join(". ")))
+    }
+}
+
+/// Where and how a key is used, written by `extract` next to the catalog
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyMetadata {
+    /// Source references as "path:line"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl KeyMetadata {
+    /// Context forwarded to translators; the first reference names the file
+    pub fn to_context(&self) -> KeyContext {
+        KeyContext {
+            file_path: self.references.first().map(|reference| {
+                reference
+                    .rsplit_once(':')
+                    .map_or(reference.as_str(), |(path, _)| path)
+                    .to_string()
+            }),
+            component: self.component.clone(),
+            element: self.element.clone(),
+            comment: self.comment.clone(),
+            max_length: None,
+        }
+    }
+}
+
+/// Translator metadata for a whole catalog: `fr.json` -> `fr.meta.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CatalogMetadata {
+    pub keys: BTreeMap<String, KeyMetadata>,
+}
+
+impl CatalogMetadata {
+    /// Companion file of a catalog, e.g. "i18n/fr.json" -> "i18n/fr.meta.json"
+    pub fn path_for(catalog: &Path) -> PathBuf {
+        let stem = catalog
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        catalog.with_file_name(format!("{}.meta.json", stem))
+    }
+
+    /// Record one occurrence of a key
+    ///
+    /// Keys used in several places keep every reference, the first component
+    /// and element found, and all distinct comments.
+    pub fn add(&mut self, key: &TranslationKey) {
+        let entry = self.keys.entry(key.id.clone()).or_default();
+
+        let reference = format!("{}:{}", key.file_path, key.line);
+        if !entry.references.contains(&reference) {
+            entry.references.push(reference);
+        }
+        if entry.component.is_none() {
+            entry.component = key.component.clone();
+        }
+        if entry.element.is_none() {
+            entry.element = key.element.clone();
+        }
+        if let Some(comment) = &key.comment {
+            match &mut entry.comment {
+                Some(existing) if !existing.contains(comment.as_str()) => {
+                    existing.push_str("; ");
+                    existing.push_str(comment);
+                }
+                Some(_) => {}
+                None => entry.comment = Some(comment.clone()),
+            }
+        }
+    }
+
+    /// Key -> context for `TranslateOptions::contexts`
+    pub fn contexts(&self) -> HashMap<String, KeyContext> {
+        self.keys
+            .iter()
+            .map(|(key, metadata)| (key.clone(), metadata.to_context()))
+            .collect()
+    }
+}
+
+/// A single key to translate, with its source text and context
+#[derive(Debug, Clone)]
+pub struct TranslationUnit {
+    pub key: String,
+    pub text: String,
+    pub context: KeyContext,
+}
+
+/// Formal or informal address of the reader
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Formality {
+    Formal,
+    Informal,
+}
+
+/// Per-language style settings (`[styles.<lang>]` in langsmith.toml)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TranslationStyle {
+    /// DeepL `formality`; an instruction in LLM prompts
+    #[serde(default)]
+    pub formality: Option<Formality>,
+    /// Tone or style guide for LLM providers, e.g. "friendly, concise, no exclamation marks"
+    #[serde(default)]
+    pub tone: Option<String>,
+}
+
+impl TranslationStyle {
+    pub fn is_empty(&self) -> bool {
+        self.formality.is_none() && self.tone.is_none()
+    }
+}
+
+/// Everything a provider needs besides the text: languages, style and key context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationRequest {
+    /// Locale of the source catalog; None lets the provider auto-detect
+    pub source: Option<Locale>,
+    pub target: Locale,
+    pub style: TranslationStyle,
+    /// Where the single key being translated is used; batches carry it per unit
+    pub context: Option<KeyContext>,
+}
+
+impl TranslationRequest {
+    pub fn new(source: Option<Locale>, target: Locale) -> Self {
+        Self {
+            source,
+            target,
+            style: TranslationStyle::default(),
+            context: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: TranslationStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Attach a key's context, ignored when it is empty
+    pub fn with_context(mut self, context: &KeyContext) -> Self {
+        self.context = (!context.is_empty()).then(|| context.clone());
+        self
+    }
+
+    /// Parse both tags, e.g. `TranslationRequest::parse(Some("en"), "pt-BR")`
+    #[allow(dead_code)]
+    pub fn parse(source: Option<&str>, target: &str) -> anyhow::Result<Self> {
+        Ok(Self::new(
+            source.map(Locale::parse).transpose()?,
+            Locale::parse(target)?,
+        ))
+    }
+
+    /// Whether the target is the source locale itself ("en" -> "EN"); "en" -> "en-GB" is allowed
+    pub fn is_same_language(&self) -> bool {
+        self.source.as_ref() == Some(&self.target)
+    }
+}
+
+/// What a provider bills for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BillingUnit {
+    /// Source characters (DeepL, Google, Azure)
+    Characters,
+    /// Prompt and completion tokens (LLM providers)
+    Tokens,
+}
+
+/// How a provider bills a translation, used for cost estimates
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostModel {
+    pub provider: String,
+    pub unit: BillingUnit,
+    /// USD per million input units (characters or prompt tokens); None when unknown
+    pub input_price: Option<f64>,
+    /// USD per million completion tokens; unused for character billing
+    pub output_price: Option<f64>,
+}
+
+/// Estimated usage of one target language
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageEstimate {
+    pub characters: usize,
+    /// Prompt plus completion tokens, for token-billed providers
+    pub tokens: Option<usize>,
+    /// USD; None when the provider's pricing is unknown
+    pub cost: Option<f64>,
+}
+
+impl CostModel {
+    /// Rough tokens per character for UI strings in Latin scripts
+    const CHARS_PER_TOKEN: usize = 4;
+    /// System prompt and JSON framing sent with every request
+    const PROMPT_TOKENS_PER_REQUEST: usize = 120;
+
+    pub fn characters(provider: &str, price: f64) -> Self {
+        Self {
+            provider: provider.to_string(),
+            unit: BillingUnit::Characters,
+            input_price: Some(price),
+            output_price: None,
+        }
+    }
+
+    pub fn tokens(provider: &str, prices: Option<(f64, f64)>) -> Self {
+        Self {
+            provider: provider.to_string(),
+            unit: BillingUnit::Tokens,
+            input_price: prices.map(|(input, _)| input),
+            output_price: prices.map(|(_, output)| output),
+        }
+    }
+
+    /// Estimate usage for `characters` of source text sent in `requests` requests
+    ///
+    /// Token counts are a heuristic (4 characters per token, translation about
+    /// as long as the source), good enough to compare providers and budgets.
+    pub fn estimate(&self, characters: usize, requests: usize) -> UsageEstimate {
+        match self.unit {
+            BillingUnit::Characters => UsageEstimate {
+                characters,
+                tokens: None,
+                cost: self
+                    .input_price
+                    .map(|price| characters as f64 * price / 1_000_000.0),
+            },
+            BillingUnit::Tokens => {
+                let text_tokens = characters.div_ceil(Self::CHARS_PER_TOKEN);
+                let prompt_tokens = text_tokens + requests * Self::PROMPT_TOKENS_PER_REQUEST;
+                let cost = self
+                    .input_price
+                    .zip(self.output_price)
+                    .map(|(input, output)| {
+                        (prompt_tokens as f64 * input + text_tokens as f64 * output) / 1_000_000.0
+                    });
+                UsageEstimate {
+                    characters,
+                    tokens: Some(prompt_tokens + text_tokens),
+                    cost,
+                }
+            }
+        }
+    }
+}
+
+/// Character quota reported by a provider (e.g., DeepL's usage endpoint)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quota {
+    pub provider: String,
+    pub used: u64,
+    /// None for unlimited plans
+    pub limit: Option<u64>,
+}
+
+impl Quota {
+    pub fn remaining(&self) -> Option<u64> {
+        self.limit.map(|limit| limit.saturating_sub(self.used))
     }
 }
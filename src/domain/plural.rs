@@ -0,0 +1,269 @@
+use super::locale::Locale;
+
+/// CLDR plural category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub const ALL: [PluralCategory; 6] = [
+        Self::Zero,
+        Self::One,
+        Self::Two,
+        Self::Few,
+        Self::Many,
+        Self::Other,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn parse(keyword: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == keyword)
+    }
+}
+
+/// Cardinal plural rules of a language, grouped by CLDR rule family
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralRules {
+    /// Japanese, Chinese, Korean...: no plural forms
+    None,
+    /// English, German, Dutch...: one = 1
+    OneOther,
+    /// Hindi, Bengali: one = 0 or 1
+    ZeroOneOther,
+    /// Spanish, Italian, Catalan: one = 1, many = millions
+    Romance,
+    /// French, Portuguese: one = 0 or 1, many = millions
+    French,
+    /// Russian, Ukrainian, Belarusian
+    EastSlavic,
+    Polish,
+    /// Czech, Slovak
+    Czech,
+    /// Croatian, Serbian, Bosnian
+    SerboCroatian,
+    Lithuanian,
+    Latvian,
+    Romanian,
+    Slovenian,
+    Hebrew,
+    Arabic,
+}
+
+/// Numbers tried, in order of preference, when picking an example for a category
+const EXAMPLE_CANDIDATES: &[&str] = &["1", "2", "3", "5", "11", "20", "100", "0", "1000000", "1.5"];
+
+/// CLDR operands of a number written as a string ("1.50" -> i=1, v=2, f=50)
+struct Operands {
+    n: f64,
+    i: u64,
+    v: usize,
+    f: u64,
+}
+
+impl Operands {
+    fn parse(number: &str) -> Option<Self> {
+        let number = number.trim().trim_start_matches('-');
+        let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+        Some(Self {
+            n: number.parse().ok()?,
+            i: int.parse().ok()?,
+            v: frac.len(),
+            f: if frac.is_empty() {
+                0
+            } else {
+                frac.parse().ok()?
+            },
+        })
+    }
+}
+
+impl PluralRules {
+    pub fn for_locale(locale: &Locale) -> Self {
+        match locale.language.as_str() {
+            "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "my" | "lo" | "km" | "yue" => {
+                Self::None
+            }
+            "hi" | "bn" | "gu" | "kn" | "mr" | "am" | "fa" | "zu" => Self::ZeroOneOther,
+            "es" | "it" | "ca" => Self::Romance,
+            "fr" | "pt" => Self::French,
+            "ru" | "uk" | "be" => Self::EastSlavic,
+            "pl" => Self::Polish,
+            "cs" | "sk" => Self::Czech,
+            "hr" | "sr" | "bs" => Self::SerboCroatian,
+            "lt" => Self::Lithuanian,
+            "lv" => Self::Latvian,
+            "ro" | "mo" => Self::Romanian,
+            "sl" => Self::Slovenian,
+            "he" | "iw" => Self::Hebrew,
+            "ar" => Self::Arabic,
+            _ => Self::OneOther,
+        }
+    }
+
+    /// Categories the language distinguishes, in CLDR order
+    pub fn categories(&self) -> Vec<PluralCategory> {
+        use PluralCategory::*;
+        match self {
+            Self::None => vec![Other],
+            Self::OneOther | Self::ZeroOneOther => vec![One, Other],
+            Self::Romance | Self::French => vec![One, Many, Other],
+            Self::EastSlavic | Self::Polish | Self::Czech | Self::Lithuanian => {
+                vec![One, Few, Many, Other]
+            }
+            Self::SerboCroatian | Self::Romanian => vec![One, Few, Other],
+            Self::Latvian => vec![Zero, One, Other],
+            Self::Slovenian => vec![One, Two, Few, Other],
+            Self::Hebrew => vec![One, Two, Other],
+            Self::Arabic => vec![Zero, One, Two, Few, Many, Other],
+        }
+    }
+
+    /// Category of a number such as "1", "21" or "1.5"; unparsable numbers are `other`
+    pub fn category(&self, number: &str) -> PluralCategory {
+        use PluralCategory::*;
+        let Some(Operands { n, i, v, f }) = Operands::parse(number) else {
+            return Other;
+        };
+        let integer = v == 0;
+        let millions = integer && i != 0 && i % 1_000_000 == 0;
+
+        match self {
+            Self::None => Other,
+            Self::OneOther if i == 1 && integer => One,
+            Self::ZeroOneOther if i == 0 || n == 1.0 => One,
+            Self::Romance if i == 1 && integer => One,
+            Self::Romance if millions => Many,
+            Self::French if i <= 1 => One,
+            Self::French if millions => Many,
+            Self::EastSlavic if !integer => Other,
+            Self::EastSlavic if i % 10 == 1 && i % 100 != 11 => One,
+            Self::EastSlavic if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) => {
+                Few
+            }
+            Self::EastSlavic => Many,
+            Self::Polish if !integer => Other,
+            Self::Polish if i == 1 => One,
+            Self::Polish if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) => Few,
+            Self::Polish => Many,
+            Self::Czech if !integer => Many,
+            Self::Czech if i == 1 => One,
+            Self::Czech if (2..=4).contains(&i) => Few,
+            Self::SerboCroatian
+                if (integer && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) =>
+            {
+                One
+            }
+            Self::SerboCroatian
+                if (integer && (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)))
+                    || ((2..=4).contains(&(f % 10)) && !(12..=14).contains(&(f % 100))) =>
+            {
+                Few
+            }
+            Self::Lithuanian if f != 0 => Many,
+            Self::Lithuanian if i % 10 == 1 && !(11..=19).contains(&(i % 100)) => One,
+            Self::Lithuanian if (2..=9).contains(&(i % 10)) && !(11..=19).contains(&(i % 100)) => {
+                Few
+            }
+            Self::Latvian if integer && (i % 10 == 0 || (11..=19).contains(&(i % 100))) => Zero,
+            Self::Latvian if integer && i % 10 == 1 && i % 100 != 11 => One,
+            Self::Romanian if i == 1 && integer => One,
+            Self::Romanian if !integer || i == 0 || (1..=19).contains(&(i % 100)) => Few,
+            Self::Slovenian if !integer => Few,
+            Self::Slovenian if i % 100 == 1 => One,
+            Self::Slovenian if i % 100 == 2 => Two,
+            Self::Slovenian if (3..=4).contains(&(i % 100)) => Few,
+            Self::Hebrew if (i == 1 && integer) || (i == 0 && !integer) => One,
+            Self::Hebrew if i == 2 && integer => Two,
+            Self::Arabic if n == 0.0 => Zero,
+            Self::Arabic if n == 1.0 => One,
+            Self::Arabic if n == 2.0 => Two,
+            Self::Arabic if integer && (3..=10).contains(&(i % 100)) => Few,
+            Self::Arabic if integer && (11..=99).contains(&(i % 100)) => Many,
+            _ => Other,
+        }
+    }
+
+    /// A number in the category, used to render a sample sentence for translators
+    /// e.g., Russian few -> "2", many -> "5", other -> "1.5"; `other` is never "1"
+    pub fn example(&self, category: PluralCategory) -> Option<&'static str> {
+        EXAMPLE_CANDIDATES
+            .iter()
+            .copied()
+            .filter(|number| category != PluralCategory::Other || *number != "1")
+            .find(|number| self.category(number) == category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PluralCategory::*;
+
+    fn rules(tag: &str) -> PluralRules {
+        PluralRules::for_locale(&Locale::parse(tag).unwrap())
+    }
+
+    #[test]
+    fn test_categories_per_language() {
+        assert_eq!(rules("en-GB").categories(), vec![One, Other]);
+        assert_eq!(rules("ja").categories(), vec![Other]);
+        assert_eq!(rules("pt-BR").categories(), vec![One, Many, Other]);
+        assert_eq!(rules("ru").categories(), vec![One, Few, Many, Other]);
+        assert_eq!(
+            rules("ar").categories(),
+            vec![Zero, One, Two, Few, Many, Other]
+        );
+    }
+
+    #[test]
+    fn test_category_rules() {
+        let ru = rules("ru");
+        assert_eq!(ru.category("1"), One);
+        assert_eq!(ru.category("21"), One);
+        assert_eq!(ru.category("11"), Many);
+        assert_eq!(ru.category("3"), Few);
+        assert_eq!(ru.category("12"), Many);
+        assert_eq!(ru.category("1.5"), Other);
+
+        assert_eq!(rules("fr").category("0"), One);
+        assert_eq!(rules("fr").category("1000000"), Many);
+        assert_eq!(rules("en").category("0"), Other);
+        assert_eq!(rules("en").category("1.0"), Other);
+        assert_eq!(rules("pl").category("22"), Few);
+        assert_eq!(rules("pl").category("25"), Many);
+        assert_eq!(rules("ar").category("105"), Few);
+    }
+
+    #[test]
+    fn test_examples_match_their_category() {
+        for tag in [
+            "en", "fr", "ru", "pl", "cs", "hr", "lt", "lv", "ro", "sl", "he", "ar", "ja",
+        ] {
+            let rules = rules(tag);
+            for category in rules.categories() {
+                let example = rules
+                    .example(category)
+                    .unwrap_or_else(|| panic!("{} has no example for {:?}", tag, category));
+                assert_eq!(rules.category(example), category, "{} {}", tag, example);
+            }
+        }
+        assert_eq!(rules("ru").example(Few), Some("2"));
+        assert_eq!(rules("ru").example(Many), Some("5"));
+    }
+}
@@ -1,8 +1,13 @@
+use crate::domain::locale::Locale;
 use crate::domain::models::{
-    FileType, LanguageFile, ReplacementStrategy, TranslationKey, TranslationKeyWithPosition,
+    CatalogMetadata, CostModel, ExtractedMessage, FileType, LanguageFile, Quota,
+    ReplacementStrategy, SyntaxError, TranslationKey, TranslationKeyWithPosition,
+    TranslationRequest, TranslationUnit,
 };
 use async_trait::async_trait;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Port: Responsible for extracting strings from files
 #[async_trait]
@@ -14,6 +19,17 @@ pub trait StringExtractor: Send + Sync {
         path: &Path,
         file_type: FileType,
     ) -> anyhow::Result<Vec<TranslationKey>>;
+
+    /// Extract strings with the byte positions needed to replace them
+    ///
+    /// None when the extractor does not track positions.
+    async fn extract_with_positions(
+        &self,
+        _path: &Path,
+        _file_type: FileType,
+    ) -> anyhow::Result<Option<Vec<TranslationKeyWithPosition>>> {
+        Ok(None)
+    }
 }
 
 /// Port: Responsible for writing translation files
@@ -24,8 +40,18 @@ pub trait FileWriter: Send + Sync {
     async fn write_language_file(&self, path: &Path, language: &LanguageFile)
     -> anyhow::Result<()>;
 
+    /// Write a catalog in FormatJS's extracted-messages format
+    async fn write_extracted_messages(
+        &self,
+        path: &Path,
+        messages: &BTreeMap<String, ExtractedMessage>,
+    ) -> anyhow::Result<()>;
+
     /// Read a language file from disk
     async fn read_language_file(&self, path: &Path) -> anyhow::Result<LanguageFile>;
+
+    /// Write translator metadata (references, components, comments) to disk
+    async fn write_metadata(&self, path: &Path, metadata: &CatalogMetadata) -> anyhow::Result<()>;
 }
 
 /// Port: Responsible for finding files to process
@@ -40,8 +66,45 @@ pub trait FileScanner: Send + Sync {
 #[async_trait]
 #[allow(unused)]
 pub trait Translator: Send + Sync {
-    /// Translate text to target language
-    async fn translate(&self, text: &str, target_lang: &str) -> anyhow::Result<String>;
+    /// Translate text from the source to the target language
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String>;
+
+    /// Translate several keys at once, returning key -> translated text
+    ///
+    /// The default implementation translates each unit separately; providers
+    /// that can handle a whole chunk in one request override it.
+    async fn translate_batch(
+        &self,
+        units: &[TranslationUnit],
+        req: &TranslationRequest,
+    ) -> anyhow::Result<HashMap<String, String>> {
+        let mut translations = HashMap::new();
+        for unit in units {
+            let text = self.translate(&unit.text, req).await?;
+            translations.insert(unit.key.clone(), text);
+        }
+        Ok(translations)
+    }
+
+    /// Whether the provider can translate into this locale
+    fn supports_language(&self, _target: &Locale) -> bool {
+        true
+    }
+
+    /// Delay between requests to avoid API throttling
+    fn rate_limit(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    /// How translating into this locale is billed, for `--estimate`
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        None
+    }
+
+    /// Character quotas reported by the provider's usage endpoint, if it has one
+    async fn quota(&self) -> anyhow::Result<Vec<Quota>> {
+        Ok(Vec::new())
+    }
 }
 
 /// Port: Responsible for replacing strings in source code
@@ -69,3 +132,49 @@ pub trait ImportManager: Send + Sync {
         strategy: &ReplacementStrategy,
     ) -> anyhow::Result<String>;
 }
+
+/// Port: Responsible for declaring the translation function where calls were inserted
+#[async_trait]
+#[allow(unused)]
+pub trait HookInjector: Send + Sync {
+    /// Call the strategy's hook in components and hooks that use `t`,
+    /// and route calls made outside them through the i18n instance
+    async fn inject_hooks(
+        &self,
+        content: &str,
+        file_type: FileType,
+        strategy: &ReplacementStrategy,
+    ) -> anyhow::Result<String>;
+
+    /// Positions among `positions` where a call would have no translation function,
+    /// e.g. outside components for a strategy whose function only comes from its hook
+    fn unreachable(
+        &self,
+        content: &str,
+        file_type: FileType,
+        strategy: &ReplacementStrategy,
+        positions: &[usize],
+    ) -> Vec<usize>;
+}
+
+/// Port: Responsible for checking that rewritten code still parses
+pub trait SyntaxValidator: Send + Sync {
+    /// First syntax error in `content`, or None if it parses
+    fn validate(&self, content: &str, file_type: FileType) -> Option<SyntaxError>;
+}
+
+/// Port: Responsible for the version control state of the files langsmith rewrites
+#[async_trait]
+pub trait VersionControl: Send + Sync {
+    /// Top directory of the working tree
+    fn root(&self) -> &Path;
+
+    /// Paths among `paths` with uncommitted changes, untracked files included
+    async fn dirty_files(&self, paths: &[PathBuf]) -> anyhow::Result<Vec<PathBuf>>;
+
+    /// Create a branch at the current commit and switch to it
+    async fn create_branch(&self, name: &str) -> anyhow::Result<()>;
+
+    /// Commit `paths` and nothing else, whatever else is staged
+    async fn commit(&self, paths: &[PathBuf], message: &str) -> anyhow::Result<()>;
+}
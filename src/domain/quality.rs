@@ -0,0 +1,183 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Back-translations scoring below this are flagged
+pub const MIN_SIMILARITY: f64 = 0.5;
+
+/// Accepted translation/source length ratio, checked for sources of at least
+/// `MIN_RATIO_CHARS` characters since short labels vary too much
+pub const LENGTH_RATIO_BOUNDS: (f64, f64) = (0.3, 3.0);
+const MIN_RATIO_CHARS: usize = 8;
+
+/// Why a translation looks suspicious
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityIssue {
+    Empty,
+    Unchanged,
+    LowSimilarity,
+    LengthRatio,
+    BackTranslationFailed,
+}
+
+impl QualityIssue {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Self::Empty => "empty translation",
+            Self::Unchanged => "identical to the source",
+            Self::LowSimilarity => "back-translation differs from the source",
+            Self::LengthRatio => "length very different from the source",
+            Self::BackTranslationFailed => "back-translation failed",
+        }
+    }
+}
+
+/// Result of verifying one translation by translating it back
+#[derive(Debug, Clone, Serialize)]
+pub struct QualityCheck {
+    pub key: String,
+    pub target_lang: String,
+    pub source: String,
+    pub translation: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub back_translation: Option<String>,
+    /// 0.0 (nothing in common) to 1.0 (same text)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<f64>,
+    pub issues: Vec<QualityIssue>,
+}
+
+impl QualityCheck {
+    pub fn new(
+        key: &str,
+        target_lang: &str,
+        source: &str,
+        translation: &str,
+        back_translation: Option<String>,
+    ) -> Self {
+        let mut issues = Vec::new();
+        let similarity = back_translation
+            .as_deref()
+            .map(|back| similarity(source, back));
+
+        if translation.trim().is_empty() {
+            issues.push(QualityIssue::Empty);
+        } else if translation.trim() == source.trim() && source.chars().any(|c| c.is_alphabetic()) {
+            issues.push(QualityIssue::Unchanged);
+        }
+
+        match similarity {
+            Some(score) if score < MIN_SIMILARITY => issues.push(QualityIssue::LowSimilarity),
+            Some(_) => {}
+            None => issues.push(QualityIssue::BackTranslationFailed),
+        }
+
+        let source_len = source.chars().count();
+        if source_len >= MIN_RATIO_CHARS && !issues.contains(&QualityIssue::Empty) {
+            let ratio = translation.chars().count() as f64 / source_len as f64;
+            let (min, max) = LENGTH_RATIO_BOUNDS;
+            if !(min..=max).contains(&ratio) {
+                issues.push(QualityIssue::LengthRatio);
+            }
+        }
+
+        Self {
+            key: key.to_string(),
+            target_lang: target_lang.to_string(),
+            source: source.to_string(),
+            translation: translation.to_string(),
+            back_translation,
+            similarity,
+            issues,
+        }
+    }
+
+    pub fn is_flagged(&self) -> bool {
+        !self.issues.is_empty()
+    }
+}
+
+/// Dice coefficient of character bigrams, ignoring case, punctuation and spacing
+/// e.g., "Save changes" vs "Save the changes" -> 0.85, vs "Delete account" -> 0.1
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (normalize(a), normalize(b));
+    if a == b {
+        return 1.0;
+    }
+
+    let (a, b) = (bigrams(&a), bigrams(&b));
+    let total: usize = a.values().sum::<usize>() + b.values().sum::<usize>();
+    if total == 0 {
+        return 0.0;
+    }
+
+    let shared: usize = a
+        .iter()
+        .map(|(bigram, count)| (*count).min(b.get(bigram).copied().unwrap_or(0)))
+        .sum();
+    2.0 * shared as f64 / total as f64
+}
+
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bigrams(text: &str) -> HashMap<(char, char), usize> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut counts = HashMap::new();
+    for pair in chars.windows(2) {
+        *counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("Save changes", "save changes!"), 1.0);
+        assert!(similarity("Save changes", "Save the changes") > 0.8);
+        assert!(similarity("Save changes", "Delete account") < 0.2);
+        assert_eq!(similarity("", "Save"), 0.0);
+    }
+
+    #[test]
+    fn test_flags_suspicious_translations() {
+        let issues = |translation: &str, back: Option<&str>| {
+            QualityCheck::new(
+                "k",
+                "de",
+                "Delete your account",
+                translation,
+                back.map(str::to_string),
+            )
+            .issues
+        };
+
+        assert!(issues("Konto löschen", Some("Delete your account")).is_empty());
+        assert_eq!(
+            issues("Konto", Some("Bill")),
+            vec![QualityIssue::LowSimilarity, QualityIssue::LengthRatio]
+        );
+        assert_eq!(
+            issues("Delete your account", Some("Delete your account")),
+            vec![QualityIssue::Unchanged]
+        );
+        assert_eq!(
+            issues(" ", Some("")),
+            vec![QualityIssue::Empty, QualityIssue::LowSimilarity]
+        );
+        assert_eq!(
+            issues("Konto löschen", None),
+            vec![QualityIssue::BackTranslationFailed]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Who produced the value recorded for a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Origin {
+    /// Written by `translate`
+    Machine,
+    /// Approved through `review`
+    Reviewed,
+}
+
+/// What langsmith last wrote or approved for one key in one language
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyRecord {
+    pub origin: Origin,
+    /// Source text the value was translated from
+    pub source: String,
+    /// Value as written or approved
+    pub value: String,
+}
+
+/// Review status of a catalog value, derived from its record and the current files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReviewStatus {
+    /// The source text changed since the value was translated or approved
+    Stale,
+    /// Machine translation nobody has looked at yet
+    Machine,
+    /// Changed by hand in the catalog after langsmith wrote it
+    Edited,
+    /// Approved through `review`
+    Reviewed,
+    /// No record, e.g. written before state tracking or by another tool
+    Untracked,
+}
+
+impl ReviewStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Stale => "stale",
+            Self::Machine => "machine",
+            Self::Edited => "edited",
+            Self::Reviewed => "reviewed",
+            Self::Untracked => "untracked",
+        }
+    }
+
+    /// Whether the key still needs a human look
+    pub fn needs_review(&self) -> bool {
+        matches!(self, Self::Stale | Self::Machine | Self::Edited)
+    }
+}
+
+/// Per-language, per-key review state, stored in `<catalog dir>/.langsmith/state.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReviewState {
+    pub languages: BTreeMap<String, BTreeMap<String, KeyRecord>>,
+}
+
+impl ReviewState {
+    /// State file for the catalogs in a directory, e.g. "i18n" -> "i18n/.langsmith/state.json"
+    pub fn path_for(catalog_dir: &Path) -> PathBuf {
+        catalog_dir.join(".langsmith").join("state.json")
+    }
+
+    pub fn get(&self, lang: &str, key: &str) -> Option<&KeyRecord> {
+        self.languages.get(lang)?.get(key)
+    }
+
+    /// Status of a key given the current source text and catalog value
+    pub fn status(&self, lang: &str, key: &str, source: &str, value: &str) -> ReviewStatus {
+        match self.get(lang, key) {
+            None => ReviewStatus::Untracked,
+            Some(record) if record.source != source => ReviewStatus::Stale,
+            Some(record) if record.value != value => ReviewStatus::Edited,
+            Some(record) => match record.origin {
+                Origin::Machine => ReviewStatus::Machine,
+                Origin::Reviewed => ReviewStatus::Reviewed,
+            },
+        }
+    }
+
+    /// Whether `translate` must keep the catalog value: approved or edited by hand
+    ///
+    /// This holds even when the source changed; the key then shows up as stale in `review`.
+    pub fn is_protected(&self, lang: &str, key: &str, value: &str) -> bool {
+        self.get(lang, key)
+            .is_some_and(|record| record.origin == Origin::Reviewed || record.value != value)
+    }
+
+    pub fn record(&mut self, lang: &str, key: &str, origin: Origin, source: &str, value: &str) {
+        self.languages.entry(lang.to_string()).or_default().insert(
+            key.to_string(),
+            KeyRecord {
+                origin,
+                source: source.to_string(),
+                value: value.to_string(),
+            },
+        );
+    }
+
+    pub fn forget(&mut self, lang: &str, key: &str) {
+        if let Some(records) = self.languages.get_mut(lang) {
+            records.remove(key);
+        }
+    }
+
+    /// Drop records of keys that are no longer in the language's catalog
+    pub fn retain_keys(&mut self, lang: &str, keep: impl Fn(&str) -> bool) {
+        if let Some(records) = self.languages.get_mut(lang) {
+            records.retain(|key, _| keep(key));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_transitions() {
+        let mut state = ReviewState::default();
+        assert_eq!(
+            state.status("de", "save", "Save", "Speichern"),
+            ReviewStatus::Untracked
+        );
+
+        state.record("de", "save", Origin::Machine, "Save", "Speichern");
+        assert_eq!(
+            state.status("de", "save", "Save", "Speichern"),
+            ReviewStatus::Machine
+        );
+        assert_eq!(
+            state.status("de", "save", "Save", "Sichern"),
+            ReviewStatus::Edited
+        );
+        assert_eq!(
+            state.status("de", "save", "Save all", "Speichern"),
+            ReviewStatus::Stale
+        );
+
+        state.record("de", "save", Origin::Reviewed, "Save", "Speichern");
+        assert_eq!(
+            state.status("de", "save", "Save", "Speichern"),
+            ReviewStatus::Reviewed
+        );
+    }
+
+    #[test]
+    fn test_protection() {
+        let mut state = ReviewState::default();
+        state.record("de", "machine", Origin::Machine, "Save", "Speichern");
+        state.record("de", "approved", Origin::Reviewed, "Open", "Öffnen");
+
+        assert!(!state.is_protected("de", "machine", "Speichern"));
+        assert!(state.is_protected("de", "machine", "Sichern"));
+        assert!(state.is_protected("de", "approved", "Öffnen"));
+        assert!(!state.is_protected("de", "untracked", "Hallo"));
+        assert!(!state.is_protected("fr", "approved", "Ouvrir"));
+    }
+}
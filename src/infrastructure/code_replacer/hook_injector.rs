@@ -0,0 +1,836 @@
+use super::import_manager::SimpleImportManager;
+use super::jsx_syntax::SyntaxMap;
+use crate::domain::models::{FileType, ReplacementStrategy};
+use crate::domain::ports::HookInjector;
+use async_trait::async_trait;
+use regex::Regex;
+use std::ops::Range;
+
+/// Body of a function component or custom hook
+struct Scope {
+    /// `{ ... }` of a block body, or `( ... )`/`<el>` of an arrow returning an expression
+    body: Range<usize>,
+    expression: bool,
+    /// Indentation of the line declaring the function
+    indent: String,
+    asynchronous: bool,
+}
+
+/// Declares the strategy's hook, e.g. `const { t } = useTranslation();`, in components
+/// and hooks that call its translation function
+///
+/// Components are functions named in PascalCase, possibly wrapped in `memo` or
+/// `forwardRef`, and default-exported functions; hooks are functions named `useX`.
+/// Async functions of server files, those without 'use client', get the strategy's
+/// async declaration instead, e.g. `const t = await getTranslations();`.
+/// Calls in other functions go through the i18n instance: `i18n.t("key")`.
+/// Calls run when the module loads, before i18n is initialized, are made lazy:
+/// object properties become getters, and constants or arrays hold the key instead,
+/// renamed `NAME_KEY`/`NAME_KEYS` and translated where the file reads them.
+/// Strategies without an instance have nothing to call there; strings outside their
+/// components are reported as unreachable and left alone.
+pub struct ComponentHookInjector;
+
+impl ComponentHookInjector {
+    /// Components and hooks, and with `any_async` every async function as well
+    fn scopes(content: &str, map: &SyntaxMap, any_async: bool) -> Vec<Scope> {
+        map.functions()
+            .iter()
+            .filter(|f| f.is_component() || f.is_hook() || (any_async && f.asynchronous))
+            .map(|f| Scope {
+                body: f.body.clone(),
+                expression: f.expression,
+                indent: line_indent(content, f.range.start),
+                asynchronous: f.asynchronous,
+            })
+            .collect()
+    }
+
+    /// Whether the function the hook declares is also called outside components, where
+    /// only an import of it can serve the calls, e.g. Lingui's `t` macro
+    pub fn called_outside_components(
+        content: &str,
+        file_type: FileType,
+        strategy: &ReplacementStrategy,
+    ) -> bool {
+        let function = strategy.function();
+        let binding = function.split('.').next().unwrap_or(function);
+        if !strategy
+            .hook_declaration()
+            .is_some_and(|declaration| declares(&declaration, binding))
+        {
+            return false;
+        }
+
+        let map = SyntaxMap::parse(content, !matches!(file_type, FileType::TypeScript));
+        let client = content[..SimpleImportManager::directives_end(content)].contains("use client");
+        let any_async = strategy.async_declaration().is_some() && !client;
+        let scopes = Self::scopes(content, &map, any_async);
+        Self::calls(content, &map, function)
+            .iter()
+            .any(|call| !scopes.iter().any(|scope| scope.body.contains(call)))
+    }
+
+    /// Code outside every scope, run when the module loads
+    fn module(content: &str, scopes: &[Scope]) -> String {
+        let mut module = String::new();
+        let mut last = 0;
+        for scope in scopes {
+            if scope.body.start >= last {
+                module.push_str(&content[last..scope.body.start]);
+                last = scope.body.end;
+            }
+        }
+        module.push_str(&content[last.min(content.len())..]);
+        module
+    }
+
+    /// Positions of calls to `function`, e.g. `t(` or the tagged template `` t` ``, in code
+    fn calls(content: &str, map: &SyntaxMap, function: &str) -> Vec<usize> {
+        let re = Regex::new(&format!(
+            r"(?:^|[^\w$.])({})\s*[(`]",
+            regex::escape(function)
+        ))
+        .expect("invalid regex");
+        re.captures_iter(content)
+            .filter_map(|cap| cap.get(1).map(|m| m.start()))
+            .filter(|&pos| map.literal_at(pos).is_none())
+            .filter(|&pos| !content[..pos].trim_end().ends_with("function"))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl HookInjector for ComponentHookInjector {
+    async fn inject_hooks(
+        &self,
+        content: &str,
+        file_type: FileType,
+        strategy: &ReplacementStrategy,
+    ) -> anyhow::Result<String> {
+        let (Some(hook), Some(declaration)) = (strategy.hook(), strategy.hook_declaration()) else {
+            return Ok(content.to_string());
+        };
+        let function = strategy.function();
+        // `intl` of `intl.formatMessage` is what the hook declares
+        let binding = function.split('.').next().unwrap_or(function);
+        // Script blocks of .vue and .html files are not scanned on their own
+        if matches!(file_type, FileType::Vue | FileType::HTML) {
+            return Ok(content.to_string());
+        }
+
+        // `<T>` is a type assertion in .ts files, an element everywhere else
+        let map = SyntaxMap::parse(content, !matches!(file_type, FileType::TypeScript));
+        // Without 'use client', async functions are server code and cannot call hooks
+        let client = content[..SimpleImportManager::directives_end(content)].contains("use client");
+        let server_declaration = strategy.async_declaration().filter(|_| !client);
+        let scopes = Self::scopes(content, &map, server_declaration.is_some());
+        let calls = Self::calls(content, &map, function);
+
+        // A call belongs to the outermost component or hook around it
+        let outermost = |pos: usize| {
+            (0..scopes.len())
+                .filter(|&i| scopes[i].body.contains(&pos))
+                .max_by_key(|&i| scopes[i].body.len())
+        };
+        let mut used: Vec<usize> = Vec::new();
+        let mut outside: Vec<usize> = Vec::new();
+        for call in calls {
+            match outermost(call) {
+                Some(i) if !used.contains(&i) => used.push(i),
+                Some(_) => {}
+                None => outside.push(call),
+            }
+        }
+
+        let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+
+        // Leave calls alone when the module brings its own function
+        if let Some((instance, _)) = strategy.instance()
+            && !declares(&Self::module(content, &scopes), binding)
+        {
+            let mut constants: Vec<KeyConstant> = Vec::new();
+            for call in outside {
+                // Code in a function runs when it is called, not when the module loads
+                if map.functions_at(call).next().is_some() {
+                    edits.push((call..call, format!("{}.", instance)));
+                } else if let Some(edit) = lazy_call(content, &map, call, instance) {
+                    edits.push(edit);
+                } else if let Some(constant) = key_constant(content, &map, call) {
+                    edits.push((constant.call.clone(), constant.key.clone()));
+                    if !constants.iter().any(|c| c.name == constant.name) {
+                        constants.push(constant);
+                    }
+                } else {
+                    tracing::warn!(
+                        "Line {}: translation runs when the module loads, before i18n is initialized",
+                        content[..call].matches('\n').count() + 1
+                    );
+                    edits.push((call..call, format!("{}.", instance)));
+                }
+            }
+
+            // `TITLE` becomes `TITLE_KEY` and the file translates it where it reads it
+            for constant in &constants {
+                let name = &content[constant.name.clone()];
+                let renamed = format!("{}_{}", name, if constant.array { "KEYS" } else { "KEY" });
+                tracing::warn!(
+                    "Line {}: {} is read when the module loads; renamed {} to hold the key{}",
+                    content[..constant.name.start].matches('\n').count() + 1,
+                    name,
+                    renamed,
+                    if constant.exported {
+                        ", modules importing it must translate it"
+                    } else {
+                        ""
+                    }
+                );
+                edits.push((constant.name.clone(), renamed.clone()));
+                for at in references(content, &map, name) {
+                    if at == constant.name.start {
+                        continue;
+                    }
+                    let scope = outermost(at);
+                    let translate = match scope {
+                        Some(i) => {
+                            if !used.contains(&i) {
+                                used.push(i);
+                            }
+                            function.to_string()
+                        }
+                        None => format!("{}.{}", instance, function),
+                    };
+                    let value = if constant.array {
+                        format!("{}.map((key) => {}(key))", renamed, translate)
+                    } else {
+                        format!("{}({})", translate, renamed)
+                    };
+                    edits.push((
+                        at..at + name.len(),
+                        reference(content, &map, at, name, &renamed, value),
+                    ));
+                }
+            }
+        }
+
+        for scope in used.iter().map(|&i| &scopes[i]) {
+            let body = &content[scope.body.clone()];
+            if declares(body, binding) || body.contains(&format!("{}(", hook)) {
+                continue;
+            }
+            let declaration = match &server_declaration {
+                Some(server) if scope.asynchronous => server,
+                _ => &declaration,
+            };
+            let inner = format!("{}  ", scope.indent);
+            if scope.expression {
+                edits.push((
+                    scope.body.clone(),
+                    format!(
+                        "{{\n{inner}{declaration}\n{inner}return {body};\n{}}}",
+                        scope.indent
+                    ),
+                ));
+            } else {
+                // Line up with the first statement when the body spans lines
+                let first_line = body[1..]
+                    .split_once('\n')
+                    .map(|(_, rest)| line_indent(rest, 0))
+                    .filter(|indent| !indent.is_empty())
+                    .unwrap_or(inner);
+                let at = scope.body.start + 1;
+                edits.push((at..at, format!("\n{}{}", first_line, declaration)));
+            }
+        }
+
+        // Apply back-to-front so earlier positions stay valid; nested calls win over
+        // the getter around them
+        edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+        let mut result = content.to_string();
+        let mut replaced_from = content.len();
+        for (range, text) in edits {
+            if range.end > replaced_from {
+                continue;
+            }
+            replaced_from = range.start;
+            result.replace_range(range, &text);
+        }
+        Ok(result)
+    }
+
+    fn unreachable(
+        &self,
+        content: &str,
+        file_type: FileType,
+        strategy: &ReplacementStrategy,
+        positions: &[usize],
+    ) -> Vec<usize> {
+        // The i18n instance, or an import of the function, translates anywhere;
+        // vue-i18n's `t` may come from `setup()`, which is not scanned
+        let function = strategy.function();
+        let binding = function.split('.').next().unwrap_or(function);
+        if strategy.hook().is_none()
+            || strategy.instance().is_some()
+            || strategy
+                .import_statements()
+                .iter()
+                .any(|i| declares(i, binding))
+            || matches!(strategy, ReplacementStrategy::VueI18n)
+            || matches!(file_type, FileType::Vue | FileType::HTML)
+        {
+            return Vec::new();
+        }
+
+        let map = SyntaxMap::parse(content, !matches!(file_type, FileType::TypeScript));
+        let client = content[..SimpleImportManager::directives_end(content)].contains("use client");
+        let any_async = strategy.async_declaration().is_some() && !client;
+        let scopes = Self::scopes(content, &map, any_async);
+        if declares(&Self::module(content, &scopes), binding) {
+            return Vec::new();
+        }
+        positions
+            .iter()
+            .copied()
+            .filter(|pos| !scopes.iter().any(|scope| scope.body.contains(pos)))
+            .collect()
+    }
+}
+
+/// Lazy form of a property call made when the module loads
+///
+/// `label: t("key")` becomes `get label() { return i18n.t("key"); }`, so reading
+/// the property translates it.
+fn lazy_call(
+    content: &str,
+    map: &SyntaxMap,
+    call: usize,
+    instance: &str,
+) -> Option<(Range<usize>, String)> {
+    let open = call + content[call..].find('(')?;
+    let end = matching(content, map, open)? + 1;
+
+    // Regex compile-time unwrap is safe (compile-time constant)
+    let property =
+        Regex::new(r#"[{,]\s*([A-Za-z_$][\w$]*|"[^"]*"|'[^']*')\s*:\s*$"#).expect("invalid regex");
+    let name = property.captures(&content[..call])?.get(1)?;
+    if !content[end..].trim_start().starts_with([',', '}']) {
+        return None;
+    }
+    Some((
+        name.start()..end,
+        format!(
+            "get {}() {{ return {}.{}; }}",
+            name.as_str(),
+            instance,
+            &content[call..end]
+        ),
+    ))
+}
+
+/// A constant, or array constant, whose module-load call was replaced by its key
+struct KeyConstant {
+    /// The call `t("key")` and the key `"key"` it becomes
+    call: Range<usize>,
+    key: String,
+    /// Name of the constant in its declaration
+    name: Range<usize>,
+    array: bool,
+    exported: bool,
+}
+
+/// `const TITLE = t("key")` or `const LABELS = [t("key"), ...]` without options
+fn key_constant(content: &str, map: &SyntaxMap, call: usize) -> Option<KeyConstant> {
+    let open = call + content[call..].find('(')?;
+    let end = matching(content, map, open)? + 1;
+    let key = content[open + 1..end - 1].trim();
+    let quoted = key.len() >= 2
+        && (key.starts_with('"') && key.ends_with('"')
+            || key.starts_with('\'') && key.ends_with('\''));
+    if !quoted || key[1..key.len() - 1].contains(['"', '\'', ',']) {
+        return None;
+    }
+
+    // The call is the whole value, or an item of the array that is
+    // Regex compile-time unwrap is safe (compile-time constant)
+    let declaration = Regex::new(r"^(export\s+)?(?:const|let|var)\s+([\w$]+)\s*(?::[^=]+)?=$")
+        .expect("invalid regex");
+    let mut value = call;
+    let array = content[head(content, map, call)..call].trim().is_empty();
+    if array {
+        value = enclosing(content, map, call).filter(|&open| content.as_bytes()[open] == b'[')?;
+    }
+    let start = head(content, map, value);
+    let prefix = &content[start..value];
+    let lead = prefix.len() - prefix.trim_start().len();
+    let cap = declaration.captures(prefix.trim())?;
+    let name = cap.get(2)?;
+    Some(KeyConstant {
+        call: call..end,
+        key: key.to_string(),
+        name: start + lead + name.start()..start + lead + name.end(),
+        array,
+        exported: cap.get(1).is_some(),
+    })
+}
+
+/// Positions where code reads `name`, not as a property or object key
+fn references(content: &str, map: &SyntaxMap, name: &str) -> Vec<usize> {
+    let re = Regex::new(&format!(
+        r"(?:^|[^\w$.])({})(?:[^\w$]|$)",
+        regex::escape(name)
+    ))
+    .expect("invalid regex");
+    let key = Regex::new(r"^\s*:").expect("invalid regex");
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(cap) = re.captures_at(content, from) {
+        let m = cap.get(1).expect("group 1 always matches");
+        from = m.end();
+        if map.literal_at(m.start()).is_some() {
+            continue;
+        }
+        // `{ TITLE: ... }` names a property; `cond ? TITLE : x` reads it
+        let before = content[..m.start()].trim_end();
+        if key.is_match(&content[m.end()..]) && before.ends_with(['{', ',']) {
+            continue;
+        }
+        found.push(m.start());
+    }
+    found
+}
+
+/// Replacement for a reference: the translated value, `NAME: value` for a
+/// shorthand property, the renamed constant in an export list
+fn reference(
+    content: &str,
+    map: &SyntaxMap,
+    at: usize,
+    name: &str,
+    renamed: &str,
+    value: String,
+) -> String {
+    let before = content[..at].trim_end();
+    let after = content[at + name.len()..].trim_start();
+    let shorthand = before.ends_with(['{', ',']) && after.starts_with([',', '}']);
+    let Some(open) = enclosing(content, map, at).filter(|_| shorthand) else {
+        return value;
+    };
+    if content.as_bytes()[open] != b'{' || map.is_jsx_expression(open) {
+        return value;
+    }
+    if content[..open].trim_end().ends_with("export") {
+        renamed.to_string()
+    } else {
+        format!("{}: {}", name, value)
+    }
+}
+
+/// Start of the expression or statement holding `pos`, at its nesting level
+fn head(content: &str, map: &SyntaxMap, pos: usize) -> usize {
+    let bytes = content.as_bytes();
+    let mut depth = 0usize;
+    let mut i = pos;
+    while i > 0 {
+        i -= 1;
+        if let Some(node) = map.literal_at(i) {
+            i = node.range.start;
+            continue;
+        }
+        match bytes[i] {
+            b')' | b']' | b'}' => depth += 1,
+            b'(' | b'[' | b'{' | b';' | b',' if depth == 0 => return i + 1,
+            b'(' | b'[' | b'{' => depth -= 1,
+            _ => {}
+        }
+    }
+    0
+}
+
+/// Position of the unclosed bracket before `pos`, skipping literals and comments
+fn enclosing(content: &str, map: &SyntaxMap, pos: usize) -> Option<usize> {
+    let bytes = content.as_bytes();
+    let mut depth = 0usize;
+    let mut i = pos;
+    while i > 0 {
+        i -= 1;
+        if let Some(node) = map.literal_at(i) {
+            i = node.range.start;
+            continue;
+        }
+        match bytes[i] {
+            b')' | b']' | b'}' => depth += 1,
+            b'(' | b'[' | b'{' if depth == 0 => return Some(i),
+            b'(' | b'[' | b'{' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether code binds `name`: `const { t } = ...`, `const intl = ...`, `import { t } from`, `function t(`
+pub fn declares(code: &str, name: &str) -> bool {
+    let name = regex::escape(name);
+    let re = Regex::new(&format!(
+        r"(?:^|[^\w$])(?:const|let|var|function)\s+{name}(?:[^\w$]|$)|[{{,]\s*{name}\s*[,}}]"
+    ))
+    .expect("invalid regex");
+    re.is_match(code)
+}
+
+/// Position of the bracket closing the one at `open`, skipping literals and comments
+fn matching(content: &str, map: &SyntaxMap, open: usize) -> Option<usize> {
+    let bytes = content.as_bytes();
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        if let Some(node) = map.literal_at(i) {
+            i = node.range.end.max(i + 1);
+            continue;
+        }
+        match bytes[i] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Leading whitespace of the line containing `pos`
+fn line_indent(content: &str, pos: usize) -> String {
+    let line_start = content[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    content[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn inject(content: &str) -> String {
+        ComponentHookInjector
+            .inject_hooks(content, FileType::JSX, &ReplacementStrategy::ReactI18n)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_components_and_hooks_get_the_hook() {
+        let content = r#"export function App() {
+  return <h1>{t("welcome")}</h1>;
+}
+
+const Card = ({ title }) => (
+  <p title={t("card")}>{title}</p>
+);
+
+function useLabels() {
+  const { t } = useTranslation();
+  return [t("save")];
+}"#;
+
+        assert_eq!(
+            inject(content).await,
+            r#"export function App() {
+  const { t } = useTranslation();
+  return <h1>{t("welcome")}</h1>;
+}
+
+const Card = ({ title }) => {
+  const { t } = useTranslation();
+  return (
+  <p title={t("card")}>{title}</p>
+);
+};
+
+function useLabels() {
+  const { t } = useTranslation();
+  return [t("save")];
+}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_arrow_with_jsx_body() {
+        let content = r#"const Item = (props) => <li title={t("item")}>{props.name}</li>;"#;
+        assert_eq!(
+            inject(content).await,
+            r#"const Item = (props) => {
+  const { t } = useTranslation();
+  return <li title={t("item")}>{props.name}</li>;
+};"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_default_export() {
+        let content = r#"export default function () {
+  return <main>{t("home")}</main>;
+}"#;
+        assert_eq!(
+            inject(content).await,
+            r#"export default function () {
+  const { t } = useTranslation();
+  return <main>{t("home")}</main>;
+}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memo_and_forward_ref_arrows() {
+        let content = r#"const Row = memo((props) => <tr>{t("row")}</tr>);
+const Field = React.forwardRef<HTMLInputElement, Props>((props, ref) => {
+  return <input ref={ref} placeholder={t("field")} />;
+});"#;
+        assert_eq!(
+            inject(content).await,
+            r#"const Row = memo((props) => {
+  const { t } = useTranslation();
+  return <tr>{t("row")}</tr>;
+});
+const Field = React.forwardRef<HTMLInputElement, Props>((props, ref) => {
+  const { t } = useTranslation();
+  return <input ref={ref} placeholder={t("field")} />;
+});"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calls_outside_components_use_the_instance() {
+        let content = r#"const TITLE = t("title");
+function formatCount(n) {
+  return t("count", { count: n });
+}
+const Badge = memo(function Badge() {
+  return <span>{items.map((item) => t("item"))}</span>;
+});"#;
+
+        assert_eq!(
+            inject(content).await,
+            r#"const TITLE_KEY = "title";
+function formatCount(n) {
+  return i18n.t("count", { count: n });
+}
+const Badge = memo(function Badge() {
+  const { t } = useTranslation();
+  return <span>{items.map((item) => t("item"))}</span>;
+});"#
+        );
+
+        // A module with its own `t` is left alone
+        let own = "import { t } from './i18n';\nconst TITLE = t(\"title\");";
+        assert_eq!(inject(own).await, own);
+    }
+
+    #[tokio::test]
+    async fn test_module_constants_become_lazy() {
+        let content = r#"export const MESSAGES = {
+  welcome: t("welcome"),
+  "log-in": t("login"),
+};
+export const LABELS = [t("home"), t("about")];
+export const describe = (n) => t("count", { count: n });
+export function formatDate(date: Date): string {
+  if (!date) {
+    return t("no_date");
+  }
+  return date.toLocaleDateString();
+}
+if (debug) {
+  console.log(t("debug"));
+}"#;
+
+        assert_eq!(
+            inject(content).await,
+            r#"export const MESSAGES = {
+  get welcome() { return i18n.t("welcome"); },
+  get "log-in"() { return i18n.t("login"); },
+};
+export const LABELS_KEYS = ["home", "about"];
+export const describe = (n) => i18n.t("count", { count: n });
+export function formatDate(date: Date): string {
+  if (!date) {
+    return i18n.t("no_date");
+  }
+  return date.toLocaleDateString();
+}
+if (debug) {
+  console.log(i18n.t("debug"));
+}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_key_constants_are_translated_where_read() {
+        let content = r#"const TITLE = t("title");
+export const LABELS = [t("home"), t("about")];
+const MENU = { title: TITLE, items: LABELS };
+export { TITLE };
+
+function Nav() {
+  return <nav title={TITLE}>{LABELS.map((label) => <a>{label}</a>)}</nav>;
+}
+
+function heading() {
+  return { TITLE };
+}"#;
+
+        assert_eq!(
+            inject(content).await,
+            r#"const TITLE_KEY = "title";
+export const LABELS_KEYS = ["home", "about"];
+const MENU = { title: i18n.t(TITLE_KEY), items: LABELS_KEYS.map((key) => i18n.t(key)) };
+export { TITLE_KEY };
+
+function Nav() {
+  const { t } = useTranslation();
+  return <nav title={t(TITLE_KEY)}>{LABELS_KEYS.map((key) => t(key)).map((label) => <a>{label}</a>)}</nav>;
+}
+
+function heading() {
+  return { TITLE: i18n.t(TITLE_KEY) };
+}"#
+        );
+    }
+
+    #[test]
+    fn test_react_intl_outside_components_is_unreachable() {
+        let content = r#"const TITLE = "Welcome";
+function format() {
+  return "Plain";
+}
+export default function () {
+  return <p>Hello</p>;
+}
+const Card = () => <p title="Card">x</p>;"#;
+        let positions: Vec<usize> = ["Welcome", "Plain", "Hello", "\"Card\""]
+            .iter()
+            .map(|text| content.find(text).unwrap())
+            .collect();
+        let unreachable = |strategy: &ReplacementStrategy| {
+            ComponentHookInjector.unreachable(content, FileType::JSX, strategy, &positions)
+        };
+
+        let intl = ReplacementStrategy::ReactIntl {
+            define_messages: false,
+        };
+        assert_eq!(unreachable(&intl), positions[..2].to_vec());
+        // i18next has its instance outside components
+        assert!(unreachable(&ReplacementStrategy::ReactI18n).is_empty());
+    }
+
+    #[test]
+    fn test_next_intl_outside_components_is_unreachable() {
+        let strategy = ReplacementStrategy::NextIntl { namespace: None };
+        let server = r#"function label() {
+  return "Plain";
+}
+export default async function Page() {
+  return <h1>Welcome</h1>;
+}"#;
+        let positions: Vec<usize> = ["Plain", "Welcome"]
+            .iter()
+            .map(|text| server.find(text).unwrap())
+            .collect();
+        assert_eq!(
+            ComponentHookInjector.unreachable(server, FileType::JSX, &strategy, &positions),
+            positions[..1].to_vec()
+        );
+
+        // Client files have no getTranslations for plain async functions
+        let client = "'use client';\nasync function load() {\n  return \"Loading\";\n}";
+        let loading = client.find("Loading").unwrap();
+        assert_eq!(
+            ComponentHookInjector.unreachable(client, FileType::JSX, &strategy, &[loading]),
+            vec![loading]
+        );
+
+        // Lingui's core `t` macro works anywhere
+        assert!(
+            ComponentHookInjector
+                .unreachable(
+                    server,
+                    FileType::JSX,
+                    &ReplacementStrategy::Lingui,
+                    &positions
+                )
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_strategy_hook() {
+        let strategy = ReplacementStrategy::Custom(crate::domain::models::CustomStrategy {
+            import: "import { useTr } from '@/lib/i18n';".to_string(),
+            expression: "tr('{key}')".to_string(),
+            jsx: "{tr('{key}')}".to_string(),
+            attribute: "{tr('{key}')}".to_string(),
+            hook: Some("const { tr } = useTr();".to_string()),
+        });
+        let content = "function App() {\n  return <h1>{tr('welcome')}</h1>;\n}\nfunction Plain() {\n  return <p>{t('x')}</p>;\n}";
+
+        assert_eq!(
+            ComponentHookInjector
+                .inject_hooks(content, FileType::JSX, &strategy)
+                .await
+                .unwrap(),
+            "function App() {\n  const { tr } = useTr();\n  return <h1>{tr('welcome')}</h1>;\n}\nfunction Plain() {\n  return <p>{t('x')}</p>;\n}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_intl_server_and_client_components() {
+        let strategy = ReplacementStrategy::NextIntl {
+            namespace: Some("home".to_string()),
+        };
+        let server = r#"export async function generateMetadata() {
+  return { title: t("title") };
+}
+
+export default async function Page() {
+  return <h1>{t("welcome")}</h1>;
+}
+
+function Footer() {
+  return <p>{t("footer")}</p>;
+}"#;
+
+        assert_eq!(
+            ComponentHookInjector
+                .inject_hooks(server, FileType::TSX, &strategy)
+                .await
+                .unwrap(),
+            r#"export async function generateMetadata() {
+  const t = await getTranslations("home");
+  return { title: t("title") };
+}
+
+export default async function Page() {
+  const t = await getTranslations("home");
+  return <h1>{t("welcome")}</h1>;
+}
+
+function Footer() {
+  const t = useTranslations("home");
+  return <p>{t("footer")}</p>;
+}"#
+        );
+
+        let client = "'use client';\nexport function Search() {\n  return <input placeholder={t(\"search\")} />;\n}";
+        assert!(
+            ComponentHookInjector
+                .inject_hooks(client, FileType::TSX, &strategy)
+                .await
+                .unwrap()
+                .contains("export function Search() {\n  const t = useTranslations(\"home\");")
+        );
+    }
+}
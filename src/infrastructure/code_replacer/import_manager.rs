@@ -1,3 +1,4 @@
+use super::hook_injector::{ComponentHookInjector, declares};
 use crate::domain::models::{FileType, ReplacementStrategy};
 use crate::domain::ports::ImportManager;
 use async_trait::async_trait;
@@ -12,9 +13,12 @@ impl SimpleImportManager {
         // e.g., "import { useTranslation } from 'react-i18next';" -> react-i18next
         // Regex compile-time unwrap is safe (compile-time constant)
         let re = Regex::new(r#"from\s+['"]([^'"]+)['"]"#).expect("invalid regex");
+        // Quoted, so 'i18next' is not found in 'react-i18next'
         if let Some(cap) = re.captures(import_statement) {
             if let Some(package) = cap.get(1) {
-                content.contains(package.as_str())
+                let package = package.as_str();
+                content.contains(&format!("'{}'", package))
+                    || content.contains(&format!("\"{}\"", package))
             } else {
                 false
             }
@@ -23,20 +27,68 @@ impl SimpleImportManager {
         }
     }
 
+    /// Whether the content calls `name`, like `useIntl(` or the tagged template `` t` ``,
+    /// or renders it, like `<FormattedMessage`
+    fn uses(content: &str, name: &str) -> bool {
+        Regex::new(&format!(
+            r"(?:^|[^\w$.]){0}\s*[(`]|<{0}\b",
+            regex::escape(name)
+        ))
+        .is_ok_and(|re| re.is_match(content))
+    }
+
+    /// Keep the named imports `used` accepts; None when it accepts none of them
+    ///
+    /// Statements without named imports are kept as they are.
+    fn used_imports(import_statement: &str, used: impl Fn(&str) -> bool) -> Option<String> {
+        let Some((head, rest)) = import_statement.split_once('{') else {
+            return Some(import_statement.to_string());
+        };
+        let Some((names, tail)) = rest.split_once('}') else {
+            return Some(import_statement.to_string());
+        };
+
+        let used: Vec<&str> = names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| {
+                // `a as b` is used as `b`
+                let local = name.rsplit(' ').next().unwrap_or(name);
+                !local.is_empty() && used(local)
+            })
+            .collect();
+        if used.is_empty() {
+            return None;
+        }
+        Some(format!("{}{{ {} }}{}", head, used.join(", "), tail))
+    }
+
     /// Find the position to insert new imports (after last existing import)
-    fn find_import_insertion_point(content: &str) -> usize {
+    pub fn find_import_insertion_point(content: &str) -> usize {
         // Find last import statement
         // Regex compile-time unwrap is safe (compile-time constant)
-        let re_pattern = r#"(?m)^import\s+.*from\s+['"][^'"]+['"];?\s*$"#;
+        let re_pattern = r#"(?m)^import\s+.*from\s+['"][^'"]+['"];?[ \t]*$"#;
         let re = Regex::new(re_pattern).expect("invalid regex");
 
         if let Some(last_match) = re.find_iter(content).last() {
             // Insert after last import
             last_match.end()
         } else {
-            // No imports found, insert at beginning
-            0
+            // No imports found, insert after the directives, or at the beginning
+            Self::directives_end(content)
+        }
+    }
+
+    /// End of the directive prologue, e.g. `'use client';`, which must stay first in the file
+    pub fn directives_end(content: &str) -> usize {
+        // Regex compile-time unwrap is safe (compile-time constant)
+        let re = Regex::new(r#"^(?:\s+|//[^\n]*|/\*[\s\S]*?\*/)*(?:'use [\w ]+'|"use [\w ]+");?"#)
+            .expect("invalid regex");
+        let mut end = 0;
+        while let Some(m) = re.find(&content[end..]) {
+            end += m.end();
         }
+        end
     }
 }
 
@@ -45,13 +97,44 @@ impl ImportManager for SimpleImportManager {
     async fn ensure_import(
         &self,
         content: &str,
-        _file_type: FileType,
+        file_type: FileType,
         strategy: &ReplacementStrategy,
     ) -> anyhow::Result<String> {
-        let import_stmt = strategy.import_statement();
+        // The names the file uses; an import without names when the file translates,
+        // through the hook or by calling the translation function anywhere
+        let mut imports = Vec::new();
+        let calls = |name: &str| {
+            Regex::new(&format!(r"(?:^|[^\w$.]){}\s*[(`]", regex::escape(name)))
+                .is_ok_and(|re| re.is_match(content))
+        };
+        let translates = strategy.hook().is_none_or(&calls) || calls(strategy.function());
+        // The function the hook declares in components is imported only for calls
+        // outside them, e.g. Lingui's `t` macro
+        let function = strategy.function();
+        let hook_declares = strategy
+            .hook_declaration()
+            .is_some_and(|declaration| declares(&declaration, function));
+        let used = |name: &str| {
+            if hook_declares && name == function {
+                ComponentHookInjector::called_outside_components(content, file_type, strategy)
+            } else {
+                Self::uses(content, name)
+            }
+        };
+        for import_stmt in strategy.import_statements() {
+            if translates || import_stmt.contains('{') {
+                imports.extend(Self::used_imports(import_stmt, used));
+            }
+        }
+        if let Some((instance, import_stmt)) = strategy.instance()
+            && content.contains(&format!("{}.t(", instance))
+        {
+            imports.push(import_stmt.to_string());
+        }
 
         // Check if import already exists
-        if Self::has_import(content, import_stmt) {
+        imports.retain(|import_stmt| !Self::has_import(content, import_stmt));
+        if imports.is_empty() {
             return Ok(content.to_string());
         }
 
@@ -59,9 +142,20 @@ impl ImportManager for SimpleImportManager {
 
         let mut result = String::new();
         result.push_str(&content[..insertion_point]);
-        result.push('\n');
-        result.push_str(import_stmt);
-        result.push('\n');
+        if insertion_point == 0 {
+            // First imports of the file, set apart from the code
+            for import_stmt in imports {
+                result.push_str(&import_stmt);
+                result.push('\n');
+            }
+            result.push('\n');
+        } else {
+            // Each on its own line below the last import or directive
+            for import_stmt in imports {
+                result.push('\n');
+                result.push_str(&import_stmt);
+            }
+        }
         result.push_str(&content[insertion_point..]);
 
         Ok(result)
@@ -80,6 +174,159 @@ mod tests {
 
         let content2 = "const x = 1;";
         assert!(!SimpleImportManager::has_import(content2, import_stmt));
+
+        // react-i18next is not i18next
+        assert!(!SimpleImportManager::has_import(
+            content,
+            "import i18n from 'i18next';"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_imports_follow_usage() {
+        let content = "import React from 'react';\nconst TITLE = i18n.t(\"title\");";
+        let result = SimpleImportManager
+            .ensure_import(content, FileType::JSX, &ReplacementStrategy::ReactI18n)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "import React from 'react';\nimport i18n from 'i18next';\nconst TITLE = i18n.t(\"title\");"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_only_used_names_are_imported() {
+        let strategy = ReplacementStrategy::ReactIntl {
+            define_messages: false,
+        };
+        let content = "const A = () => <FormattedMessage id=\"a\" />;";
+        let result = SimpleImportManager
+            .ensure_import(content, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "import { FormattedMessage } from 'react-intl';\n\nconst A = () => <FormattedMessage id=\"a\" />;"
+        );
+
+        let plain = "const a = 1;";
+        let result = SimpleImportManager
+            .ensure_import(plain, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert_eq!(result, plain);
+    }
+
+    #[tokio::test]
+    async fn test_imports_go_below_directives() {
+        let strategy = ReplacementStrategy::NextIntl { namespace: None };
+        let content = "'use client';\n\nexport function App() {\n  const t = useTranslations();\n}";
+        let result = SimpleImportManager
+            .ensure_import(content, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "'use client';\nimport { useTranslations } from 'next-intl';\n\nexport function App() {\n  const t = useTranslations();\n}"
+        );
+
+        let server = "// Server component\n\"use server\"\nasync function Page() {\n  const t = await getTranslations();\n}";
+        let result = SimpleImportManager
+            .ensure_import(server, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert!(result.starts_with(
+            "// Server component\n\"use server\"\nimport { getTranslations } from 'next-intl/server';\nasync"
+        ));
+    }
+
+    fn custom(import: &str, hook: Option<&str>) -> ReplacementStrategy {
+        ReplacementStrategy::Custom(crate::domain::models::CustomStrategy {
+            import: import.to_string(),
+            expression: "tr('{key}')".to_string(),
+            jsx: "{tr('{key}')}".to_string(),
+            attribute: "{tr('{key}')}".to_string(),
+            hook: hook.map(str::to_string),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_custom_strategy_without_hook() {
+        let strategy = custom("import { tr } from '@/lib/i18n';", None);
+        let content = "const TITLE = tr('title');";
+        let result = SimpleImportManager
+            .ensure_import(content, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "import { tr } from '@/lib/i18n';\n\nconst TITLE = tr('title');"
+        );
+
+        let plain = "const title = attr('title');";
+        let result = SimpleImportManager
+            .ensure_import(plain, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert_eq!(result, plain);
+    }
+
+    #[tokio::test]
+    async fn test_custom_strategy_with_hook() {
+        let strategy = custom(
+            "import tr from '@/lib/i18n';",
+            Some("const locale = useLocale();"),
+        );
+
+        // Called outside any component, so the hook is not: the import is still needed
+        let content = "import React from 'react';\nconst TITLE = tr('title');";
+        let result = SimpleImportManager
+            .ensure_import(content, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "import React from 'react';\nimport tr from '@/lib/i18n';\nconst TITLE = tr('title');"
+        );
+
+        let plain = "import React from 'react';\nconst title = attr('title');";
+        let result = SimpleImportManager
+            .ensure_import(plain, FileType::JSX, &strategy)
+            .await
+            .unwrap();
+        assert_eq!(result, plain);
+    }
+
+    #[tokio::test]
+    async fn test_lingui_t_outside_components() {
+        let component =
+            "const Form = () => {\n  const { t } = useLingui();\n  return <p>{t`Hi`}</p>;\n};";
+        let result = SimpleImportManager
+            .ensure_import(component, FileType::JSX, &ReplacementStrategy::Lingui)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            format!(
+                "import {{ useLingui }} from '@lingui/react/macro';\n\n{}",
+                component
+            )
+        );
+
+        // A module constant has no useLingui: it takes `t` from the core macros
+        let content = format!("const title = t`Settings`;\n{}", component);
+        let result = SimpleImportManager
+            .ensure_import(&content, FileType::JSX, &ReplacementStrategy::Lingui)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            format!(
+                "import {{ useLingui }} from '@lingui/react/macro';\nimport {{ t }} from '@lingui/core/macro';\n\n{}",
+                content
+            )
+        );
     }
 
     #[test]
@@ -0,0 +1,741 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, ArrowFunctionExpression, CallExpression, Directive, ExportAllDeclaration,
+    ExportDefaultDeclaration, ExportDefaultDeclarationKind, ExportNamedDeclaration, Expression,
+    Function, ImportAttribute, ImportDeclaration, ImportExpression, JSXAttributeValue, JSXElement,
+    JSXExpressionContainer, JSXFragment, JSXText, ModuleExportName, NewExpression, ObjectProperty,
+    PropertyKey, ReturnStatement, StringLiteral, TSEnumMemberName, TSExternalModuleReference,
+    TSImportType, TSLiteral, TSLiteralType, TSModuleDeclaration, TSModuleDeclarationName,
+    TemplateLiteral, VariableDeclarator,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::scope::ScopeFlags;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+/// Where a piece of source sits, which decides how a translation call is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxContext {
+    /// Plain JavaScript: `t("key")`
+    Expression,
+    /// Argument of a call: `toast(t("key"))`
+    Argument,
+    /// Value of an object property: `{ label: t("key") }`
+    Property,
+    /// JSX attribute value: `placeholder={t("key")}`
+    Attribute,
+    /// JSX child text: `<p>{t("key")}</p>`
+    JsxText,
+    /// Inside a comment, never replaced
+    Comment,
+    /// Module specifier of `import`/`require`, never replaced
+    Import,
+    /// Directive such as `'use client'` opening a file or function body, never replaced
+    Directive,
+    /// Object key or TypeScript literal type, `{ "id": ... }` or `type Size = "sm"`, never replaced
+    Key,
+}
+
+/// A string literal, template, JSX text or comment and its context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxNode {
+    pub range: Range<usize>,
+    pub context: SyntaxContext,
+}
+
+/// A function declaration, function expression, method or arrow function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionNode {
+    /// Its own name, or that of the variable or property holding it, through
+    /// `memo(...)`/`forwardRef(...)` wrappers
+    pub name: Option<String>,
+    /// `export default function ...` or `export default () => ...`
+    pub default_export: bool,
+    pub range: Range<usize>,
+    /// `{ ... }` of a block body, or the expression an arrow returns
+    pub body: Range<usize>,
+    pub expression: bool,
+    pub asynchronous: bool,
+    /// Whether a `return` (or the arrow's expression) holds a JSX element
+    pub returns_jsx: bool,
+}
+
+impl FunctionNode {
+    /// Named in PascalCase or default-exported, as React components are
+    pub fn is_component(&self) -> bool {
+        self.default_export
+            || self
+                .name
+                .as_deref()
+                .is_some_and(|name| name.starts_with(|c: char| c.is_ascii_uppercase()))
+    }
+
+    /// Named `useX`, as React hooks are
+    pub fn is_hook(&self) -> bool {
+        self.name.as_deref().is_some_and(|name| {
+            name.strip_prefix("use")
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+        })
+    }
+}
+
+/// Literals, JSX text, comments and functions of a JavaScript/TypeScript file
+///
+/// Built from the oxc parser's AST, so each string's context comes from its
+/// parent node. Syntax errors are left to the syntax validator; where the parser
+/// cannot recover, the map is empty.
+#[derive(Debug, Default)]
+pub struct SyntaxMap {
+    nodes: Vec<SyntaxNode>,
+    /// Start of each template literal node
+    templates: Vec<usize>,
+    /// `{` of JSX attribute and child expressions
+    containers: Vec<usize>,
+    functions: Vec<FunctionNode>,
+}
+
+impl SyntaxMap {
+    /// Parse a file; `jsx` is false for plain TypeScript where `<T>` is a type assertion
+    pub fn parse(content: &str, jsx: bool) -> Self {
+        let allocator = Allocator::default();
+        let parsed = Parser::new(&allocator, content, source_type(jsx)).parse();
+
+        let mut builder = Builder {
+            source: content,
+            ..Default::default()
+        };
+        builder.visit_program(&parsed.program);
+        for comment in &parsed.program.comments {
+            builder.push(comment.span, SyntaxContext::Comment);
+        }
+
+        builder.into_map()
+    }
+
+    /// Innermost node containing a position
+    pub fn node_at(&self, pos: usize) -> Option<&SyntaxNode> {
+        self.nodes
+            .iter()
+            .filter(|node| node.range.contains(&pos))
+            .min_by_key(|node| node.range.len())
+    }
+
+    /// String, JSX text or comment containing a position; template literals
+    /// are not included since their `${}` holds code
+    pub fn literal_at(&self, pos: usize) -> Option<&SyntaxNode> {
+        self.node_at(pos)
+            .filter(|node| !self.templates.contains(&node.range.start))
+    }
+
+    /// Whether the `{` at a position opens a JSX expression, `attr={...}` or `{child}`
+    pub fn is_jsx_expression(&self, open: usize) -> bool {
+        self.containers.contains(&open)
+    }
+
+    /// Context of a position; code outside any node is an expression
+    pub fn context_at(&self, pos: usize) -> SyntaxContext {
+        self.node_at(pos)
+            .map(|node| node.context)
+            .unwrap_or(SyntaxContext::Expression)
+    }
+
+    /// All functions, outer ones before those nested in them
+    pub fn functions(&self) -> &[FunctionNode] {
+        &self.functions
+    }
+
+    /// Functions around a position, innermost first; none means module scope
+    pub fn functions_at(&self, pos: usize) -> impl Iterator<Item = &FunctionNode> {
+        self.functions
+            .iter()
+            .rev()
+            .filter(move |function| function.range.contains(&pos))
+    }
+}
+
+/// TSX, or TypeScript without JSX where `<T>` is a type assertion
+pub fn source_type(jsx: bool) -> SourceType {
+    if jsx {
+        SourceType::tsx()
+    } else {
+        SourceType::ts()
+    }
+}
+
+/// Position and message of a parser diagnostic
+pub fn located(error: &OxcDiagnostic) -> (usize, String) {
+    let at = error
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.first())
+        .map(|label| label.offset())
+        .unwrap_or(0);
+    (at, error.message.to_string())
+}
+
+/// Collects the map while walking the AST
+///
+/// Parents decide the context of their direct string children, recorded by
+/// start position before the walk reaches the literal itself.
+#[derive(Default)]
+struct Builder<'s> {
+    source: &'s str,
+    nodes: Vec<SyntaxNode>,
+    templates: Vec<usize>,
+    /// JSX elements and fragments, from `<` to the end of the closing tag
+    elements: Vec<Range<usize>>,
+    containers: Vec<usize>,
+    functions: Vec<FunctionNode>,
+    /// Spans of what each function returns, by function index
+    returns: Vec<Vec<Range<usize>>>,
+    /// Functions being walked, innermost last
+    stack: Vec<usize>,
+    contexts: HashMap<u32, SyntaxContext>,
+    /// Function start -> name of the variable or property holding it
+    names: HashMap<u32, String>,
+    default_exports: HashSet<u32>,
+}
+
+impl Builder<'_> {
+    fn push(&mut self, span: Span, context: SyntaxContext) {
+        self.nodes.push(SyntaxNode {
+            range: range(span),
+            context,
+        });
+    }
+
+    /// Record the context of a string or template that is this expression
+    fn mark(&mut self, expression: &Expression, context: SyntaxContext) {
+        if let Expression::StringLiteral(_) | Expression::TemplateLiteral(_) = expression {
+            self.contexts.insert(expression.span().start, context);
+        }
+    }
+
+    fn mark_arguments(&mut self, callee: &Expression, arguments: &[Argument]) {
+        let context = match callee {
+            Expression::Identifier(id) if id.name == "require" => SyntaxContext::Import,
+            _ => SyntaxContext::Argument,
+        };
+        for argument in arguments {
+            if let Some(expression) = argument.as_expression() {
+                self.mark(expression, context);
+            }
+        }
+    }
+
+    fn enter_function(
+        &mut self,
+        span: Span,
+        name: Option<String>,
+        body: Range<usize>,
+        expression: bool,
+        asynchronous: bool,
+    ) {
+        let name = name.or_else(|| self.names.get(&span.start).cloned());
+        self.functions.push(FunctionNode {
+            name,
+            default_export: self.default_exports.contains(&span.start),
+            range: range(span),
+            body: body.clone(),
+            expression,
+            asynchronous,
+            returns_jsx: false,
+        });
+        self.returns
+            .push(if expression { vec![body] } else { Vec::new() });
+        self.stack.push(self.functions.len() - 1);
+    }
+
+    fn into_map(mut self) -> SyntaxMap {
+        for (function, returns) in self.functions.iter_mut().zip(&self.returns) {
+            function.returns_jsx = self.elements.iter().any(|element| {
+                returns
+                    .iter()
+                    .any(|r| r.start <= element.start && element.end <= r.end)
+            });
+        }
+        SyntaxMap {
+            nodes: self.nodes,
+            templates: self.templates,
+            containers: self.containers,
+            functions: self.functions,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for Builder<'_> {
+    fn visit_string_literal(&mut self, it: &StringLiteral<'a>) {
+        let context = self
+            .contexts
+            .remove(&it.span.start)
+            .unwrap_or(SyntaxContext::Expression);
+        self.push(it.span, context);
+    }
+
+    fn visit_template_literal(&mut self, it: &TemplateLiteral<'a>) {
+        let context = self
+            .contexts
+            .remove(&it.span.start)
+            .unwrap_or(SyntaxContext::Expression);
+        self.push(it.span, context);
+        self.templates.push(it.span.start as usize);
+        walk::walk_template_literal(self, it);
+    }
+
+    fn visit_directive(&mut self, it: &Directive<'a>) {
+        self.push(it.expression.span, SyntaxContext::Directive);
+    }
+
+    fn visit_jsx_text(&mut self, it: &JSXText<'a>) {
+        // Decoded `value` may differ in length from the source; trim what is written
+        let raw = it.span.source_text(self.source);
+        let leading = raw.len() - raw.trim_start().len();
+        let trimmed = raw.trim();
+        if !trimmed.is_empty() {
+            let start = it.span.start as usize + leading;
+            self.nodes.push(SyntaxNode {
+                range: start..start + trimmed.len(),
+                context: SyntaxContext::JsxText,
+            });
+        }
+    }
+
+    fn visit_jsx_element(&mut self, it: &JSXElement<'a>) {
+        self.elements.push(range(it.span));
+        walk::walk_jsx_element(self, it);
+    }
+
+    fn visit_jsx_fragment(&mut self, it: &JSXFragment<'a>) {
+        self.elements.push(range(it.span));
+        walk::walk_jsx_fragment(self, it);
+    }
+
+    fn visit_jsx_expression_container(&mut self, it: &JSXExpressionContainer<'a>) {
+        self.containers.push(it.span.start as usize);
+        walk::walk_jsx_expression_container(self, it);
+    }
+
+    fn visit_jsx_attribute_value(&mut self, it: &JSXAttributeValue<'a>) {
+        if let JSXAttributeValue::StringLiteral(literal) = it {
+            self.contexts
+                .insert(literal.span.start, SyntaxContext::Attribute);
+        }
+        walk::walk_jsx_attribute_value(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        self.mark_arguments(&it.callee, &it.arguments);
+        walk::walk_call_expression(self, it);
+    }
+
+    fn visit_new_expression(&mut self, it: &NewExpression<'a>) {
+        self.mark_arguments(&it.callee, &it.arguments);
+        walk::walk_new_expression(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        self.mark(&it.value, SyntaxContext::Property);
+        if let Some(name) = it.key.static_name() {
+            self.names.insert(
+                it.value.get_inner_expression().span().start,
+                name.to_string(),
+            );
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &PropertyKey<'a>) {
+        if let PropertyKey::StringLiteral(literal) = it {
+            self.contexts.insert(literal.span.start, SyntaxContext::Key);
+        }
+        walk::walk_property_key(self, it);
+    }
+
+    fn visit_ts_literal_type(&mut self, it: &TSLiteralType<'a>) {
+        if let TSLiteral::StringLiteral(literal) = &it.literal {
+            self.contexts.insert(literal.span.start, SyntaxContext::Key);
+        }
+        walk::walk_ts_literal_type(self, it);
+    }
+
+    fn visit_ts_enum_member_name(&mut self, it: &TSEnumMemberName<'a>) {
+        if let TSEnumMemberName::String(literal) | TSEnumMemberName::ComputedString(literal) = it {
+            self.contexts.insert(literal.span.start, SyntaxContext::Key);
+        }
+        walk::walk_ts_enum_member_name(self, it);
+    }
+
+    fn visit_import_declaration(&mut self, it: &ImportDeclaration<'a>) {
+        self.contexts
+            .insert(it.source.span.start, SyntaxContext::Import);
+        walk::walk_import_declaration(self, it);
+    }
+
+    fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
+        if let Some(source) = &it.source {
+            self.contexts
+                .insert(source.span.start, SyntaxContext::Import);
+        }
+        walk::walk_export_named_declaration(self, it);
+    }
+
+    fn visit_export_all_declaration(&mut self, it: &ExportAllDeclaration<'a>) {
+        self.contexts
+            .insert(it.source.span.start, SyntaxContext::Import);
+        walk::walk_export_all_declaration(self, it);
+    }
+
+    fn visit_module_export_name(&mut self, it: &ModuleExportName<'a>) {
+        if let ModuleExportName::StringLiteral(literal) = it {
+            self.contexts
+                .insert(literal.span.start, SyntaxContext::Import);
+        }
+        walk::walk_module_export_name(self, it);
+    }
+
+    fn visit_import_attribute(&mut self, it: &ImportAttribute<'a>) {
+        self.contexts
+            .insert(it.value.span.start, SyntaxContext::Import);
+        walk::walk_import_attribute(self, it);
+    }
+
+    fn visit_import_expression(&mut self, it: &ImportExpression<'a>) {
+        self.mark(&it.source, SyntaxContext::Import);
+        walk::walk_import_expression(self, it);
+    }
+
+    fn visit_ts_import_type(&mut self, it: &TSImportType<'a>) {
+        self.contexts
+            .insert(it.source.span.start, SyntaxContext::Import);
+        walk::walk_ts_import_type(self, it);
+    }
+
+    fn visit_ts_external_module_reference(&mut self, it: &TSExternalModuleReference<'a>) {
+        self.contexts
+            .insert(it.expression.span.start, SyntaxContext::Import);
+        walk::walk_ts_external_module_reference(self, it);
+    }
+
+    fn visit_ts_module_declaration(&mut self, it: &TSModuleDeclaration<'a>) {
+        if let TSModuleDeclarationName::StringLiteral(literal) = &it.id {
+            self.contexts
+                .insert(literal.span.start, SyntaxContext::Import);
+        }
+        walk::walk_ts_module_declaration(self, it);
+    }
+
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let (Some(name), Some(init)) = (it.id.get_identifier_name(), &it.init)
+            && let Some(start) = wrapped_function(init)
+        {
+            self.names.insert(start, name.to_string());
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+
+    fn visit_export_default_declaration(&mut self, it: &ExportDefaultDeclaration<'a>) {
+        let start = match &it.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+                Some(function.span.start)
+            }
+            declaration => declaration.as_expression().and_then(wrapped_function),
+        };
+        if let Some(start) = start {
+            self.default_exports.insert(start);
+        }
+        walk::walk_export_default_declaration(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        let Some(body) = &it.body else {
+            // Overloads and `declare function` have no body to run
+            return walk::walk_function(self, it, flags);
+        };
+        self.enter_function(
+            it.span,
+            it.name().map(|name| name.to_string()),
+            range(body.span),
+            false,
+            it.r#async,
+        );
+        walk::walk_function(self, it, flags);
+        self.stack.pop();
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        let body = match it.get_expression() {
+            Some(expression) if it.expression => range(expression.span()),
+            _ => range(it.body.span),
+        };
+        self.enter_function(it.span, None, body, it.expression, it.r#async);
+        walk::walk_arrow_function_expression(self, it);
+        self.stack.pop();
+    }
+
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        if let (Some(&function), Some(argument)) = (self.stack.last(), &it.argument) {
+            self.returns[function].push(range(argument.span()));
+        }
+        walk::walk_return_statement(self, it);
+    }
+}
+
+/// Start of the function an expression is, possibly wrapped in `memo(...)`,
+/// `forwardRef(...)`, parentheses or a type assertion
+fn wrapped_function(expression: &Expression) -> Option<u32> {
+    match expression.get_inner_expression() {
+        Expression::ArrowFunctionExpression(arrow) => Some(arrow.span.start),
+        Expression::FunctionExpression(function) => Some(function.span.start),
+        Expression::CallExpression(call) => {
+            let callee = match call.callee.get_inner_expression() {
+                Expression::Identifier(id) => id.name.as_str(),
+                Expression::StaticMemberExpression(member) => member.property.name.as_str(),
+                _ => return None,
+            };
+            if !matches!(callee, "memo" | "forwardRef") {
+                return None;
+            }
+            wrapped_function(call.arguments.first()?.as_expression()?)
+        }
+        _ => None,
+    }
+}
+
+fn range(span: Span) -> Range<usize> {
+    span.start as usize..span.end as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parses(content: &str, jsx: bool) -> bool {
+        let allocator = Allocator::default();
+        Parser::new(&allocator, content, source_type(jsx))
+            .parse()
+            .errors
+            .is_empty()
+    }
+
+    fn context_of(content: &str, needle: &str) -> SyntaxContext {
+        let map = SyntaxMap::parse(content, true);
+        map.context_at(content.find(needle).expect(needle))
+    }
+
+    #[test]
+    fn test_contexts() {
+        let content = r#"import React from "react";
+const re = /"[<>]"/g;
+const ok = a < b && list.length > 0 ? "Done" : "Pending";
+const items: Array<string> = ["Apple"];
+export function Form() {
+  // "Not a string"
+  return (
+    <form onSubmit={() => save("Saving")}>
+      <input placeholder="Email" title={`Hi ${name}`} />
+      <p>
+        Welcome back
+      </p>
+      <>{"Inline"}</>
+    </form>
+  );
+}
+"#;
+
+        assert_eq!(context_of(content, "\"react\""), SyntaxContext::Import);
+        assert_eq!(context_of(content, "\"Done\""), SyntaxContext::Expression);
+        assert_eq!(context_of(content, "\"Apple\""), SyntaxContext::Expression);
+        assert_eq!(
+            context_of(content, "\"Not a string\""),
+            SyntaxContext::Comment
+        );
+        assert_eq!(context_of(content, "\"Saving\""), SyntaxContext::Argument);
+        assert_eq!(context_of(content, "\"Email\""), SyntaxContext::Attribute);
+        assert_eq!(context_of(content, "`Hi"), SyntaxContext::Expression);
+        assert_eq!(context_of(content, "Welcome back"), SyntaxContext::JsxText);
+        assert_eq!(context_of(content, "\"Inline\""), SyntaxContext::Expression);
+
+        let map = SyntaxMap::parse(content, true);
+        let text = map.node_at(content.find("Welcome").unwrap()).unwrap();
+        assert_eq!(&content[text.range.clone()], "Welcome back");
+    }
+
+    #[test]
+    fn test_typescript_generics_are_not_tags() {
+        let ts = r#"const cast = <T>(value: unknown) => value as T;
+const label = "Label";"#;
+        assert_eq!(
+            SyntaxMap::parse(ts, false).context_at(ts.find("\"Label\"").unwrap()),
+            SyntaxContext::Expression
+        );
+
+        let tsx = r#"const id = <T,>(x: T) => x;
+const label = "Label";"#;
+        assert_eq!(context_of(tsx, "\"Label\""), SyntaxContext::Expression);
+    }
+
+    #[test]
+    fn test_detect_jsx_context() {
+        // Ported from the regex replacer: quotes inside JSX children are text
+        let content = r#"<div>"Hello"</div>"#;
+        assert_eq!(context_of(content, "Hello"), SyntaxContext::JsxText);
+
+        let content = r#"const msg = "Hello";"#;
+        assert_eq!(context_of(content, "Hello"), SyntaxContext::Expression);
+    }
+
+    #[test]
+    fn test_tsx_generic_arrow_is_not_a_tag() {
+        let tsx = r#"const first = <T,>(items: T[]): T => items[0];
+const pick = <T extends object>(x: T) => x;
+const el = <p title="Title">Hi there</p>;"#;
+        assert!(parses(tsx, true));
+        assert_eq!(context_of(tsx, "\"Title\""), SyntaxContext::Attribute);
+        assert_eq!(context_of(tsx, "Hi there"), SyntaxContext::JsxText);
+    }
+
+    #[test]
+    fn test_regex_and_division() {
+        let content = r#"const half = total / 2 / scale;
+const ratio = (a + b) / "2".length;
+const re = /"quoted"\/[a-z]/g;
+const label = "After";"#;
+        let map = SyntaxMap::parse(content, true);
+        assert!(parses(content, true));
+
+        // Division leaves the next string alone; the regex holds no string
+        let two = content.find("\"2\"").unwrap();
+        assert_eq!(map.node_at(two).unwrap().range, two..two + 3);
+        assert!(map.node_at(content.find("quoted").unwrap()).is_none());
+        assert_eq!(context_of(content, "\"After\""), SyntaxContext::Expression);
+    }
+
+    #[test]
+    fn test_nested_template_literals() {
+        let content = r#"const a = `outer ${ok ? `inner ${"Deep"} }` : "No"} end`;
+const b = "After";"#;
+        let map = SyntaxMap::parse(content, true);
+        assert!(parses(content, true));
+
+        let deep = content.find("\"Deep\"").unwrap();
+        assert_eq!(map.node_at(deep).unwrap().range, deep..deep + 6);
+        assert_eq!(map.literal_at(deep).unwrap().range, deep..deep + 6);
+        assert_eq!(context_of(content, "\"No\""), SyntaxContext::Expression);
+        assert_eq!(context_of(content, "\"After\""), SyntaxContext::Expression);
+        // Template text is not a string of its own
+        assert!(map.literal_at(content.find("outer").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_type_assertions_in_typescript() {
+        let ts = r#"const value = <string>input;
+const size = <number>(<unknown>raw);
+const label = "Label";"#;
+        let map = SyntaxMap::parse(ts, false);
+        assert!(parses(ts, false));
+        assert_eq!(
+            map.context_at(ts.find("\"Label\"").unwrap()),
+            SyntaxContext::Expression
+        );
+    }
+
+    #[test]
+    fn test_directives() {
+        let content = r#"// App
+'use client';
+"use strict"
+import x from "x";
+const label = 'use client';
+async function save() {
+  'use server';
+  return { "status": "Saved" };
+}"#;
+        let map = SyntaxMap::parse(content, true);
+        let at = |needle: &str, nth: usize| {
+            let pos = content.match_indices(needle).nth(nth).unwrap().0;
+            map.context_at(pos)
+        };
+        assert_eq!(at("'use client'", 0), SyntaxContext::Directive);
+        assert_eq!(at("\"use strict\"", 0), SyntaxContext::Directive);
+        assert_eq!(at("'use client'", 1), SyntaxContext::Expression);
+        assert_eq!(at("'use server'", 0), SyntaxContext::Directive);
+        assert_eq!(at("\"status\"", 0), SyntaxContext::Key);
+        assert_eq!(at("\"Saved\"", 0), SyntaxContext::Property);
+    }
+
+    #[test]
+    fn test_keys_types_and_imports() {
+        let content = r#"import data from "./data.json" with { type: "json" };
+type Size = "sm" | "lg";
+const lazy = import("./Lazy");
+const fs = require("fs");
+const sizes = { "sm": "Small", label: toast("Saved", "Done") };
+export { data as "data-set" } from "./data";
+"#;
+        assert_eq!(
+            context_of(content, "\"./data.json\""),
+            SyntaxContext::Import
+        );
+        assert_eq!(context_of(content, "\"json\""), SyntaxContext::Import);
+        assert_eq!(context_of(content, "\"sm\" |"), SyntaxContext::Key);
+        assert_eq!(context_of(content, "\"./Lazy\""), SyntaxContext::Import);
+        assert_eq!(context_of(content, "\"fs\""), SyntaxContext::Import);
+        assert_eq!(context_of(content, "\"sm\":"), SyntaxContext::Key);
+        assert_eq!(context_of(content, "\"Small\""), SyntaxContext::Property);
+        assert_eq!(context_of(content, "\"Done\""), SyntaxContext::Argument);
+        assert_eq!(context_of(content, "\"data-set\""), SyntaxContext::Import);
+    }
+
+    #[test]
+    fn test_functions() {
+        let content = r#"const LABEL = "Module";
+export default function () {
+  return <Page />;
+}
+export const Card = memo(forwardRef<HTMLDivElement, Props>((props, ref) => (
+  <div ref={ref}>{props.items.map((item) => <Item key={item} />)}</div>
+)));
+function useTitle() {
+  return "Title";
+}
+const helpers = {
+  async load() {
+    return fetch("/api");
+  },
+};
+"#;
+        let map = SyntaxMap::parse(content, true);
+        let named = |name: &str| {
+            map.functions()
+                .iter()
+                .find(|f| f.name.as_deref() == Some(name))
+                .expect(name)
+        };
+
+        assert!(
+            map.functions_at(content.find("Module").unwrap())
+                .next()
+                .is_none()
+        );
+        let page = map
+            .functions_at(content.find("<Page").unwrap())
+            .next()
+            .unwrap();
+        assert!(page.default_export && page.is_component() && page.returns_jsx);
+
+        let card = named("Card");
+        assert!(card.is_component() && card.expression && card.returns_jsx);
+        assert!(content[card.body.clone()].starts_with("(\n  <div"));
+        let item = content.find("<Item").unwrap();
+        let around: Vec<Option<&str>> = map.functions_at(item).map(|f| f.name.as_deref()).collect();
+        assert_eq!(around, vec![None, Some("Card")]);
+
+        let hook = named("useTitle");
+        assert!(hook.is_hook() && !hook.returns_jsx);
+        let load = named("load");
+        assert!(load.asynchronous && !load.is_component());
+    }
+}
@@ -1,5 +1,10 @@
+pub mod hook_injector;
 pub mod import_manager;
-pub mod regex_replacer;
+pub mod jsx_syntax;
+pub mod syntax_replacer;
+pub mod syntax_validator;
 
+pub use hook_injector::ComponentHookInjector;
 pub use import_manager::SimpleImportManager;
-pub use regex_replacer::RegexReplacer;
+pub use syntax_replacer::SyntaxReplacer;
+pub use syntax_validator::JsxSyntaxValidator;
@@ -0,0 +1,367 @@
+use super::import_manager::SimpleImportManager;
+use super::jsx_syntax::{SyntaxContext, SyntaxMap};
+use crate::domain::models::{
+    CallContext, FileType, QuoteType, ReplacementStrategy, TranslationKeyWithPosition,
+};
+use crate::domain::ports::CodeReplacer;
+use async_trait::async_trait;
+use std::ops::Range;
+use std::path::Path;
+use tokio::fs;
+
+/// Replaces strings with translation calls written for their syntax context
+///
+/// `t("key")` in expressions, `attr={t("key")}` for JSX attributes and
+/// `{t("key")}` for JSX text. Strings in comments, import specifiers, directives
+/// such as `'use client'`, object keys and TypeScript types are left alone.
+pub struct SyntaxReplacer;
+
+impl SyntaxReplacer {
+    /// Range to replace and the call to put there, or None when the key must be left alone
+    fn replacement(
+        map: &SyntaxMap,
+        key: &TranslationKeyWithPosition,
+        strategy: &ReplacementStrategy,
+    ) -> Option<(Range<usize>, String)> {
+        let node = map.node_at(key.start_byte);
+        let context = map.context_at(key.start_byte);
+
+        // A literal is replaced whole, quotes included, even if the key points inside it
+        let range = match node {
+            Some(node) if key.quote_type != QuoteType::Plural && key.end_byte <= node.range.end => {
+                node.range.clone()
+            }
+            _ => key.start_byte..key.end_byte,
+        };
+
+        let context = match context {
+            SyntaxContext::Expression | SyntaxContext::Argument | SyntaxContext::Property => {
+                CallContext::Expression
+            }
+            SyntaxContext::Attribute => CallContext::Attribute,
+            SyntaxContext::JsxText => CallContext::JsxText,
+            SyntaxContext::Comment
+            | SyntaxContext::Import
+            | SyntaxContext::Directive
+            | SyntaxContext::Key => {
+                tracing::debug!("Leaving {} at line {}: not text", key.id, key.line);
+                return None;
+            }
+        };
+        Some((
+            range,
+            strategy.translate_call(&key.id, &key.source, context, &key.params),
+        ))
+    }
+}
+
+#[async_trait]
+impl CodeReplacer for SyntaxReplacer {
+    async fn replace_in_file(
+        &self,
+        file_path: &Path,
+        keys: &[TranslationKeyWithPosition],
+        strategy: &ReplacementStrategy,
+    ) -> anyhow::Result<String> {
+        let content = fs::read_to_string(file_path).await?;
+
+        // `<T>` is a type assertion in .ts files, an element everywhere else
+        let file_type = file_path
+            .extension()
+            .map(|ext| FileType::from_extension(&ext.to_string_lossy()))
+            .unwrap_or(FileType::Other);
+        let map = SyntaxMap::parse(&content, !matches!(file_type, FileType::TypeScript));
+
+        let mut messages: Vec<(&str, &str)> = Vec::new();
+        let mut replacements: Vec<(Range<usize>, String)> = keys
+            .iter()
+            .filter(|k| k.start_byte <= k.end_byte && k.end_byte <= content.len())
+            .filter_map(|k| {
+                let replacement = Self::replacement(&map, k, strategy)?;
+                if !messages.iter().any(|(id, _)| *id == k.id) {
+                    messages.push((&k.id, &k.source));
+                }
+                Some(replacement)
+            })
+            .collect();
+
+        // Message descriptors go after the imports, ahead of any code using them
+        if let Some(block) = strategy.messages_block(&messages) {
+            let (at, text) = match SimpleImportManager::find_import_insertion_point(&content) {
+                0 => (0, format!("{}\n\n", block)),
+                end => (end, format!("\n\n{}", block)),
+            };
+            replacements.push((at..at, text));
+        }
+
+        // Replace back-to-front so earlier byte positions stay valid
+        replacements.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+
+        let mut result = content.clone();
+        let mut replaced_from = content.len();
+        for (range, call) in replacements {
+            // Overlapping keys (a literal inside a replaced ternary) are skipped
+            if range.end > replaced_from {
+                continue;
+            }
+            replaced_from = range.start;
+            result.replace_range(range, &call);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn replace(
+        name: &str,
+        content: &str,
+        keys: &[(&str, &str)],
+        strategy: &ReplacementStrategy,
+    ) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "langsmith-replace-{}-{}.jsx",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, content).unwrap();
+
+        let keys: Vec<TranslationKeyWithPosition> = keys
+            .iter()
+            .map(|(id, text)| {
+                let start = content.find(text).expect(text);
+                TranslationKeyWithPosition {
+                    id: id.to_string(),
+                    source: text.trim_matches('"').to_string(),
+                    file_path: path.to_string_lossy().to_string(),
+                    line: 1,
+                    start_byte: start,
+                    end_byte: start + text.len(),
+                    quote_type: QuoteType::Double,
+                    params: Vec::new(),
+                }
+            })
+            .collect();
+
+        let result = SyntaxReplacer
+            .replace_in_file(&path, &keys, strategy)
+            .await
+            .unwrap();
+        std::fs::remove_file(&path).ok();
+        result
+    }
+
+    #[tokio::test]
+    async fn test_calls_match_their_context() {
+        let content = r#"const sorted = a < b ? "Ascending" : "Descending";
+// "Archived" in a comment stays
+const Form = () => (
+  <form>
+    <input placeholder="Email" />
+    <p>Welcome back</p>
+  </form>
+);"#;
+
+        let result = replace(
+            "contexts",
+            content,
+            &[
+                ("ascending", "\"Ascending\""),
+                ("archived", "\"Archived\""),
+                ("email", "\"Email\""),
+                ("welcome_back", "Welcome back"),
+            ],
+            &ReplacementStrategy::ReactI18n,
+        )
+        .await;
+
+        assert_eq!(
+            result,
+            r#"const sorted = a < b ? t("ascending") : "Descending";
+// "Archived" in a comment stays
+const Form = () => (
+  <form>
+    <input placeholder={t("email")} />
+    <p>{t("welcome_back")}</p>
+  </form>
+);"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plural_ternary_becomes_call_with_count() {
+        let path =
+            std::env::temp_dir().join(format!("langsmith-plural-{}.jsx", std::process::id()));
+        let content = "const label = files.length === 1 ? \"1 file\" : `${files.length} files`;";
+        std::fs::write(&path, content).unwrap();
+
+        let start = content.find("files.length").unwrap();
+        let key = TranslationKeyWithPosition {
+            id: "filescount_files".to_string(),
+            source: "{filesCount, plural, one {# file} other {# files}}".to_string(),
+            file_path: path.to_string_lossy().to_string(),
+            line: 1,
+            start_byte: start,
+            end_byte: content.len() - 1,
+            quote_type: QuoteType::Plural,
+            params: vec![("filesCount".to_string(), "files.length".to_string())],
+        };
+
+        let result = SyntaxReplacer
+            .replace_in_file(&path, &[key], &ReplacementStrategy::ReactI18n)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            "const label = t(\"filescount_files\", { count: files.length });"
+        );
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_react_intl_messages() {
+        let content = r#"import React from 'react';
+
+const Form = () => (
+  <form>
+    <input placeholder="Email" />
+    <p>Say "hi" now</p>
+  </form>
+);"#;
+        let keys = [("email", "\"Email\""), ("say_hi", "Say \"hi\" now")];
+
+        assert_eq!(
+            replace(
+                "intl",
+                content,
+                &keys,
+                &ReplacementStrategy::ReactIntl {
+                    define_messages: false
+                }
+            )
+            .await,
+            r#"import React from 'react';
+
+const Form = () => (
+  <form>
+    <input placeholder={intl.formatMessage({ id: "email", defaultMessage: "Email" })} />
+    <p><FormattedMessage id="say_hi" defaultMessage={"Say \"hi\" now"} /></p>
+  </form>
+);"#
+        );
+
+        assert_eq!(
+            replace(
+                "intl-messages",
+                content,
+                &keys,
+                &ReplacementStrategy::ReactIntl {
+                    define_messages: true
+                }
+            )
+            .await,
+            r#"import React from 'react';
+
+const messages = defineMessages({
+  email: {
+    id: "email",
+    defaultMessage: "Email",
+  },
+  say_hi: {
+    id: "say_hi",
+    defaultMessage: "Say \"hi\" now",
+  },
+});
+
+const Form = () => (
+  <form>
+    <input placeholder={intl.formatMessage(messages.email)} />
+    <p><FormattedMessage {...messages.say_hi} /></p>
+  </form>
+);"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_define_messages_without_imports() {
+        let strategy = ReplacementStrategy::ReactIntl {
+            define_messages: true,
+        };
+        let keys = [("email", "\"Email\"")];
+        let block = r#"const messages = defineMessages({
+  email: {
+    id: "email",
+    defaultMessage: "Email",
+  },
+});"#;
+
+        // First thing in the file
+        let content = r#"const Field = () => <input placeholder="Email" />;"#;
+        assert_eq!(
+            replace("intl-bare", content, &keys, &strategy).await,
+            format!(
+                "{}\n\nconst Field = () => <input placeholder={{intl.formatMessage(messages.email)}} />;",
+                block
+            )
+        );
+
+        // Below the directive, which must stay first
+        let content = "'use client';\n\nconst Field = () => <input placeholder=\"Email\" />;";
+        assert_eq!(
+            replace("intl-directive", content, &keys, &strategy).await,
+            format!(
+                "'use client';\n\n{}\n\nconst Field = () => <input placeholder={{intl.formatMessage(messages.email)}} />;",
+                block
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lingui_macros() {
+        let content = r#"const title = "Settings";
+const Form = () => (
+  <form>
+    <input placeholder="Use `name`" />
+    <p>Welcome back</p>
+  </form>
+);"#;
+        let keys = [
+            ("settings", "\"Settings\""),
+            ("use_name", "\"Use `name`\""),
+            ("welcome_back", "Welcome back"),
+        ];
+
+        assert_eq!(
+            replace("lingui", content, &keys, &ReplacementStrategy::Lingui).await,
+            r#"const title = t`Settings`;
+const Form = () => (
+  <form>
+    <input placeholder={t`Use \`name\``} />
+    <p><Trans>Welcome back</Trans></p>
+  </form>
+);"#
+        );
+
+        let params = [
+            ("itemsCount".to_string(), "items.length".to_string()),
+            ("name".to_string(), "user.name".to_string()),
+        ];
+        let source = "{itemsCount, plural, =0 {No items} one {{name} has one item} other {{name} has # items}}";
+        assert_eq!(
+            ReplacementStrategy::Lingui.translate_call(
+                "k",
+                source,
+                CallContext::Expression,
+                &params
+            ),
+            "plural(items.length, { _0: `No items`, one: `${user.name} has one item`, other: `${user.name} has # items` })"
+        );
+        assert_eq!(
+            ReplacementStrategy::Lingui.translate_call("k", source, CallContext::JsxText, &params),
+            "<Plural value={items.length} _0=\"No items\" one={`${user.name} has one item`} other={`${user.name} has # items`} />"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use super::jsx_syntax;
+use crate::domain::models::{FileType, SyntaxError};
+use crate::domain::ports::SyntaxValidator;
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+
+/// Reports the first error of the oxc parser, then of its semantic syntax checks
+///
+/// Catches what a rewrite can break, such as a call nested in a quoted
+/// attribute, an unclosed `{`, a mismatched closing tag or a `t` declared twice.
+pub struct JsxSyntaxValidator;
+
+impl SyntaxValidator for JsxSyntaxValidator {
+    fn validate(&self, content: &str, file_type: FileType) -> Option<SyntaxError> {
+        // Only the script of .vue and .html files would be JavaScript
+        if !matches!(
+            file_type,
+            FileType::JavaScript | FileType::TypeScript | FileType::JSX | FileType::TSX
+        ) {
+            return None;
+        }
+
+        let allocator = Allocator::default();
+        let jsx = !matches!(file_type, FileType::TypeScript);
+        let parsed = Parser::new(&allocator, content, jsx_syntax::source_type(jsx)).parse();
+        // Redeclarations and the like are only checked on a program that parses
+        let errors = if parsed.errors.is_empty() {
+            SemanticBuilder::new()
+                .with_check_syntax_error(true)
+                .build(&parsed.program)
+                .errors
+        } else {
+            parsed.errors
+        };
+        let (pos, message) = errors
+            .iter()
+            .map(jsx_syntax::located)
+            .min_by_key(|(at, _)| *at)?;
+        let before = &content[..pos.min(content.len())];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        Some(SyntaxError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(content: &str) -> Option<String> {
+        JsxSyntaxValidator
+            .validate(content, FileType::TSX)
+            .map(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_valid_code_passes() {
+        let content = r#"import React from "react";
+const re = /[(]"/g;
+export function App<T,>({ items }: { items: T[] }) {
+  const { t } = useTranslation();
+  return (
+    <ul title={t("list")} className="list">
+      {items.map((item) => <li key={`${item}`}>{t("item", { count: 1 })}</li>)}
+      <>It's fine</>
+    </ul>
+  );
+}
+"#;
+        assert_eq!(check(content), None);
+    }
+
+    #[test]
+    fn test_broken_rewrites_are_located() {
+        for (content, error) in [
+            (
+                "const a = <input title=\"{t(\"x\")}\" />;",
+                "1:30: Unexpected token",
+            ),
+            (
+                "function App() {\n  return <p>{t(\"x\")</p>;\n}",
+                "2:21: Unexpected token",
+            ),
+            (
+                "const a = <div><span></div>;",
+                "1:24: Expected corresponding JSX closing tag for 'span'.",
+            ),
+            (
+                "const a = [t(\"x\");",
+                "1:18: Expected `,` or `]` but found `;`",
+            ),
+            ("const s = \"open;\n", "1:11: Unterminated string"),
+            ("const s = `a ${b`;", "1:17: Unterminated string"),
+            (
+                "function App() {\n  const { t } = useTranslation();\n  const t = 1;\n}",
+                "2:11: Identifier `t` has already been declared",
+            ),
+        ] {
+            assert_eq!(check(content).as_deref(), Some(error), "{}", content);
+        }
+    }
+}
@@ -1,10 +1,89 @@
+use crate::domain::locale::Locale;
+use crate::domain::models::{CustomStrategy, ReplacementStrategy, TranslationStyle};
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::env;
+use std::path::{Path, PathBuf};
+
+/// Project config file looked up in the working directory
+pub const PROJECT_CONFIG_FILE: &str = "langsmith.toml";
+
+/// Project configuration (langsmith.toml)
+///
+/// # Example
+/// ```toml
+/// [translate]
+/// providers = ["openai"]          # chain for languages without a route
+///
+/// [[translate.routes]]
+/// languages = ["de", "fr", "es"]
+/// providers = ["deepl", "openai"] # DeepL first, OpenAI if it fails
+///
+/// [styles.de]
+/// formality = "informal"          # "du" instead of "Sie"
+/// tone = "friendly and concise"   # style guide for LLM providers
+///
+/// [strategies.tr]                 # replace --strategy tr
+/// import = "import { tr } from '@/lib/i18n';"
+/// expression = "tr('{key}'{args})"
+/// jsx = "{tr('{key}'{args})}"
+/// attribute = "{tr('{key}'{args})}"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProjectConfig {
+    pub translate: TranslateConfig,
+    /// Target language -> style; "de" also applies to "de-AT" unless it has its own entry
+    pub styles: HashMap<String, TranslationStyle>,
+    /// Custom replacement strategies by name
+    pub strategies: HashMap<String, CustomStrategy>,
+}
+
+impl ProjectConfig {
+    /// Replacement strategy by name: a `[strategies.<name>]` entry, or a built-in one
+    pub fn strategy(&self, name: &str) -> anyhow::Result<ReplacementStrategy> {
+        match self.strategies.get(name) {
+            Some(custom) => Ok(ReplacementStrategy::Custom(custom.clone())),
+            None => ReplacementStrategy::from_str(name),
+        }
+    }
+}
+
+/// `[translate]` section: provider chains and per-language routing
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TranslateConfig {
+    /// Provider chain used for languages without a route
+    pub providers: Vec<String>,
+    pub routes: Vec<LanguageRoute>,
+}
+
+/// Route a set of target languages to a provider chain
+#[derive(Debug, Clone, Deserialize)]
+pub struct LanguageRoute {
+    pub languages: Vec<String>,
+    pub providers: Vec<String>,
+}
+
+impl TranslateConfig {
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty() && self.routes.is_empty()
+    }
+}
 
 /// Configuration for API providers
 #[derive(Debug, Clone)]
 pub struct ApiConfig {
     pub provider: ApiProvider,
     pub api_key: String,
+    /// Endpoint override for self-hosted or OpenAI-compatible servers
+    pub base_url: Option<String>,
+    /// Model name for LLM providers
+    pub model: Option<String>,
+    /// Google Cloud project id
+    pub project_id: Option<String>,
+    /// Azure Translator resource region
+    pub region: Option<String>,
 }
 
 /// Supported API providers
@@ -12,6 +91,18 @@ pub struct ApiConfig {
 pub enum ApiProvider {
     DeepL,
     OpenAI,
+    /// Self-hosted OpenAI-compatible server (Ollama, vLLM, LM Studio)
+    OpenAICompatible,
+    /// Self-hosted or public LibreTranslate instance
+    LibreTranslate,
+    /// Google Cloud Translation v3
+    Google,
+    /// Azure AI Translator
+    Azure,
+    /// Anthropic Messages API
+    Anthropic,
+    /// Offline pseudo-localization, no API key needed
+    Pseudo,
 }
 
 impl ApiProvider {
@@ -19,8 +110,16 @@ impl ApiProvider {
         match s.to_lowercase().as_str() {
             "deepl" => Ok(ApiProvider::DeepL),
             "openai" => Ok(ApiProvider::OpenAI),
+            "openai-compatible" | "local" | "ollama" | "vllm" | "lmstudio" => {
+                Ok(ApiProvider::OpenAICompatible)
+            }
+            "libretranslate" => Ok(ApiProvider::LibreTranslate),
+            "google" => Ok(ApiProvider::Google),
+            "azure" => Ok(ApiProvider::Azure),
+            "anthropic" => Ok(ApiProvider::Anthropic),
+            "pseudo" => Ok(ApiProvider::Pseudo),
             _ => Err(anyhow::anyhow!(
-                "Unknown provider: {}. Supported: deepl, openai",
+                "Unknown provider: {}. Supported: deepl, openai, openai-compatible (local, ollama, vllm, lmstudio), libretranslate, google, azure, anthropic, pseudo",
                 s
             )),
         }
@@ -30,8 +129,85 @@ impl ApiProvider {
         match self {
             ApiProvider::DeepL => "DEEPL_API_KEY",
             ApiProvider::OpenAI => "OPENAI_API_KEY",
+            ApiProvider::OpenAICompatible => "LOCAL_LLM_API_KEY",
+            ApiProvider::LibreTranslate => "LIBRETRANSLATE_API_KEY",
+            ApiProvider::Google => "GOOGLE_TRANSLATE_ACCESS_TOKEN",
+            ApiProvider::Azure => "AZURE_TRANSLATOR_KEY",
+            ApiProvider::Anthropic => "ANTHROPIC_API_KEY",
+            ApiProvider::Pseudo => "",
+        }
+    }
+
+    /// Environment variable holding the endpoint override, if the provider has one
+    pub fn base_url_env_var(&self) -> Option<&'static str> {
+        match self {
+            ApiProvider::DeepL => Some("DEEPL_BASE_URL"),
+            ApiProvider::OpenAI => Some("OPENAI_BASE_URL"),
+            ApiProvider::OpenAICompatible => Some("LOCAL_LLM_BASE_URL"),
+            ApiProvider::LibreTranslate => Some("LIBRETRANSLATE_URL"),
+            ApiProvider::Google => Some("GOOGLE_TRANSLATE_BASE_URL"),
+            ApiProvider::Azure => Some("AZURE_TRANSLATOR_ENDPOINT"),
+            ApiProvider::Anthropic => Some("ANTHROPIC_BASE_URL"),
+            _ => None,
         }
     }
+
+    /// Endpoint used when neither CLI flag nor environment variable is set
+    pub fn default_base_url(&self) -> Option<&'static str> {
+        match self {
+            ApiProvider::OpenAICompatible => Some("http://localhost:11434/v1"),
+            ApiProvider::LibreTranslate => Some("http://localhost:5000"),
+            _ => None,
+        }
+    }
+
+    /// Environment variable holding the model name for LLM providers
+    pub fn model_env_var(&self) -> Option<&'static str> {
+        match self {
+            ApiProvider::OpenAI => Some("OPENAI_MODEL"),
+            ApiProvider::OpenAICompatible => Some("LOCAL_LLM_MODEL"),
+            ApiProvider::Anthropic => Some("ANTHROPIC_MODEL"),
+            _ => None,
+        }
+    }
+
+    /// Environment variable holding the Google Cloud project id
+    pub fn project_env_var(&self) -> Option<&'static str> {
+        match self {
+            ApiProvider::Google => Some("GOOGLE_CLOUD_PROJECT"),
+            _ => None,
+        }
+    }
+
+    /// Environment variable holding the Azure resource region
+    pub fn region_env_var(&self) -> Option<&'static str> {
+        match self {
+            ApiProvider::Azure => Some("AZURE_TRANSLATOR_REGION"),
+            _ => None,
+        }
+    }
+
+    /// Model used when neither CLI flag nor environment variable is set
+    pub fn default_model(&self) -> Option<&'static str> {
+        match self {
+            ApiProvider::OpenAICompatible => Some("llama3.1"),
+            _ => None,
+        }
+    }
+
+    /// Whether the provider needs an API key at all
+    ///
+    /// Self-hosted servers usually run without authentication, so their key is optional.
+    pub fn requires_api_key(&self) -> bool {
+        matches!(
+            self,
+            ApiProvider::DeepL
+                | ApiProvider::OpenAI
+                | ApiProvider::Google
+                | ApiProvider::Azure
+                | ApiProvider::Anthropic
+        )
+    }
 }
 
 /// Manages API configuration with priority order
@@ -41,33 +217,113 @@ impl ConfigManager {
     /// Get API configuration with priority:
     /// 1. CLI flag (highest)
     /// 2. Environment variable
-    /// 3. Error (lowest)
-    pub fn get_api_config(provider: &str, cli_api_key: Option<&str>) -> anyhow::Result<ApiConfig> {
+    /// 3. Provider default / error (lowest)
+    pub fn get_api_config(
+        provider: &str,
+        cli_api_key: Option<&str>,
+        cli_base_url: Option<&str>,
+        cli_model: Option<&str>,
+    ) -> anyhow::Result<ApiConfig> {
         let provider = ApiProvider::from_str(provider)?;
 
+        let api_key = Self::resolve_api_key(&provider, cli_api_key)?;
+        let base_url = Self::resolve_base_url(&provider, cli_base_url)?;
+        let model = Self::resolve_setting(cli_model, provider.model_env_var())
+            .or_else(|| provider.default_model().map(str::to_string));
+        let project_id = Self::resolve_setting(None, provider.project_env_var());
+        let region = Self::resolve_setting(None, provider.region_env_var());
+
+        if provider == ApiProvider::Google && project_id.is_none() {
+            return Err(anyhow::anyhow!(
+                "Google Cloud project not found. Set GOOGLE_CLOUD_PROJECT environment variable"
+            ));
+        }
+
+        Ok(ApiConfig {
+            provider,
+            api_key,
+            base_url,
+            model,
+            project_id,
+            region,
+        })
+    }
+
+    /// Directory of the project config: that of an explicit file, else the working directory
+    pub fn project_dir(config: Option<&Path>) -> PathBuf {
+        config
+            .and_then(Path::parent)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+            .to_path_buf()
+    }
+
+    /// Load the project config from an explicit path, or langsmith.toml if present
+    pub fn load_project_config(path: Option<&Path>) -> anyhow::Result<ProjectConfig> {
+        let path = match path {
+            Some(path) => path,
+            None if Path::new(PROJECT_CONFIG_FILE).exists() => Path::new(PROJECT_CONFIG_FILE),
+            None => return Ok(ProjectConfig::default()),
+        };
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read config file {}: {}", path.display(), e))?;
+        let config = Self::parse_project_config(&content)
+            .map_err(|e| anyhow::anyhow!("Invalid config file {}: {}", path.display(), e))?;
+
+        tracing::debug!("Loaded project config from {}", path.display());
+        Ok(config)
+    }
+
+    fn parse_project_config(content: &str) -> anyhow::Result<ProjectConfig> {
+        let config: ProjectConfig = toml::from_str(content)?;
+
+        // Fail early on typos instead of at the first translation
+        let routed = config.translate.routes.iter().flat_map(|r| &r.providers);
+        for provider in config.translate.providers.iter().chain(routed) {
+            ApiProvider::from_str(provider)?;
+        }
+        for lang in config.styles.keys() {
+            Locale::parse(lang)?;
+        }
+        for (name, strategy) in &config.strategies {
+            strategy
+                .validate()
+                .map_err(|e| anyhow::anyhow!("strategies.{}: {}", name, e))?;
+        }
+
+        Ok(config)
+    }
+
+    fn resolve_api_key(
+        provider: &ApiProvider,
+        cli_api_key: Option<&str>,
+    ) -> anyhow::Result<String> {
         // Priority 1: CLI flag
         if let Some(key) = cli_api_key {
             if key.is_empty() {
                 return Err(anyhow::anyhow!("API key provided via CLI is empty"));
             }
             tracing::debug!("Using API key from CLI flag");
-            return Ok(ApiConfig {
-                provider,
-                api_key: key.to_string(),
-            });
+            return Ok(key.to_string());
         }
 
-        // Priority 2: Environment variable
         let env_var = provider.env_var_name();
+        if env_var.is_empty() {
+            return Ok(String::new());
+        }
+
+        // Priority 2: Environment variable
         if let Ok(key) = env::var(env_var) {
             if key.is_empty() {
                 return Err(anyhow::anyhow!("Environment variable {} is empty", env_var));
             }
             tracing::debug!("Using API key from environment variable: {}", env_var);
-            return Ok(ApiConfig {
-                provider,
-                api_key: key,
-            });
+            return Ok(key);
+        }
+
+        if !provider.requires_api_key() {
+            return Ok(String::new());
         }
 
         // Priority 3: Error
@@ -76,11 +332,58 @@ impl ConfigManager {
             env_var
         ))
     }
+
+    fn resolve_base_url(
+        provider: &ApiProvider,
+        cli_base_url: Option<&str>,
+    ) -> anyhow::Result<Option<String>> {
+        let Some(url) = Self::resolve_setting(cli_base_url, provider.base_url_env_var())
+            .or_else(|| provider.default_base_url().map(str::to_string))
+        else {
+            return Ok(None);
+        };
+
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(anyhow::anyhow!(
+                "Invalid base URL: {} (expected http:// or https://)",
+                url
+            ));
+        }
+
+        tracing::debug!("Using base URL: {}", url);
+        Ok(Some(url.trim_end_matches('/').to_string()))
+    }
+
+    /// CLI value first, then a non-empty environment variable
+    fn resolve_setting(cli_value: Option<&str>, env_var: Option<&str>) -> Option<String> {
+        cli_value
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .or_else(|| {
+                env_var
+                    .and_then(|name| env::var(name).ok())
+                    .filter(|v| !v.is_empty())
+            })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::models::CallContext;
+
+    #[test]
+    fn test_project_dir() {
+        assert_eq!(
+            ConfigManager::project_dir(Some(Path::new("web/langsmith.toml"))),
+            PathBuf::from("web")
+        );
+        assert_eq!(
+            ConfigManager::project_dir(Some(Path::new("langsmith.toml"))),
+            PathBuf::from(".")
+        );
+        assert_eq!(ConfigManager::project_dir(None), PathBuf::from("."));
+    }
 
     #[test]
     fn test_provider_from_str() {
@@ -90,13 +393,199 @@ mod tests {
             ApiProvider::OpenAI
         );
         assert_eq!(ApiProvider::from_str("DEEPL").unwrap(), ApiProvider::DeepL);
+        assert_eq!(
+            ApiProvider::from_str("pseudo").unwrap(),
+            ApiProvider::Pseudo
+        );
+        assert_eq!(
+            ApiProvider::from_str("ollama").unwrap(),
+            ApiProvider::OpenAICompatible
+        );
+        assert_eq!(
+            ApiProvider::from_str("libretranslate").unwrap(),
+            ApiProvider::LibreTranslate
+        );
+        assert_eq!(
+            ApiProvider::from_str("Google").unwrap(),
+            ApiProvider::Google
+        );
+        assert_eq!(ApiProvider::from_str("azure").unwrap(), ApiProvider::Azure);
+        assert_eq!(
+            ApiProvider::from_str("anthropic").unwrap(),
+            ApiProvider::Anthropic
+        );
         assert!(ApiProvider::from_str("invalid").is_err());
     }
 
+    #[test]
+    fn test_commercial_provider_env_vars() {
+        assert_eq!(
+            ApiProvider::Google.env_var_name(),
+            "GOOGLE_TRANSLATE_ACCESS_TOKEN"
+        );
+        assert_eq!(ApiProvider::Azure.env_var_name(), "AZURE_TRANSLATOR_KEY");
+        assert_eq!(ApiProvider::Anthropic.env_var_name(), "ANTHROPIC_API_KEY");
+        assert!(ApiProvider::Anthropic.requires_api_key());
+    }
+
     #[test]
     fn test_config_cli_priority() {
-        let config = ConfigManager::get_api_config("deepl", Some("cli-key"));
+        let config = ConfigManager::get_api_config("deepl", Some("cli-key"), None, None);
         assert!(config.is_ok());
         assert_eq!(config.unwrap().api_key, "cli-key");
     }
+
+    #[test]
+    fn test_config_pseudo_needs_no_key() {
+        let config = ConfigManager::get_api_config("pseudo", None, None, None).unwrap();
+        assert_eq!(config.provider, ApiProvider::Pseudo);
+        assert!(config.api_key.is_empty());
+    }
+
+    #[test]
+    fn test_config_base_url_cli_override() {
+        let config = ConfigManager::get_api_config(
+            "openai",
+            Some("key"),
+            Some("http://gpu-box:8000/v1/"),
+            Some("mistral"),
+        )
+        .unwrap();
+        assert_eq!(config.base_url.as_deref(), Some("http://gpu-box:8000/v1"));
+        assert_eq!(config.model.as_deref(), Some("mistral"));
+    }
+
+    #[test]
+    fn test_config_invalid_base_url() {
+        let config = ConfigManager::get_api_config("openai", Some("key"), Some("gpu-box"), None);
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn test_parse_project_config_routes() {
+        let config = ConfigManager::parse_project_config(
+            r#"
+[translate]
+providers = ["openai"]
+
+[[translate.routes]]
+languages = ["de", "fr"]
+providers = ["deepl", "openai"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.translate.providers, vec!["openai"]);
+        assert_eq!(config.translate.routes.len(), 1);
+        assert_eq!(config.translate.routes[0].languages, vec!["de", "fr"]);
+        assert_eq!(
+            config.translate.routes[0].providers,
+            vec!["deepl", "openai"]
+        );
+    }
+
+    #[test]
+    fn test_parse_project_config_unknown_provider() {
+        let result = ConfigManager::parse_project_config(
+            r#"
+[[translate.routes]]
+languages = ["de"]
+providers = ["deeepl"]
+"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_project_config_styles() {
+        let config = ConfigManager::parse_project_config(
+            r#"
+[styles.de]
+formality = "informal"
+
+[styles.fr]
+formality = "informal"
+tone = "friendly"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.styles["de"].formality,
+            Some(crate::domain::models::Formality::Informal)
+        );
+        assert_eq!(config.styles["fr"].tone.as_deref(), Some("friendly"));
+
+        assert!(
+            ConfigManager::parse_project_config("[styles.de]\nformality = \"casual\"").is_err()
+        );
+        assert!(ConfigManager::parse_project_config("[styles.german]\ntone = \"x\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_project_config_strategies() {
+        let config = ConfigManager::parse_project_config(
+            r#"
+[strategies.tr]
+import = "import { tr } from '@/lib/i18n';"
+expression = "tr('{key}'{args})"
+jsx = "{tr('{key}'{args})}"
+attribute = "{tr('{key}')}"
+"#,
+        )
+        .unwrap();
+
+        let strategy = config.strategy("tr").unwrap();
+        assert_eq!(
+            strategy.import_statements(),
+            vec!["import { tr } from '@/lib/i18n';"]
+        );
+        let params = [("name".to_string(), "user.name".to_string())];
+        assert_eq!(
+            strategy.translate_call("hello", "Hello {name}", CallContext::Expression, &params),
+            "tr('hello', { name: user.name })"
+        );
+        assert_eq!(
+            strategy.translate_call("hello", "Hello", CallContext::Attribute, &[]),
+            "{tr('hello')}"
+        );
+        assert!(matches!(
+            config.strategy("react-i18n"),
+            Ok(ReplacementStrategy::ReactI18n)
+        ));
+        assert!(config.strategy("trr").is_err());
+
+        // Every template needs the key; a hook needs a function to declare
+        assert!(
+            ConfigManager::parse_project_config(
+                "[strategies.tr]\nimport = \"\"\nexpression = \"tr()\"\njsx = \"{key}\"\nattribute = \"{key}\""
+            )
+            .is_err()
+        );
+        assert!(
+            ConfigManager::parse_project_config(
+                "[strategies.tr]\nimport = \"\"\nexpression = \"i18n.tr('{key}')\"\njsx = \"{key}\"\nattribute = \"{key}\"\nhook = \"const { tr } = useTr();\""
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_project_config() {
+        let config = ConfigManager::parse_project_config("").unwrap();
+        assert!(config.translate.is_empty());
+    }
+
+    #[test]
+    fn test_default_base_urls() {
+        assert_eq!(
+            ApiProvider::OpenAICompatible.default_base_url(),
+            Some("http://localhost:11434/v1")
+        );
+        assert_eq!(
+            ApiProvider::LibreTranslate.default_base_url(),
+            Some("http://localhost:5000")
+        );
+        assert_eq!(ApiProvider::OpenAI.default_base_url(), None);
+    }
 }
@@ -1,6 +1,7 @@
-use crate::domain::models::{FileType, LanguageFile};
+use crate::domain::models::{CatalogMetadata, ExtractedMessage, FileType, LanguageFile};
 use crate::domain::ports::{FileScanner, FileWriter};
 use async_trait::async_trait;
+use std::collections::BTreeMap;
 use std::path::Path;
 use tokio::fs;
 use walkdir::WalkDir;
@@ -27,10 +28,37 @@ impl FileWriter for FileSystemWriter {
         Ok(())
     }
 
+    async fn write_extracted_messages(
+        &self,
+        path: &Path,
+        messages: &BTreeMap<String, ExtractedMessage>,
+    ) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        let json = serde_json::to_string_pretty(messages)?;
+        fs::write(path, json).await?;
+
+        tracing::info!("Written {}", path.display());
+        Ok(())
+    }
+
     async fn read_language_file(&self, path: &Path) -> anyhow::Result<LanguageFile> {
         let content = fs::read_to_string(path).await?;
-        let translations = serde_json::from_str(&content)?;
-        Ok(LanguageFile { translations })
+        LanguageFile::parse(&content)
+    }
+
+    async fn write_metadata(&self, path: &Path, metadata: &CatalogMetadata) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        let json = serde_json::to_string_pretty(metadata)?;
+        fs::write(path, json).await?;
+
+        tracing::info!("Written {}", path.display());
+        Ok(())
     }
 }
 
@@ -0,0 +1,220 @@
+use crate::domain::ports::VersionControl;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+/// Local git repository, driven through the `git` command
+pub struct GitRepository {
+    root: PathBuf,
+}
+
+impl GitRepository {
+    /// Repository containing `dir`, or None outside git (or without git installed)
+    pub async fn discover(dir: &Path) -> anyhow::Result<Option<Self>> {
+        let dir = std::path::absolute(dir)?;
+        let dir = if dir.is_dir() {
+            dir
+        } else {
+            dir.parent().map(Path::to_path_buf).unwrap_or(dir)
+        };
+
+        let output = match Command::new("git")
+            .arg("-C")
+            .arg(&dir)
+            .args(["rev-parse", "--show-toplevel"])
+            .output()
+            .await
+        {
+            Ok(output) => output,
+            Err(_) => return Ok(None),
+        };
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(Some(Self {
+            root: PathBuf::from(root),
+        }))
+    }
+
+    async fn git(&self, args: &[&str], paths: &[PathBuf]) -> anyhow::Result<String> {
+        // Paths are file names, not patterns: `app/[id]/page.tsx` must not match `app/i/page.tsx`
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&self.root)
+            .arg("--literal-pathspecs")
+            .args(args)
+            .args(paths)
+            .output()
+            .await?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    /// Paths as git sees them: absolute, so they do not depend on the working directory
+    fn absolute(paths: &[PathBuf]) -> anyhow::Result<Vec<PathBuf>> {
+        paths.iter().map(|p| Ok(std::path::absolute(p)?)).collect()
+    }
+}
+
+#[async_trait]
+impl VersionControl for GitRepository {
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    async fn dirty_files(&self, paths: &[PathBuf]) -> anyhow::Result<Vec<PathBuf>> {
+        if paths.is_empty() {
+            return Ok(Vec::new());
+        }
+        let status = self
+            .git(
+                &["status", "--porcelain", "-z", "--untracked-files=all", "--"],
+                &Self::absolute(paths)?,
+            )
+            .await?;
+
+        // Records are "XY path"; a rename is followed by its source path
+        let mut dirty = Vec::new();
+        let mut records = status.split('\0').filter(|r| !r.is_empty());
+        while let Some(record) = records.next() {
+            let (code, path) = record.split_at(3.min(record.len()));
+            dirty.push(self.root.join(path));
+            if code.starts_with('R') || code.starts_with('C') {
+                records.next();
+            }
+        }
+        Ok(dirty)
+    }
+
+    async fn create_branch(&self, name: &str) -> anyhow::Result<()> {
+        self.git(&["checkout", "-b", name], &[]).await?;
+        Ok(())
+    }
+
+    async fn commit(&self, paths: &[PathBuf], message: &str) -> anyhow::Result<()> {
+        let paths = Self::absolute(paths)?;
+
+        // Deleted files can only be staged if git knew them
+        let tracked = self.git(&["ls-files", "-z", "--"], &paths).await?;
+        let tracked: Vec<PathBuf> = tracked
+            .split('\0')
+            .filter(|p| !p.is_empty())
+            .map(|p| self.root.join(p))
+            .collect();
+        let paths: Vec<PathBuf> = paths
+            .into_iter()
+            .filter(|p| p.exists() || tracked.contains(p))
+            .collect();
+        if paths.is_empty() {
+            return Ok(());
+        }
+
+        self.git(&["add", "-A", "--"], &paths).await?;
+        if let Err(e) = self.git(&["commit", "-m", message, "--"], &paths).await {
+            // Leave the index as it was, e.g. when no author identity is set
+            self.git(&["reset", "-q", "--"], &paths).await.ok();
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    }
+
+    #[tokio::test]
+    async fn test_dirty_files_and_commit_only_given_paths() {
+        let dir = std::env::temp_dir().join(format!("langsmith-git-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        run(&dir, &["init", "-q"]);
+        run(&dir, &["config", "user.name", "Test"]);
+        run(&dir, &["config", "user.email", "test@example.com"]);
+        std::fs::write(dir.join("App.jsx"), "a").unwrap();
+        std::fs::write(dir.join("other.js"), "b").unwrap();
+        run(&dir, &["add", "."]);
+        run(&dir, &["commit", "-qm", "init"]);
+
+        let repo = GitRepository::discover(&dir).await.unwrap().unwrap();
+        let app = vec![repo.root.join("App.jsx")];
+        let other = vec![repo.root.join("other.js")];
+        assert!(repo.dirty_files(&app).await.unwrap().is_empty());
+
+        std::fs::write(&app[0], "changed").unwrap();
+        std::fs::write(&other[0], "changed").unwrap();
+        assert_eq!(repo.dirty_files(&app).await.unwrap(), app);
+
+        repo.commit(&app, "Replace strings").await.unwrap();
+        assert!(repo.dirty_files(&app).await.unwrap().is_empty());
+        assert_eq!(repo.dirty_files(&other).await.unwrap(), other);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_commit_stages_only_given_paths_and_unstages_on_failure() {
+        let dir = std::env::temp_dir().join(format!("langsmith-git-stage-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("app/[id]")).unwrap();
+        std::fs::create_dir_all(dir.join("app/i")).unwrap();
+        run(&dir, &["init", "-q"]);
+        run(&dir, &["config", "user.name", "Test"]);
+        run(&dir, &["config", "user.email", "test@example.com"]);
+        std::fs::write(dir.join("app/[id]/page.tsx"), "a").unwrap();
+        std::fs::write(dir.join("app/i/page.tsx"), "b").unwrap();
+        run(&dir, &["add", "."]);
+        run(&dir, &["commit", "-qm", "init"]);
+
+        let repo = GitRepository::discover(&dir).await.unwrap().unwrap();
+        let dynamic = vec![repo.root.join("app/[id]/page.tsx")];
+        let other = vec![repo.root.join("app/i/page.tsx")];
+        std::fs::write(&dynamic[0], "changed").unwrap();
+        std::fs::write(&other[0], "changed").unwrap();
+        let staged = || {
+            let output = std::process::Command::new("git")
+                .arg("-C")
+                .arg(&dir)
+                .args(["diff", "--cached", "--name-only"])
+                .output()
+                .unwrap();
+            String::from_utf8_lossy(&output.stdout).to_string()
+        };
+
+        // A hook refusing the commit: nothing is left staged
+        let hook = dir.join(".git/hooks/pre-commit");
+        std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        assert!(repo.commit(&dynamic, "Replace strings").await.is_err());
+        assert_eq!(staged(), "");
+
+        // `[id]` is a directory name, not a pattern matching `i`
+        std::fs::remove_file(&hook).unwrap();
+        repo.commit(&dynamic, "Replace strings").await.unwrap();
+        assert!(repo.dirty_files(&dynamic).await.unwrap().is_empty());
+        assert_eq!(repo.dirty_files(&other).await.unwrap(), other);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,143 @@
+use crate::domain::models::Glossary;
+use std::path::Path;
+
+/// Loads project glossary files (TOML or JSON)
+pub struct GlossaryLoader;
+
+impl GlossaryLoader {
+    /// Load a glossary, picking the format from the file extension
+    ///
+    /// # Example (TOML)
+    /// ```toml
+    /// source_lang = "en"
+    /// do_not_translate = ["Langsmith Pro"]
+    ///
+    /// [terms.Workspace]
+    /// de = "Arbeitsbereich"
+    /// fr = "Espace de travail"
+    /// ```
+    pub fn load(path: &Path) -> anyhow::Result<Glossary> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read glossary file {}: {}", path.display(), e))?;
+
+        let is_json = path
+            .extension()
+            .map(|ext| ext.eq_ignore_ascii_case("json"))
+            .unwrap_or(false);
+
+        let glossary = if is_json {
+            Self::parse_json(&content)?
+        } else {
+            Self::parse_toml(&content)?
+        };
+
+        tracing::debug!(
+            "Loaded glossary: {} terms, {} do-not-translate",
+            glossary.terms.len(),
+            glossary.do_not_translate.len()
+        );
+
+        Ok(glossary)
+    }
+
+    fn parse_toml(content: &str) -> anyhow::Result<Glossary> {
+        toml::from_str(content).map_err(|e| anyhow::anyhow!("Invalid glossary TOML: {}", e))
+    }
+
+    fn parse_json(content: &str) -> anyhow::Result<Glossary> {
+        serde_json::from_str(content).map_err(|e| anyhow::anyhow!("Invalid glossary JSON: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+source_lang = "en"
+do_not_translate = ["Langsmith Pro"]
+
+[terms.Workspace]
+de = "Arbeitsbereich"
+fr = "Espace de travail"
+"#;
+
+    #[test]
+    fn test_parse_toml() {
+        let glossary = GlossaryLoader::parse_toml(SAMPLE).unwrap();
+        assert_eq!(glossary.source_lang.as_deref(), Some("en"));
+        assert_eq!(glossary.do_not_translate, vec!["Langsmith Pro"]);
+        assert_eq!(glossary.terms["Workspace"]["de"], "Arbeitsbereich");
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let json = r#"{"do_not_translate": ["Langsmith"], "terms": {"Workspace": {"de": "Arbeitsbereich"}}}"#;
+        let glossary = GlossaryLoader::parse_json(json).unwrap();
+        assert!(glossary.source_lang.is_none());
+        assert_eq!(glossary.entries_for("de").len(), 2);
+    }
+
+    #[test]
+    fn test_entries_in_text() {
+        let glossary = GlossaryLoader::parse_toml(SAMPLE).unwrap();
+        let entries = glossary.entries_in("Open your workspace", "de");
+        assert_eq!(
+            entries,
+            vec![("Workspace".to_string(), "Arbeitsbereich".to_string())]
+        );
+        assert!(glossary.entries_in("Open your settings", "de").is_empty());
+    }
+
+    #[test]
+    fn test_entries_regional_fallback() {
+        let glossary = GlossaryLoader::parse_toml(SAMPLE).unwrap();
+        let entries = glossary.entries_for("fr-CA");
+        assert!(entries.contains(&("Workspace".to_string(), "Espace de travail".to_string())));
+    }
+
+    #[test]
+    fn test_check_violations() {
+        let glossary = GlossaryLoader::parse_toml(SAMPLE).unwrap();
+
+        let ok = glossary.check(
+            "upgrade",
+            "Upgrade to Langsmith Pro workspace",
+            "Auf Langsmith Pro Arbeitsbereich upgraden",
+            "de",
+        );
+        assert!(ok.is_empty());
+
+        let violations = glossary.check(
+            "upgrade",
+            "Upgrade to Langsmith Pro workspace",
+            "Auf Langschmied Profi Arbeitsraum upgraden",
+            "de",
+        );
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().any(|v| v.expected == "Langsmith Pro"));
+        assert!(violations.iter().any(|v| v.expected == "Arbeitsbereich"));
+    }
+
+    #[test]
+    fn test_terms_match_whole_words() {
+        let glossary = GlossaryLoader::parse_json(
+            r#"{"do_not_translate": ["Pro", "C++"], "terms": {"Profile": {"de": "Profil"}}}"#,
+        )
+        .unwrap();
+
+        let entries = glossary.entries_in("Edit your profile", "de");
+        assert_eq!(entries, vec![("Profile".to_string(), "Profil".to_string())]);
+        assert_eq!(glossary.entries_in("Upgrade to pro", "de").len(), 1);
+        assert_eq!(glossary.entries_in("Learn C++ today", "de").len(), 1);
+
+        // "Pro" inside "Produkt" does not satisfy the do-not-translate term
+        let violations = glossary.check("upgrade", "Go Pro", "Neues Produkt", "de");
+        assert_eq!(violations.len(), 1);
+        assert!(
+            glossary
+                .check("upgrade", "Go Pro", "Hol dir Pro", "de")
+                .is_empty()
+        );
+    }
+}
@@ -1,11 +1,22 @@
 pub mod code_replacer;
 pub mod config;
 pub mod file_system;
+pub mod git;
+pub mod glossary;
 pub mod string_extractor;
 pub mod translators;
 
-pub use code_replacer::{RegexReplacer, SimpleImportManager};
-pub use config::{ApiProvider, ConfigManager};
+pub use code_replacer::{
+    ComponentHookInjector, JsxSyntaxValidator, SimpleImportManager, SyntaxReplacer,
+};
+pub use config::ConfigManager;
 pub use file_system::{FileSystemScanner, FileSystemWriter};
+pub use git::GitRepository;
+pub use glossary::GlossaryLoader;
 pub use string_extractor::SwcStringExtractor;
-pub use translators::{DeepLTranslator, OpenAITranslator};
+#[allow(unused_imports)]
+pub use translators::{
+    AnthropicTranslator, AzureTranslator, DeepLTranslator, GoogleTranslator,
+    LibreTranslateTranslator, OpenAITranslator, ProviderOverrides, PseudoTranslator,
+    TranslatorFactory,
+};
@@ -1,7 +1,10 @@
+use crate::domain::icu::{Message, Part};
 use crate::domain::models::{FileType, QuoteType, TranslationKey, TranslationKeyWithPosition};
 use crate::domain::ports::StringExtractor;
+use crate::infrastructure::code_replacer::jsx_syntax::{SyntaxContext, SyntaxMap};
 use async_trait::async_trait;
 use regex::Regex;
+use std::ops::Range;
 use std::path::Path;
 
 #[allow(dead_code)]
@@ -12,14 +15,30 @@ impl StringExtractor for SwcStringExtractor {
     async fn extract(
         &self,
         path: &Path,
-        file_type: FileType,
+        _file_type: FileType,
     ) -> anyhow::Result<Vec<TranslationKey>> {
-        let keys_with_pos = self.extract_with_positions(path, file_type).await?;
+        let content = std::fs::read_to_string(path)?;
+        let keys_with_pos = self.find_strings(path, &content);
+        let jsx = path.extension().is_none_or(|ext| ext != "ts");
+        let annotator = ContextAnnotator::new(&content, jsx);
 
-        // Convert from TranslationKeyWithPosition to TranslationKey
+        // Convert from TranslationKeyWithPosition to TranslationKey, adding translator context
         Ok(keys_with_pos
             .into_iter()
-            .map(|k| TranslationKey {
+            .map(|k| {
+                // Text nodes are described from the '>' of their tag, where comments attach
+                let anchor = match k.quote_type {
+                    QuoteType::JsxText => {
+                        content[..k.start_byte].rfind('>').unwrap_or(k.start_byte)
+                    }
+                    _ => k.start_byte,
+                };
+                (anchor, k)
+            })
+            .map(|(anchor, k)| TranslationKey {
+                component: annotator.component(anchor),
+                element: annotator.element(anchor, k.quote_type),
+                comment: annotator.comment(anchor),
                 id: k.id,
                 source: k.source,
                 file_path: k.file_path,
@@ -27,36 +46,90 @@ impl StringExtractor for SwcStringExtractor {
             })
             .collect())
     }
-}
 
-/// Extended extraction with position tracking for string replacement
-#[allow(dead_code)]
-impl SwcStringExtractor {
-    /// Extract strings with byte position tracking
-    pub async fn extract_with_positions(
+    async fn extract_with_positions(
         &self,
         path: &Path,
         _file_type: FileType,
-    ) -> anyhow::Result<Vec<TranslationKeyWithPosition>> {
+    ) -> anyhow::Result<Option<Vec<TranslationKeyWithPosition>>> {
         let content = std::fs::read_to_string(path)?;
+        Ok(Some(self.find_strings(path, &content)))
+    }
+}
+
+/// Extended extraction with position tracking for string replacement
+#[allow(dead_code)]
+impl SwcStringExtractor {
+    /// Find translatable strings in file content
+    fn find_strings(&self, path: &Path, content: &str) -> Vec<TranslationKeyWithPosition> {
         let mut keys = Vec::new();
         let mut seen = std::collections::HashSet::new();
 
         let excluded = self.get_excluded_strings();
 
+        // 0. Match count ternaries: n === 1 ? "1 item" : `${n} items` -> ICU plural
+        // Their string literals are not extracted again by the passes below
+        let mut covered: Vec<Range<usize>> = Vec::new();
+        let literal = r#"("(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|`(?:[^`\\]|\\.)*`)"#;
+        if let Ok(re) = Regex::new(&format!(
+            r"([A-Za-z_$][\w$.]*)\s*(===?|!==?)\s*1\s*\?\s*{}\s*:\s*{}",
+            literal, literal
+        )) {
+            for cap in re.captures_iter(content) {
+                let Some(match_range) = cap.get(0).map(|m| m.range()) else {
+                    continue;
+                };
+                let swapped = cap[2].starts_with('!');
+                let (one, other) = if swapped {
+                    (&cap[4], &cap[3])
+                } else {
+                    (&cap[3], &cap[4])
+                };
+
+                let Some(plural) = PluralTernary::parse(&cap[1], one, other) else {
+                    continue;
+                };
+                covered.push(match_range.clone());
+                if !self.should_extract(&plural.key, &excluded) || seen.contains(&plural.key) {
+                    continue;
+                }
+
+                let line = content[..match_range.start].matches('\n').count() + 1;
+                seen.insert(plural.key.clone());
+                keys.push(TranslationKeyWithPosition {
+                    id: plural.key,
+                    source: plural.message,
+                    file_path: path.to_string_lossy().to_string(),
+                    line,
+                    start_byte: match_range.start,
+                    end_byte: match_range.end,
+                    quote_type: QuoteType::Plural,
+                    params: plural.params,
+                });
+            }
+        }
+        // Directives such as 'use client' are code, not text
+        let jsx = path.extension().is_none_or(|ext| ext != "ts");
+        let map = SyntaxMap::parse(content, jsx);
+        let is_covered = |pos: usize| {
+            covered.iter().any(|r| r.contains(&pos))
+                || map.context_at(pos) == SyntaxContext::Directive
+        };
+
         // 1. Match double-quoted strings: "text"
         if let Ok(re) = Regex::new(r#""([^"\\]|\\.)*""#) {
-            for cap in re.find_iter(&content) {
+            for cap in re.find_iter(content) {
                 let match_range = cap.range();
                 if let Some(text) = cap
                     .as_str()
                     .strip_prefix('"')
                     .and_then(|s| s.strip_suffix('"'))
+                    && !is_covered(match_range.start)
                     && self.should_extract(text, &excluded)
                 {
                     let key = format_key(text);
                     if !seen.contains(&key) {
-                        let line = content[..match_range.start].lines().count();
+                        let line = content[..match_range.start].matches('\n').count() + 1;
 
                         keys.push(TranslationKeyWithPosition {
                             id: key.clone(),
@@ -66,6 +139,7 @@ impl SwcStringExtractor {
                             start_byte: match_range.start,
                             end_byte: match_range.end,
                             quote_type: QuoteType::Double,
+                            params: Vec::new(),
                         });
                         seen.insert(key);
                     }
@@ -75,17 +149,18 @@ impl SwcStringExtractor {
 
         // 2. Match single-quoted strings: 'text'
         if let Ok(re) = Regex::new(r"'([^'\\]|\\.)*'") {
-            for cap in re.find_iter(&content) {
+            for cap in re.find_iter(content) {
                 let match_range = cap.range();
                 if let Some(text) = cap
                     .as_str()
                     .strip_prefix('\'')
                     .and_then(|s| s.strip_suffix('\''))
+                    && !is_covered(match_range.start)
                     && self.should_extract(text, &excluded)
                 {
                     let key = format_key(text);
                     if !seen.contains(&key) {
-                        let line = content[..match_range.start].lines().count();
+                        let line = content[..match_range.start].matches('\n').count() + 1;
 
                         keys.push(TranslationKeyWithPosition {
                             id: key.clone(),
@@ -95,6 +170,7 @@ impl SwcStringExtractor {
                             start_byte: match_range.start,
                             end_byte: match_range.end,
                             quote_type: QuoteType::Single,
+                            params: Vec::new(),
                         });
                         seen.insert(key);
                     }
@@ -104,18 +180,19 @@ impl SwcStringExtractor {
 
         // 3. Match template literals: `text` (with proper escaping)
         if let Ok(re) = Regex::new(r"`([^\`\\]|\\.)*`") {
-            for cap in re.find_iter(&content) {
+            for cap in re.find_iter(content) {
                 let match_range = cap.range();
                 if let Some(text) = cap
                     .as_str()
                     .strip_prefix('`')
                     .and_then(|s| s.strip_suffix('`'))
                     && !text.contains("${")
+                    && !is_covered(match_range.start)
                     && self.should_extract(text, &excluded)
                 {
                     let key = format_key(text);
                     if !seen.contains(&key) {
-                        let line = content[..match_range.start].lines().count();
+                        let line = content[..match_range.start].matches('\n').count() + 1;
 
                         keys.push(TranslationKeyWithPosition {
                             id: key.clone(),
@@ -125,6 +202,7 @@ impl SwcStringExtractor {
                             start_byte: match_range.start,
                             end_byte: match_range.end,
                             quote_type: QuoteType::Template,
+                            params: Vec::new(),
                         });
                         seen.insert(key);
                     }
@@ -134,14 +212,14 @@ impl SwcStringExtractor {
 
         // 4. Match JSX text nodes: <div>Text here</div>
         if let Ok(re) = Regex::new(r">[^<]*</") {
-            for cap in re.find_iter(&content) {
+            for cap in re.find_iter(content) {
                 let match_str = cap.as_str();
                 // Extract text between > and </
-                if let Some(text) = match_str
+                if let Some(raw) = match_str
                     .strip_prefix('>')
                     .and_then(|s| s.strip_suffix("</"))
                 {
-                    let text = text.trim();
+                    let text = raw.trim();
 
                     // Skip empty strings and JSX expressions
                     if !text.is_empty()
@@ -151,17 +229,19 @@ impl SwcStringExtractor {
                     {
                         let key = format_key(text);
                         if !seen.contains(&key) {
-                            let match_range = cap.range();
-                            let line = content[..match_range.start].lines().count();
+                            // Only the text itself, so it can be replaced in place
+                            let start = cap.start() + 1 + raw.len() - raw.trim_start().len();
+                            let line = content[..start].matches('\n').count() + 1;
 
                             keys.push(TranslationKeyWithPosition {
                                 id: key.clone(),
                                 source: text.to_string(),
                                 file_path: path.to_string_lossy().to_string(),
                                 line,
-                                start_byte: match_range.start,
-                                end_byte: match_range.end,
+                                start_byte: start,
+                                end_byte: start + text.len(),
                                 quote_type: QuoteType::JsxText,
+                                params: Vec::new(),
                             });
                             seen.insert(key);
                         }
@@ -172,7 +252,7 @@ impl SwcStringExtractor {
 
         // 5. Match HTML attributes: placeholder="text", alt="text", title="text", aria-label="text"
         if let Ok(re) = Regex::new(r#"(placeholder|alt|title|aria-label)="([^"]*)""#) {
-            for cap in re.captures_iter(&content) {
+            for cap in re.captures_iter(content) {
                 if let Some(attr_value) = cap.get(2) {
                     let text = attr_value.as_str();
 
@@ -185,7 +265,7 @@ impl SwcStringExtractor {
                         let key = format_key(text);
                         if !seen.contains(&key) {
                             let match_range = attr_value.range();
-                            let line = content[..match_range.start].lines().count();
+                            let line = content[..match_range.start].matches('\n').count() + 1;
 
                             keys.push(TranslationKeyWithPosition {
                                 id: key.clone(),
@@ -195,6 +275,7 @@ impl SwcStringExtractor {
                                 start_byte: match_range.start,
                                 end_byte: match_range.end,
                                 quote_type: QuoteType::Double,
+                                params: Vec::new(),
                             });
                             seen.insert(key);
                         }
@@ -203,7 +284,7 @@ impl SwcStringExtractor {
             }
         }
 
-        Ok(keys)
+        keys
     }
 
     /// Check if a string should be extracted
@@ -323,6 +404,177 @@ impl SwcStringExtractor {
     }
 }
 
+/// Finds the context around an extracted string: component, JSX element and `// i18n:` notes
+struct ContextAnnotator<'a> {
+    content: &'a str,
+    map: SyntaxMap,
+    comment_re: Regex,
+}
+
+impl<'a> ContextAnnotator<'a> {
+    fn new(content: &'a str, jsx: bool) -> Self {
+        Self {
+            content,
+            map: SyntaxMap::parse(content, jsx),
+            // "// i18n: note" or "{/* i18n: note */}"
+            comment_re: Regex::new(r"(?://|/\*)\s*i18n:\s*(.*?)\s*(?:\*/\s*\}?\s*)?$")
+                .expect("valid comment regex"),
+        }
+    }
+
+    /// Name of the innermost component, a named function returning JSX, or hook around
+    /// the string; none for module-level strings such as constant objects
+    fn component(&self, pos: usize) -> Option<String> {
+        self.map
+            .functions_at(pos)
+            .filter(|function| function.returns_jsx || function.is_hook())
+            .find_map(|function| function.name.clone())
+    }
+
+    /// Tag of the JSX element holding the string as text or attribute
+    fn element(&self, pos: usize, quote_type: QuoteType) -> Option<String> {
+        let before = &self.content[..pos];
+        let open = before.rfind('<')?;
+
+        // Text nodes start at the '>' closing their tag; other strings must sit inside a tag
+        if quote_type != QuoteType::JsxText && before.rfind('>').is_some_and(|close| close > open) {
+            return None;
+        }
+
+        let tag: String = self.content[open + 1..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '.' || *c == '-')
+            .collect();
+        tag.starts_with(|c: char| c.is_ascii_alphabetic())
+            .then_some(tag)
+    }
+
+    /// `i18n:` comments on the string's line or in the comment block right above it
+    fn comment(&self, pos: usize) -> Option<String> {
+        let lines: Vec<&str> = self.content.lines().collect();
+        let line = self.content[..pos].matches('\n').count();
+
+        let mut notes = Vec::new();
+        for above in lines[..line.min(lines.len())].iter().rev() {
+            let trimmed = above.trim_start();
+            if !["//", "/*", "{/*", "*"]
+                .iter()
+                .any(|p| trimmed.starts_with(p))
+            {
+                break;
+            }
+            notes.extend(self.note(trimmed));
+        }
+        notes.reverse();
+        notes.extend(lines.get(line).and_then(|l| self.note(l)));
+
+        (!notes.is_empty()).then(|| notes.join(" "))
+    }
+
+    fn note(&self, line: &str) -> Option<String> {
+        self.comment_re
+            .captures(line)
+            .map(|cap| cap[1].trim().to_string())
+            .filter(|note| !note.is_empty())
+    }
+}
+
+/// A `n === 1 ? one : other` ternary turned into an ICU plural
+struct PluralTernary {
+    /// From the `other` text, e.g. "itemscount_items"
+    key: String,
+    /// e.g. "{itemsCount, plural, one {# item} other {# items}}"
+    message: String,
+    /// (argument, expression) pairs, the counted value first
+    params: Vec<(String, String)>,
+}
+
+impl PluralTernary {
+    fn parse(condition: &str, one: &str, other: &str) -> Option<Self> {
+        let mut segments = condition.rsplit('.');
+        let name = match (segments.next()?, segments.next()) {
+            ("length" | "size", Some(collection)) => format!("{}Count", collection),
+            (last, _) => last.to_string(),
+        };
+        let mut params = vec![(name.clone(), condition.to_string())];
+
+        let mut branch = |literal: &str, is_one: bool| -> Option<Message> {
+            let template = literal.starts_with('`');
+            let mut parts = Vec::new();
+            let mut rest = &literal[1..literal.len() - 1];
+
+            // Template placeholders: the counted value becomes '#', others become arguments
+            while let Some(start) = rest.find("${").filter(|_| template) {
+                let end = start + rest[start..].find('}')?;
+                parts.extend(count_text(&rest[..start], is_one));
+                let expr = rest[start + 2..end].trim();
+                if expr == condition {
+                    parts.push(Part::Pound);
+                } else {
+                    let arg = expr.rsplit('.').next()?;
+                    if !arg.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                        return None;
+                    }
+                    if !params.iter().any(|(name, _)| name == arg) {
+                        params.push((arg.to_string(), expr.to_string()));
+                    }
+                    parts.push(Part::Argument {
+                        name: arg.to_string(),
+                        format: None,
+                    });
+                }
+                rest = &rest[end + 1..];
+            }
+            parts.extend(count_text(rest, is_one));
+            Some(Message { parts })
+        };
+        let (one, other) = (branch(one, true)?, branch(other, false)?);
+
+        let key_text: String = other
+            .parts
+            .iter()
+            .map(|part| match part {
+                Part::Text(text) => text.clone(),
+                Part::Argument { name, .. } => name.clone(),
+                _ => name.clone(),
+            })
+            .collect();
+        let message = Message {
+            parts: vec![Part::Plural {
+                name,
+                ordinal: false,
+                offset: 0,
+                options: vec![("one".to_string(), one), ("other".to_string(), other)],
+            }],
+        };
+
+        Some(Self {
+            key: format_key(&key_text),
+            message: message.to_string(),
+            params,
+        })
+    }
+}
+
+/// Literal text of a ternary branch; a standalone "1" in the singular branch becomes '#'
+fn count_text(text: &str, is_one: bool) -> Vec<Part> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+    let one = Regex::new(r"\b1\b").expect("valid number regex");
+    match one.find(text).filter(|_| is_one) {
+        Some(m) => [
+            Part::Text(text[..m.start()].to_string()),
+            Part::Pound,
+            Part::Text(text[m.end()..].to_string()),
+        ]
+        .into_iter()
+        .filter(|p| *p != Part::Text(String::new()))
+        .collect(),
+        None => vec![Part::Text(text.to_string())],
+    }
+}
+
 /// Convert a string to a translation key
 /// "Hello World" -> "hello_world"
 /// "user-profile" -> "user_profile"
@@ -409,6 +661,166 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod context_tests {
+    use super::*;
+
+    const SOURCE: &str = r#"import React from 'react';
+
+export function SettingsForm() {
+  const title = "Account settings";
+  return (
+    <form>
+      {/* i18n: Label of the button that saves the form */}
+      <button className="primary">Save changes</button>
+      <input placeholder="Your display name" /> // i18n: Shown until the user types
+    </form>
+  );
+}
+
+const Footer = () => <p>All rights reserved</p>;
+"#;
+
+    async fn extract(source: &str) -> Vec<TranslationKey> {
+        let dir = std::env::temp_dir().join(format!("langsmith-context-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Settings.jsx");
+        std::fs::write(&path, source).unwrap();
+        let keys = SwcStringExtractor
+            .extract(&path, FileType::JSX)
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        keys
+    }
+
+    fn find<'a>(keys: &'a [TranslationKey], id: &str) -> &'a TranslationKey {
+        keys.iter().find(|k| k.id == id).expect(id)
+    }
+
+    #[tokio::test]
+    async fn test_extract_records_component_and_element() {
+        let keys = extract(SOURCE).await;
+
+        let title = find(&keys, "account_settings");
+        assert_eq!(title.component.as_deref(), Some("SettingsForm"));
+        assert_eq!(title.element, None);
+
+        let save = find(&keys, "save_changes");
+        assert_eq!(save.component.as_deref(), Some("SettingsForm"));
+        assert_eq!(save.element.as_deref(), Some("button"));
+
+        let placeholder = find(&keys, "your_display_name");
+        assert_eq!(placeholder.element.as_deref(), Some("input"));
+
+        let footer = find(&keys, "all_rights_reserved");
+        assert_eq!(footer.component.as_deref(), Some("Footer"));
+        assert_eq!(footer.element.as_deref(), Some("p"));
+    }
+
+    #[tokio::test]
+    async fn test_module_strings_have_no_component() {
+        let keys = extract(
+            r#"export function Banner() {
+  const format = (name) => `Hi ${name}`;
+  return <p title="Welcome banner">{format("Ada")}</p>;
+}
+
+export const MESSAGES = {
+  error: "Something went wrong",
+};
+
+const ERROR_TITLE = "Request failed";
+
+function useStatus() {
+  return "Loading status";
+}
+"#,
+        )
+        .await;
+
+        assert_eq!(
+            find(&keys, "welcome_banner").component.as_deref(),
+            Some("Banner")
+        );
+        assert_eq!(find(&keys, "something_went_wrong").component, None);
+        assert_eq!(find(&keys, "request_failed").component, None);
+        assert_eq!(
+            find(&keys, "loading_status").component.as_deref(),
+            Some("useStatus")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_directives_are_not_extracted() {
+        let keys = extract(
+            "'use client';\n\nexport function Save() {\n  return <button title='use client'>Save it</button>;\n}\nasync function save() {\n  \"use server\";\n}\n",
+        )
+        .await;
+        let ids: Vec<&str> = keys.iter().map(|k| k.id.as_str()).collect();
+        // Only the attribute, which is not a directive, and the button text
+        assert_eq!(ids, vec!["use_client", "save_it"]);
+        assert_eq!(keys[0].line, 4);
+    }
+
+    #[tokio::test]
+    async fn test_extract_reads_i18n_comments() {
+        let keys = extract(SOURCE).await;
+
+        assert_eq!(
+            find(&keys, "save_changes").comment.as_deref(),
+            Some("Label of the button that saves the form")
+        );
+        assert_eq!(
+            find(&keys, "your_display_name").comment.as_deref(),
+            Some("Shown until the user types")
+        );
+        assert_eq!(find(&keys, "account_settings").comment, None);
+    }
+}
+
+#[cfg(test)]
+mod plural_tests {
+    use super::*;
+
+    #[test]
+    fn test_count_ternaries_become_plural_messages() {
+        let content = r#"
+const label = count === 1 ? "1 file selected" : `${count} files selected`;
+const owner = items.length !== 1 ? `${user.name} has ${items.length} items` : `${user.name} has one item`;
+"#;
+        let keys = SwcStringExtractor.find_strings(Path::new("List.jsx"), content);
+
+        let sources: Vec<(&str, &str, QuoteType)> = keys
+            .iter()
+            .map(|k| (k.id.as_str(), k.source.as_str(), k.quote_type))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                (
+                    "count_files_selected",
+                    "{count, plural, one {# file selected} other {# files selected}}",
+                    QuoteType::Plural
+                ),
+                (
+                    "name_has_itemscount_items",
+                    "{itemsCount, plural, one {{name} has one item} other {{name} has # items}}",
+                    QuoteType::Plural
+                ),
+            ]
+        );
+        assert!(Message::parse(&keys[1].source).is_ok());
+        assert_eq!(
+            keys[1].params,
+            vec![
+                ("itemsCount".to_string(), "items.length".to_string()),
+                ("name".to_string(), "user.name".to_string()),
+            ]
+        );
+    }
+}
+
 #[cfg(test)]
 mod integration_tests {
     use super::*;
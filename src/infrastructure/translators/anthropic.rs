@@ -0,0 +1,224 @@
+use super::prompt;
+use crate::domain::locale::Locale;
+use crate::domain::models::{CostModel, Glossary, TranslationRequest};
+use crate::domain::ports::Translator;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+const ANTHROPIC_API_URL: &str = "https://api.anthropic.com";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const DEFAULT_MODEL: &str = "claude-3-5-haiku-latest";
+
+/// USD per million tokens (input, output) by model prefix
+const MODEL_PRICING: &[(&str, f64, f64)] = &[
+    ("claude-3-5-haiku", 0.80, 4.00),
+    ("claude-3-haiku", 0.25, 1.25),
+    ("claude-haiku-4", 1.00, 5.00),
+    ("claude-3-5-sonnet", 3.00, 15.00),
+    ("claude-3-7-sonnet", 3.00, 15.00),
+    ("claude-sonnet-4", 3.00, 15.00),
+    ("claude-opus-4", 15.00, 75.00),
+];
+
+/// Anthropic Messages API implementation
+pub struct AnthropicTranslator {
+    api_key: String,
+    base_url: String,
+    model: String,
+    glossary: Option<Glossary>,
+    client: reqwest::Client,
+}
+
+impl AnthropicTranslator {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            base_url: ANTHROPIC_API_URL.to_string(),
+            model: DEFAULT_MODEL.to_string(),
+            glossary: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_model(mut self, model: String) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Inject project glossary terms into the system prompt
+    pub fn with_glossary(mut self, glossary: Glossary) -> Self {
+        if !glossary.is_empty() {
+            self.glossary = Some(glossary);
+        }
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct AnthropicMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct AnthropicRequest {
+    model: String,
+    max_tokens: u32,
+    system: String,
+    messages: Vec<AnthropicMessage>,
+    temperature: f32,
+}
+
+#[derive(Deserialize)]
+struct AnthropicContent {
+    #[serde(rename = "type")]
+    content_type: String,
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContent>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicErrorBody {
+    #[serde(rename = "type")]
+    error_type: String,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct AnthropicError {
+    error: AnthropicErrorBody,
+}
+
+#[async_trait]
+impl Translator for AnthropicTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let request = AnthropicRequest {
+            model: self.model.clone(),
+            max_tokens: 1024,
+            system: prompt::system_prompt(self.glossary.as_ref(), text, req),
+            messages: vec![AnthropicMessage {
+                role: "user".to_string(),
+                content: text.to_string(),
+            }],
+            temperature: 0.3,
+        };
+
+        let response = self
+            .client
+            .post(format!("{}/v1/messages", self.base_url))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            // Anthropic reports errors as {"type": "error", "error": {"type", "message"}}
+            let message = serde_json::from_str::<AnthropicError>(&error_text)
+                .map(|e| format!("{}: {}", e.error.error_type, e.error.message))
+                .unwrap_or(error_text);
+            return Err(anyhow::anyhow!(
+                "Anthropic API error ({}): {}",
+                status,
+                message
+            ));
+        }
+
+        let data: AnthropicResponse = response.json().await?;
+
+        let translated: String = data
+            .content
+            .iter()
+            .filter(|c| c.content_type == "text")
+            .map(|c| c.text.as_str())
+            .collect();
+
+        if translated.trim().is_empty() {
+            return Err(anyhow::anyhow!("Anthropic returned no text content"));
+        }
+
+        Ok(translated.trim().to_string())
+    }
+
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        let pricing = MODEL_PRICING
+            .iter()
+            .find(|(prefix, _, _)| self.model.starts_with(prefix))
+            .map(|&(_, input, output)| (input, output));
+        Some(CostModel::tokens("anthropic", pricing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_translate_against_mock_server() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/messages")
+            .match_header("x-api-key", "key")
+            .match_header("anthropic-version", ANTHROPIC_VERSION)
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "model": "claude-test",
+                "messages": [{"role": "user", "content": "Save"}],
+            })))
+            .with_status(200)
+            .with_body(r#"{"content": [{"type": "text", "text": " Speichern\n"}]}"#)
+            .create_async()
+            .await;
+
+        let translator = AnthropicTranslator::new("key".to_string())
+            .with_base_url(server.url())
+            .with_model("claude-test".to_string());
+        let result = translator
+            .translate(
+                "Save",
+                &TranslationRequest::parse(Some("en"), "de").unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, "Speichern");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_mapping() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v1/messages")
+            .with_status(401)
+            .with_body(
+                r#"{"type": "error", "error": {"type": "authentication_error", "message": "invalid x-api-key"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let translator = AnthropicTranslator::new("bad".to_string()).with_base_url(server.url());
+        let err = translator
+            .translate(
+                "Save",
+                &TranslationRequest::parse(Some("en"), "de").unwrap(),
+            )
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("401"));
+        assert!(message.contains("authentication_error: invalid x-api-key"));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::domain::locale::Locale;
+use crate::domain::models::{CostModel, TranslationRequest};
+use crate::domain::ports::Translator;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+const AZURE_API_URL: &str = "https://api.cognitive.microsofttranslator.com";
+
+/// USD per million characters (Translator S1, pay as you go)
+const PRICE_PER_MILLION_CHARS: f64 = 10.0;
+
+/// Azure AI Translator (v3) implementation
+pub struct AzureTranslator {
+    api_key: String,
+    /// Resource region, required for regional and multi-service resources
+    region: Option<String>,
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl AzureTranslator {
+    pub fn new(api_key: String, region: Option<String>) -> Self {
+        Self {
+            api_key,
+            region: region.filter(|r| !r.is_empty()),
+            base_url: AZURE_API_URL.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Map a locale to an Azure language code
+    /// e.g., "DE" -> "de", "zh" -> "zh-Hans", "pt-BR" -> "pt", "fr-CA" -> "fr-ca"
+    fn language_code(locale: &Locale) -> String {
+        let region = locale.region.as_deref();
+        match locale.language.as_str() {
+            "zh" if locale.script_or_default() == Some("Hant") => "zh-Hant".to_string(),
+            "zh" => "zh-Hans".to_string(),
+            "pt" if region == Some("PT") => "pt-pt".to_string(),
+            "fr" if region == Some("CA") => "fr-ca".to_string(),
+            "sr" if locale.script.as_deref() == Some("Cyrl") => "sr-Cyrl".to_string(),
+            "sr" => "sr-Latn".to_string(),
+            language => language.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AzureRequestItem {
+    #[serde(rename = "Text")]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct AzureTranslation {
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct AzureResponseItem {
+    translations: Vec<AzureTranslation>,
+}
+
+#[derive(Deserialize)]
+struct AzureErrorBody {
+    code: u64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct AzureError {
+    error: AzureErrorBody,
+}
+
+#[async_trait]
+impl Translator for AzureTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let normalized_lang = Self::language_code(&req.target);
+        let body = vec![AzureRequestItem {
+            text: text.to_string(),
+        }];
+
+        tracing::debug!("Translating '{}' to {} via Azure", text, normalized_lang);
+
+        let mut request = self
+            .client
+            .post(format!("{}/translate", self.base_url))
+            .query(&[("api-version", "3.0"), ("to", normalized_lang.as_str())])
+            .header("Ocp-Apim-Subscription-Key", &self.api_key)
+            .json(&body);
+
+        // Without "from" Azure detects the language of each text on its own
+        if let Some(source) = &req.source {
+            request = request.query(&[("from", Self::language_code(source))]);
+        }
+
+        if let Some(region) = &self.region {
+            request = request.header("Ocp-Apim-Subscription-Region", region);
+        }
+
+        let response = request.send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            // Azure reports errors as {"error": {"code": 400036, "message": "..."}}
+            let message = serde_json::from_str::<AzureError>(&error_text)
+                .map(|e| format!("{} {}", e.error.code, e.error.message))
+                .unwrap_or(error_text);
+            return Err(anyhow::anyhow!(
+                "Azure Translator API error ({}): {}",
+                status,
+                message
+            ));
+        }
+
+        let data: Vec<AzureResponseItem> = response.json().await?;
+
+        data.into_iter()
+            .next()
+            .and_then(|item| item.translations.into_iter().next())
+            .map(|t| t.text)
+            .ok_or_else(|| anyhow::anyhow!("Azure Translator returned empty translations"))
+    }
+
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        Some(CostModel::characters("azure", PRICE_PER_MILLION_CHARS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_code() {
+        let code = |tag: &str| AzureTranslator::language_code(&Locale::parse(tag).unwrap());
+        assert_eq!(code("DE"), "de");
+        assert_eq!(code("zh"), "zh-Hans");
+        assert_eq!(code("zh-TW"), "zh-Hant");
+        assert_eq!(code("pt-BR"), "pt");
+        assert_eq!(code("pt-PT"), "pt-pt");
+        assert_eq!(code("fr_CA"), "fr-ca");
+        assert_eq!(code("sr-Cyrl"), "sr-Cyrl");
+    }
+
+    #[tokio::test]
+    async fn test_translate_against_mock_server() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/translate")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("api-version".into(), "3.0".into()),
+                mockito::Matcher::UrlEncoded("to".into(), "zh-Hans".into()),
+                mockito::Matcher::UrlEncoded("from".into(), "en".into()),
+            ]))
+            .match_header("ocp-apim-subscription-key", "key")
+            .match_header("ocp-apim-subscription-region", "westeurope")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!([{"Text": "Save"}]),
+            ))
+            .with_status(200)
+            .with_body(r#"[{"translations": [{"text": "保存", "to": "zh-Hans"}]}]"#)
+            .create_async()
+            .await;
+
+        let translator = AzureTranslator::new("key".to_string(), Some("westeurope".to_string()))
+            .with_base_url(server.url());
+        let result = translator
+            .translate(
+                "Save",
+                &TranslationRequest::parse(Some("en"), "zh").unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, "保存");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_mapping() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", mockito::Matcher::Any)
+            .with_status(400)
+            .with_body(
+                r#"{"error": {"code": 400036, "message": "The target language is not valid."}}"#,
+            )
+            .create_async()
+            .await;
+
+        let translator = AzureTranslator::new("key".to_string(), None).with_base_url(server.url());
+        let err = translator
+            .translate("Save", &TranslationRequest::parse(None, "xx").unwrap())
+            .await
+            .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("400036 The target language is not valid.")
+        );
+    }
+}
@@ -1,11 +1,33 @@
+use crate::domain::journal::content_hash;
+use crate::domain::locale::Locale;
+use crate::domain::models::{
+    CostModel, Formality, Glossary, Quota, TranslationRequest, TranslationUnit,
+};
 use crate::domain::ports::Translator;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+
+const DEEPL_API_URL: &str = "https://api-free.deepl.com/v2";
+
+/// USD per million characters (DeepL API Pro)
+const PRICE_PER_MILLION_CHARS: f64 = 25.0;
+
+/// Target languages accepted by DeepL (primary subtags)
+const SUPPORTED_TARGET_LANGS: &[&str] = &[
+    "ar", "bg", "cs", "da", "de", "el", "en", "es", "et", "fi", "fr", "hu", "id", "it", "ja", "ko",
+    "lt", "lv", "nb", "nl", "pl", "pt", "ro", "ru", "sk", "sl", "sv", "tr", "uk", "zh",
+];
 
 /// DeepL translation API implementation
 pub struct DeepLTranslator {
     api_key: String,
     client: reqwest::Client,
+    base_url: String,
+    glossary: Option<Glossary>,
+    /// DeepL glossary ids, created lazily per target language
+    glossary_ids: Mutex<HashMap<String, Option<String>>>,
 }
 
 impl DeepLTranslator {
@@ -13,26 +35,189 @@ impl DeepLTranslator {
         Self {
             api_key,
             client: reqwest::Client::new(),
+            base_url: DEEPL_API_URL.to_string(),
+            glossary: None,
+            glossary_ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Use another API endpoint, e.g. "https://api.deepl.com/v2" for DeepL API Pro
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Enforce a project glossary through DeepL's glossary API
+    pub fn with_glossary(mut self, glossary: Glossary) -> Self {
+        if !glossary.is_empty() {
+            self.glossary = Some(glossary);
+        }
+        self
+    }
+
+    /// Map a locale to a DeepL target language, None when DeepL does not offer it
+    /// e.g., "en" -> "EN-US", "en-GB" -> "EN-GB", "pt-BR" -> "PT-BR", "zh-TW" -> "ZH-HANT"
+    fn target_code(locale: &Locale) -> Option<String> {
+        let region = locale.region.as_deref();
+        let code = match locale.language.as_str() {
+            "en" if matches!(region, Some("GB" | "AU" | "NZ" | "IE" | "IN" | "ZA")) => "EN-GB",
+            "en" => "EN-US",
+            "pt" if region == Some("BR") => "PT-BR",
+            "pt" => "PT-PT",
+            "zh" if locale.script_or_default() == Some("Hant") => "ZH-HANT",
+            "zh" => "ZH-HANS",
+            language if SUPPORTED_TARGET_LANGS.contains(&language) => {
+                return Some(language.to_uppercase());
+            }
+            _ => return None,
+        };
+        Some(code.to_string())
+    }
+
+    /// Map a locale to a DeepL source language; source languages have no variants
+    /// e.g., "en-GB" -> "EN", "pt-BR" -> "PT"
+    fn source_code(locale: &Locale) -> Option<String> {
+        SUPPORTED_TARGET_LANGS
+            .contains(&locale.language.as_str())
+            .then(|| locale.language.to_uppercase())
+    }
+
+    /// Return the DeepL glossary id for a language pair, reusing or creating it on first use
+    ///
+    /// The glossary's own source_lang is used when the run has none.
+    /// Failures are logged and cached as "no glossary" so translation keeps working;
+    /// the post-translation glossary check still reports violations.
+    async fn glossary_id(&self, req: &TranslationRequest) -> Option<(String, String)> {
+        let glossary = self.glossary.as_ref()?;
+        let source = match &req.source {
+            Some(source) => source.language.clone(),
+            None => {
+                Locale::parse(glossary.source_lang.as_deref()?)
+                    .ok()?
+                    .language
+            }
+        };
+        let target_lang = req.target.tag();
+        let cache_key = format!("{}:{}", source, target_lang);
+
+        let mut ids = self.glossary_ids.lock().await;
+        if let Some(cached) = ids.get(&cache_key) {
+            return cached.clone().map(|id| (id, source));
+        }
+
+        let entries = glossary.entries_for(&target_lang);
+        let created = if entries.is_empty() {
+            None
+        } else {
+            match self.ensure_glossary(&source, &req.target, &entries).await {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    tracing::warn!("Could not set up DeepL glossary for {}: {}", target_lang, e);
+                    None
+                }
+            }
+        };
+
+        ids.insert(cache_key, created.clone());
+        created.map(|id| (id, source))
+    }
+
+    /// Reuse the glossary created by an earlier run with the same entries, else create it
+    ///
+    /// The name embeds a hash of the entries, so an edited glossary gets a new DeepL glossary.
+    async fn ensure_glossary(
+        &self,
+        source_lang: &str,
+        target: &Locale,
+        entries: &[(String, String)],
+    ) -> anyhow::Result<String> {
+        // DeepL expects tab-separated entries without tabs/newlines in terms
+        let tsv = entries
+            .iter()
+            .map(|(term, expected)| {
+                format!(
+                    "{}\t{}",
+                    term.replace(['\t', '\n'], " "),
+                    expected.replace(['\t', '\n'], " ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Glossaries are defined per language, not per regional variant
+        let name = format!(
+            "langsmith-{}-{}-{}",
+            source_lang,
+            target.language,
+            &content_hash(tsv.as_bytes())[..12]
+        );
+
+        if let Some(id) = self.find_glossary(&name).await? {
+            tracing::debug!("Reusing DeepL glossary {} for {}", id, target);
+            return Ok(id);
+        }
+
+        let request = DeepLGlossaryRequest {
+            name,
+            source_lang: source_lang.to_string(),
+            target_lang: target.language.clone(),
+            entries: tsv,
+            entries_format: "tsv".to_string(),
+        };
+
+        let response = self
+            .client
+            .post(format!("{}/glossaries", self.base_url))
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!(
+                "DeepL glossary error ({}): {}",
+                status,
+                error_text
+            ));
         }
+
+        let data: DeepLGlossaryResponse = response.json().await?;
+        tracing::debug!(
+            "Created DeepL glossary {} ({} entries) for {}",
+            data.glossary_id,
+            entries.len(),
+            target
+        );
+        Ok(data.glossary_id)
     }
 
-    /// Convert language code to DeepL target language
-    /// e.g., "en" -> "EN", "fr" -> "FR"
-    fn normalize_target_lang(&self, lang: &str) -> String {
-        match lang.to_lowercase().as_str() {
-            "en" => "EN-US".to_string(),
-            "fr" => "FR".to_string(),
-            "es" => "ES".to_string(),
-            "de" => "DE".to_string(),
-            "it" => "IT".to_string(),
-            "pt" => "PT-PT".to_string(),
-            "nl" => "NL".to_string(),
-            "pl" => "PL".to_string(),
-            "ru" => "RU".to_string(),
-            "ja" => "JA".to_string(),
-            "zh" => "ZH".to_string(),
-            other => other.to_uppercase(),
+    /// Id of the account's glossary with this name, if any
+    async fn find_glossary(&self, name: &str) -> anyhow::Result<Option<String>> {
+        let response = self
+            .client
+            .get(format!("{}/glossaries", self.base_url))
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!(
+                "DeepL glossary error ({}): {}",
+                status,
+                error_text
+            ));
         }
+
+        let data: DeepLGlossaryList = response.json().await?;
+        Ok(data
+            .glossaries
+            .into_iter()
+            .find(|glossary| glossary.name == name)
+            .map(|glossary| glossary.glossary_id))
     }
 }
 
@@ -40,6 +225,15 @@ impl DeepLTranslator {
 struct DeepLRequest {
     text: Vec<String>,
     target_lang: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_lang: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    glossary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    formality: Option<String>,
+    /// Free text that influences the translation but is not translated itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -52,14 +246,68 @@ struct DeepLResponse {
     translations: Vec<DeepLTranslation>,
 }
 
-#[async_trait]
-impl Translator for DeepLTranslator {
-    async fn translate(&self, text: &str, target_lang: &str) -> anyhow::Result<String> {
-        let normalized_lang = self.normalize_target_lang(target_lang);
+#[derive(Deserialize)]
+struct DeepLUsageResponse {
+    character_count: u64,
+    character_limit: u64,
+}
+
+#[derive(Serialize)]
+struct DeepLGlossaryRequest {
+    name: String,
+    source_lang: String,
+    target_lang: String,
+    entries: String,
+    entries_format: String,
+}
+
+#[derive(Deserialize)]
+struct DeepLGlossaryResponse {
+    glossary_id: String,
+}
+
+#[derive(Deserialize)]
+struct DeepLGlossaryInfo {
+    glossary_id: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct DeepLGlossaryList {
+    glossaries: Vec<DeepLGlossaryInfo>,
+}
+
+impl DeepLTranslator {
+    async fn translate_with_context(
+        &self,
+        text: &str,
+        context: Option<String>,
+        req: &TranslationRequest,
+    ) -> anyhow::Result<String> {
+        let normalized_lang = Self::target_code(&req.target)
+            .ok_or_else(|| anyhow::anyhow!("DeepL does not support {}", req.target))?;
+
+        // DeepL source languages have no regional variant ("EN", not "EN-US");
+        // a glossary requires an explicit source language
+        let (glossary_id, source_lang) = match self.glossary_id(req).await {
+            Some((id, source)) => (Some(id), Some(source.to_uppercase())),
+            None => (None, req.source.as_ref().and_then(Self::source_code)),
+        };
 
         let request = DeepLRequest {
             text: vec![text.to_string()],
             target_lang: normalized_lang.clone(),
+            source_lang,
+            glossary_id,
+            // "prefer_*" falls back to the default for languages without formality support
+            formality: req.style.formality.map(|formality| {
+                match formality {
+                    Formality::Formal => "prefer_more",
+                    Formality::Informal => "prefer_less",
+                }
+                .to_string()
+            }),
+            context,
         };
 
         // Debug: log key info (first and last chars only)
@@ -77,7 +325,7 @@ impl Translator for DeepLTranslator {
 
         let response = self
             .client
-            .post("https://api-free.deepl.com/v2/translate")
+            .post(format!("{}/translate", self.base_url))
             .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
             .json(&request)
             .send()
@@ -105,3 +353,230 @@ impl Translator for DeepLTranslator {
         Ok(data.translations[0].text.clone())
     }
 }
+
+#[async_trait]
+impl Translator for DeepLTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let context = req.context.as_ref().and_then(|c| c.describe());
+        self.translate_with_context(text, context, req).await
+    }
+
+    /// One request per unit, since DeepL's context applies to the whole request
+    async fn translate_batch(
+        &self,
+        units: &[TranslationUnit],
+        req: &TranslationRequest,
+    ) -> anyhow::Result<HashMap<String, String>> {
+        let mut translations = HashMap::new();
+        for unit in units {
+            let text = self
+                .translate_with_context(&unit.text, unit.context.describe(), req)
+                .await?;
+            translations.insert(unit.key.clone(), text);
+        }
+        Ok(translations)
+    }
+
+    fn supports_language(&self, target: &Locale) -> bool {
+        Self::target_code(target).is_some()
+    }
+
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        Some(CostModel::characters("deepl", PRICE_PER_MILLION_CHARS))
+    }
+
+    async fn quota(&self) -> anyhow::Result<Vec<Quota>> {
+        let response = self
+            .client
+            .get(format!("{}/usage", self.base_url))
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!(
+                "DeepL usage error ({}): {}",
+                status,
+                error_text
+            ));
+        }
+
+        let data: DeepLUsageResponse = response.json().await?;
+        Ok(vec![Quota {
+            provider: "deepl".to_string(),
+            used: data.character_count,
+            limit: Some(data.character_limit),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{KeyContext, TranslationStyle};
+
+    fn locale(tag: &str) -> Locale {
+        Locale::parse(tag).unwrap()
+    }
+
+    #[test]
+    fn test_supports_language() {
+        let translator = DeepLTranslator::new("key".to_string());
+        assert!(translator.supports_language(&locale("de")));
+        assert!(translator.supports_language(&locale("PT-BR")));
+        assert!(translator.supports_language(&locale("en_GB")));
+        assert!(!translator.supports_language(&locale("vi")));
+        assert!(!translator.supports_language(&locale("hi")));
+    }
+
+    #[test]
+    fn test_regional_variants() {
+        let code = |tag: &str| DeepLTranslator::target_code(&locale(tag));
+        assert_eq!(code("en").as_deref(), Some("EN-US"));
+        assert_eq!(code("en-GB").as_deref(), Some("EN-GB"));
+        assert_eq!(code("en-AU").as_deref(), Some("EN-GB"));
+        assert_eq!(code("pt").as_deref(), Some("PT-PT"));
+        assert_eq!(code("pt-BR").as_deref(), Some("PT-BR"));
+        assert_eq!(code("zh-TW").as_deref(), Some("ZH-HANT"));
+        assert_eq!(code("zh-Hans").as_deref(), Some("ZH-HANS"));
+        assert_eq!(code("fr-CA").as_deref(), Some("FR"));
+        assert_eq!(
+            DeepLTranslator::source_code(&locale("en-GB")).as_deref(),
+            Some("EN")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_quota_from_usage_endpoint() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/usage")
+            .match_header("authorization", "DeepL-Auth-Key key")
+            .with_status(200)
+            .with_body(r#"{"character_count": 180118, "character_limit": 500000}"#)
+            .create_async()
+            .await;
+
+        let translator = DeepLTranslator::new("key".to_string()).with_base_url(server.url());
+        let quotas = translator.quota().await.unwrap();
+
+        assert_eq!(quotas.len(), 1);
+        assert_eq!(quotas[0].remaining(), Some(319882));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_informal_style_sets_formality() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/translate")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "target_lang": "DE",
+                "source_lang": "EN",
+                "formality": "prefer_less",
+            })))
+            .with_status(200)
+            .with_body(r#"{"translations": [{"text": "Speichere deine Änderungen"}]}"#)
+            .create_async()
+            .await;
+
+        let translator = DeepLTranslator::new("key".to_string()).with_base_url(server.url());
+        let req = TranslationRequest::parse(Some("en"), "de")
+            .unwrap()
+            .with_style(TranslationStyle {
+                formality: Some(Formality::Informal),
+                tone: None,
+            });
+        let result = translator
+            .translate("Save your changes", &req)
+            .await
+            .unwrap();
+
+        assert_eq!(result, "Speichere deine Änderungen");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_batch_sends_key_context() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/translate")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "text": ["Save"],
+                "context": "Saves the profile form. Shown in <button> of ProfileForm.",
+            })))
+            .with_status(200)
+            .with_body(r#"{"translations": [{"text": "Enregistrer"}]}"#)
+            .create_async()
+            .await;
+
+        let translator = DeepLTranslator::new("key".to_string()).with_base_url(server.url());
+        let unit = TranslationUnit {
+            key: "save".to_string(),
+            text: "Save".to_string(),
+            context: KeyContext {
+                component: Some("ProfileForm".to_string()),
+                element: Some("button".to_string()),
+                comment: Some("Saves the profile form".to_string()),
+                ..Default::default()
+            },
+        };
+        let req = TranslationRequest::parse(Some("en"), "fr").unwrap();
+        let translations = translator.translate_batch(&[unit], &req).await.unwrap();
+
+        assert_eq!(translations["save"], "Enregistrer");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_reuses_existing_glossary() {
+        let mut server = mockito::Server::new_async().await;
+        let tsv = "Acme\tAcme";
+        let name = format!("langsmith-en-de-{}", &content_hash(tsv.as_bytes())[..12]);
+        let list = server
+            .mock("GET", "/glossaries")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "glossaries": [
+                        {"glossary_id": "stale", "name": "langsmith-en-de-000000000000"},
+                        {"glossary_id": "g-123", "name": name},
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let create = server
+            .mock("POST", "/glossaries")
+            .expect(0)
+            .create_async()
+            .await;
+        let translate = server
+            .mock("POST", "/translate")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"glossary_id": "g-123", "source_lang": "EN"}),
+            ))
+            .with_status(200)
+            .with_body(r#"{"translations": [{"text": "Acme Konto"}]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let translator = DeepLTranslator::new("key".to_string())
+            .with_base_url(server.url())
+            .with_glossary(Glossary {
+                do_not_translate: vec!["Acme".to_string()],
+                ..Default::default()
+            });
+        let req = TranslationRequest::parse(Some("en"), "de").unwrap();
+        translator.translate("Acme account", &req).await.unwrap();
+        translator.translate("Acme account", &req).await.unwrap();
+
+        list.assert_async().await;
+        create.assert_async().await;
+        translate.assert_async().await;
+    }
+}
@@ -0,0 +1,139 @@
+use super::{
+    AnthropicTranslator, AzureTranslator, DeepLTranslator, FallbackTranslator, GoogleTranslator,
+    LibreTranslateTranslator, OpenAITranslator, PseudoTranslator, RoutingTranslator,
+};
+use crate::domain::models::Glossary;
+use crate::domain::ports::Translator;
+use crate::infrastructure::config::{ApiConfig, ApiProvider, ConfigManager, TranslateConfig};
+
+/// CLI overrides applied when a single provider is used
+#[derive(Debug, Clone, Default)]
+pub struct ProviderOverrides {
+    pub api_key: Option<String>,
+    pub base_url: Option<String>,
+    pub model: Option<String>,
+}
+
+/// Builds translators from provider names and project routing config
+pub struct TranslatorFactory;
+
+impl TranslatorFactory {
+    /// Build the translator for the configured provider
+    pub fn create(api_config: ApiConfig, glossary: Glossary) -> Box<dyn Translator> {
+        match api_config.provider {
+            ApiProvider::DeepL => {
+                let mut translator =
+                    DeepLTranslator::new(api_config.api_key).with_glossary(glossary);
+                if let Some(base_url) = api_config.base_url {
+                    translator = translator.with_base_url(base_url);
+                }
+                Box::new(translator)
+            }
+            ApiProvider::OpenAI | ApiProvider::OpenAICompatible => {
+                let mut translator =
+                    OpenAITranslator::new(api_config.api_key).with_glossary(glossary);
+                if let Some(base_url) = api_config.base_url {
+                    translator = translator.with_base_url(base_url);
+                }
+                if let Some(model) = api_config.model {
+                    translator = translator.with_model(model);
+                }
+                Box::new(translator)
+            }
+            ApiProvider::LibreTranslate => Box::new(LibreTranslateTranslator::new(
+                api_config.base_url.unwrap_or_default(),
+                Some(api_config.api_key),
+            )),
+            ApiProvider::Google => {
+                let mut translator = GoogleTranslator::new(
+                    api_config.api_key,
+                    api_config.project_id.unwrap_or_default(),
+                );
+                if let Some(base_url) = api_config.base_url {
+                    translator = translator.with_base_url(base_url);
+                }
+                Box::new(translator)
+            }
+            ApiProvider::Azure => {
+                let mut translator = AzureTranslator::new(api_config.api_key, api_config.region);
+                if let Some(base_url) = api_config.base_url {
+                    translator = translator.with_base_url(base_url);
+                }
+                Box::new(translator)
+            }
+            ApiProvider::Anthropic => {
+                let mut translator =
+                    AnthropicTranslator::new(api_config.api_key).with_glossary(glossary);
+                if let Some(base_url) = api_config.base_url {
+                    translator = translator.with_base_url(base_url);
+                }
+                if let Some(model) = api_config.model {
+                    translator = translator.with_model(model);
+                }
+                Box::new(translator)
+            }
+            ApiProvider::Pseudo => Box::new(PseudoTranslator::new()),
+        }
+    }
+
+    /// Build a provider chain, e.g. ["deepl", "openai"]
+    ///
+    /// CLI overrides only apply to a single-provider chain; chained providers
+    /// read their keys and endpoints from environment variables.
+    pub fn create_chain(
+        providers: &[String],
+        overrides: &ProviderOverrides,
+        glossary: &Glossary,
+    ) -> anyhow::Result<Box<dyn Translator>> {
+        match providers {
+            [] => Err(anyhow::anyhow!("No translation provider configured")),
+            [provider] => {
+                let api_config = ConfigManager::get_api_config(
+                    provider,
+                    overrides.api_key.as_deref(),
+                    overrides.base_url.as_deref(),
+                    overrides.model.as_deref(),
+                )?;
+                Ok(Self::create(api_config, glossary.clone()))
+            }
+            _ => {
+                let chain = providers
+                    .iter()
+                    .map(|provider| {
+                        let api_config = ConfigManager::get_api_config(provider, None, None, None)?;
+                        Ok((provider.clone(), Self::create(api_config, glossary.clone())))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(Box::new(FallbackTranslator::new(chain)))
+            }
+        }
+    }
+
+    /// Build a translator from the `[translate]` section of the project config
+    pub fn create_routed(
+        config: &TranslateConfig,
+        overrides: &ProviderOverrides,
+        glossary: &Glossary,
+    ) -> anyhow::Result<Box<dyn Translator>> {
+        let default = if config.providers.is_empty() {
+            None
+        } else {
+            Some(Self::create_chain(&config.providers, overrides, glossary)?)
+        };
+
+        if config.routes.is_empty() {
+            return default.ok_or_else(|| anyhow::anyhow!("No translation provider configured"));
+        }
+
+        let routes = config
+            .routes
+            .iter()
+            .map(|route| {
+                let chain = Self::create_chain(&route.providers, overrides, glossary)?;
+                Ok((route.languages.clone(), chain))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Box::new(RoutingTranslator::new(routes, default)))
+    }
+}
@@ -0,0 +1,213 @@
+use crate::domain::locale::Locale;
+use crate::domain::models::{CostModel, TranslationRequest};
+use crate::domain::ports::Translator;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+const GOOGLE_API_URL: &str = "https://translation.googleapis.com";
+
+/// USD per million characters (Cloud Translation, Basic and Advanced NMT)
+const PRICE_PER_MILLION_CHARS: f64 = 20.0;
+
+/// Google Cloud Translation v3 implementation
+///
+/// Authenticates with an OAuth access token (e.g., `gcloud auth print-access-token`).
+pub struct GoogleTranslator {
+    access_token: String,
+    project_id: String,
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl GoogleTranslator {
+    pub fn new(access_token: String, project_id: String) -> Self {
+        Self {
+            access_token,
+            project_id,
+            base_url: GOOGLE_API_URL.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Map a locale to a Google language code
+    /// e.g., "DE" -> "de", "zh" -> "zh-CN", "zh-Hant" -> "zh-TW", "pt-PT" -> "pt-PT"
+    fn language_code(locale: &Locale) -> String {
+        let region = locale.region.as_deref();
+        match locale.language.as_str() {
+            "zh" if locale.script_or_default() == Some("Hant") => "zh-TW".to_string(),
+            "zh" => "zh-CN".to_string(),
+            "pt" if region == Some("PT") => "pt-PT".to_string(),
+            "fr" if region == Some("CA") => "fr-CA".to_string(),
+            "he" => "iw".to_string(),
+            language => language.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GoogleRequest {
+    contents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_language_code: Option<String>,
+    target_language_code: String,
+    mime_type: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GoogleTranslation {
+    translated_text: String,
+}
+
+#[derive(Deserialize)]
+struct GoogleResponse {
+    #[serde(default)]
+    translations: Vec<GoogleTranslation>,
+}
+
+#[derive(Deserialize)]
+struct GoogleErrorBody {
+    message: String,
+    #[serde(default)]
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct GoogleError {
+    error: GoogleErrorBody,
+}
+
+#[async_trait]
+impl Translator for GoogleTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let request = GoogleRequest {
+            contents: vec![text.to_string()],
+            source_language_code: req.source.as_ref().map(Self::language_code),
+            target_language_code: Self::language_code(&req.target),
+            mime_type: "text/plain".to_string(),
+        };
+
+        tracing::debug!(
+            "Translating '{}' to {} via Google",
+            text,
+            request.target_language_code
+        );
+
+        let response = self
+            .client
+            .post(format!(
+                "{}/v3/projects/{}/locations/global:translateText",
+                self.base_url, self.project_id
+            ))
+            .bearer_auth(&self.access_token)
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            // Google reports errors as {"error": {"code", "message", "status"}}
+            let message = serde_json::from_str::<GoogleError>(&error_text)
+                .map(|e| format!("{} {}", e.error.status, e.error.message))
+                .unwrap_or(error_text);
+            return Err(anyhow::anyhow!(
+                "Google Translate API error ({}): {}",
+                status,
+                message.trim()
+            ));
+        }
+
+        let data: GoogleResponse = response.json().await?;
+
+        data.translations
+            .into_iter()
+            .next()
+            .map(|t| t.translated_text)
+            .ok_or_else(|| anyhow::anyhow!("Google Translate returned empty translations"))
+    }
+
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        Some(CostModel::characters("google", PRICE_PER_MILLION_CHARS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_code() {
+        let code = |tag: &str| GoogleTranslator::language_code(&Locale::parse(tag).unwrap());
+        assert_eq!(code("DE"), "de");
+        assert_eq!(code("zh"), "zh-CN");
+        assert_eq!(code("zh_Hant"), "zh-TW");
+        assert_eq!(code("zh-HK"), "zh-TW");
+        assert_eq!(code("pt-PT"), "pt-PT");
+        assert_eq!(code("pt-BR"), "pt");
+        assert_eq!(code("es-MX"), "es");
+    }
+
+    #[tokio::test]
+    async fn test_translate_against_mock_server() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "POST",
+                "/v3/projects/my-project/locations/global:translateText",
+            )
+            .match_header("authorization", "Bearer token")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "contents": ["Save"],
+                "sourceLanguageCode": "en",
+                "targetLanguageCode": "de",
+            })))
+            .with_status(200)
+            .with_body(r#"{"translations": [{"translatedText": "Speichern"}]}"#)
+            .create_async()
+            .await;
+
+        let translator = GoogleTranslator::new("token".to_string(), "my-project".to_string())
+            .with_base_url(server.url());
+        let result = translator
+            .translate(
+                "Save",
+                &TranslationRequest::parse(Some("en"), "de").unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, "Speichern");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_mapping() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", mockito::Matcher::Any)
+            .with_status(400)
+            .with_body(
+                r#"{"error": {"code": 400, "message": "Target language is invalid.", "status": "INVALID_ARGUMENT"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let translator =
+            GoogleTranslator::new("token".to_string(), "p".to_string()).with_base_url(server.url());
+        let err = translator
+            .translate("Save", &TranslationRequest::parse(None, "xx").unwrap())
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("400"));
+        assert!(message.contains("INVALID_ARGUMENT Target language is invalid."));
+    }
+}
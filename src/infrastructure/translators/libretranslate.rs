@@ -0,0 +1,131 @@
+use crate::domain::locale::Locale;
+use crate::domain::models::{CostModel, TranslationRequest};
+use crate::domain::ports::Translator;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// LibreTranslate API implementation (self-hosted or public instance)
+pub struct LibreTranslateTranslator {
+    base_url: String,
+    api_key: Option<String>,
+    client: reqwest::Client,
+}
+
+impl LibreTranslateTranslator {
+    /// Create a translator for an instance, e.g. "http://localhost:5000"
+    pub fn new(base_url: String, api_key: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.filter(|k| !k.is_empty()),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Map a locale to a LibreTranslate language code
+    /// e.g., "DE" -> "de", "pt-BR" -> "pt-BR", "zh-Hant" -> "zt"
+    fn language_code(locale: &Locale) -> String {
+        match locale.language.as_str() {
+            "pt" if locale.region.as_deref() == Some("BR") => "pt-BR".to_string(),
+            "zh" if locale.script_or_default() == Some("Hant") => "zt".to_string(),
+            language => language.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct LibreTranslateRequest {
+    q: String,
+    source: String,
+    target: String,
+    format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_key: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LibreTranslateResponse {
+    #[serde(rename = "translatedText")]
+    translated_text: String,
+}
+
+#[derive(Deserialize)]
+struct LibreTranslateError {
+    error: String,
+}
+
+#[async_trait]
+impl Translator for LibreTranslateTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let request = LibreTranslateRequest {
+            q: text.to_string(),
+            source: req
+                .source
+                .as_ref()
+                .map(Self::language_code)
+                .unwrap_or_else(|| "auto".to_string()),
+            target: Self::language_code(&req.target),
+            format: "text".to_string(),
+            api_key: self.api_key.clone(),
+        };
+
+        tracing::debug!(
+            "Translating '{}' to {} via LibreTranslate",
+            text,
+            request.target
+        );
+
+        let response = self
+            .client
+            .post(format!("{}/translate", self.base_url))
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            // LibreTranslate reports errors as {"error": "..."}
+            let message = serde_json::from_str::<LibreTranslateError>(&error_text)
+                .map(|e| e.error)
+                .unwrap_or(error_text);
+            return Err(anyhow::anyhow!(
+                "LibreTranslate API error ({}): {}",
+                status,
+                message
+            ));
+        }
+
+        let data: LibreTranslateResponse = response.json().await?;
+        Ok(data.translated_text)
+    }
+
+    /// Self-hosted instances have no per-character cost
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        Some(CostModel::characters("libretranslate", 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_code() {
+        let translator = LibreTranslateTranslator::new("http://localhost:5000/".to_string(), None);
+        assert_eq!(translator.base_url, "http://localhost:5000");
+
+        let code =
+            |tag: &str| LibreTranslateTranslator::language_code(&Locale::parse(tag).unwrap());
+        assert_eq!(code("DE"), "de");
+        assert_eq!(code("fr-CA"), "fr");
+        assert_eq!(code("pt_BR"), "pt-BR");
+        assert_eq!(code("zh-Hant"), "zt");
+    }
+
+    #[test]
+    fn test_empty_api_key_is_omitted() {
+        let translator =
+            LibreTranslateTranslator::new("http://lt".to_string(), Some(String::new()));
+        assert!(translator.api_key.is_none());
+    }
+}
@@ -1,6 +1,21 @@
 /// Translation API implementations
+pub mod anthropic;
+pub mod azure;
 pub mod deepl;
+pub mod factory;
+pub mod google;
+pub mod libretranslate;
 pub mod openai;
+mod prompt;
+pub mod pseudo;
+pub mod routing;
 
+pub use anthropic::AnthropicTranslator;
+pub use azure::AzureTranslator;
 pub use deepl::DeepLTranslator;
+pub use factory::{ProviderOverrides, TranslatorFactory};
+pub use google::GoogleTranslator;
+pub use libretranslate::LibreTranslateTranslator;
 pub use openai::OpenAITranslator;
+pub use pseudo::PseudoTranslator;
+pub use routing::{FallbackTranslator, RoutingTranslator};
@@ -1,11 +1,34 @@
+use super::prompt;
+use crate::domain::locale::Locale;
+use crate::domain::models::{CostModel, Glossary, TranslationRequest, TranslationUnit};
 use crate::domain::ports::Translator;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const OPENAI_API_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_MODEL: &str = "gpt-3.5-turbo";
+
+/// USD per million tokens (input, output) by model prefix; more specific prefixes first
+const MODEL_PRICING: &[(&str, f64, f64)] = &[
+    ("gpt-4o-mini", 0.15, 0.60),
+    ("gpt-4o", 2.50, 10.00),
+    ("gpt-4.1-nano", 0.10, 0.40),
+    ("gpt-4.1-mini", 0.40, 1.60),
+    ("gpt-4.1", 2.00, 8.00),
+    ("gpt-3.5-turbo", 0.50, 1.50),
+];
 
 /// OpenAI translation API implementation
+///
+/// Also works with any OpenAI-compatible chat completions server
+/// (Ollama, vLLM, LM Studio) through `with_base_url`.
 pub struct OpenAITranslator {
     api_key: String,
     client: reqwest::Client,
+    base_url: String,
+    model: String,
+    glossary: Option<Glossary>,
 }
 
 impl OpenAITranslator {
@@ -13,66 +36,123 @@ impl OpenAITranslator {
         Self {
             api_key,
             client: reqwest::Client::new(),
+            base_url: OPENAI_API_URL.to_string(),
+            model: DEFAULT_MODEL.to_string(),
+            glossary: None,
         }
     }
 
-    /// Convert language code to full language name
-    /// e.g., "en" -> "English", "fr" -> "French"
-    fn lang_code_to_name(&self, code: &str) -> String {
-        match code.to_lowercase().as_str() {
-            "en" => "English".to_string(),
-            "fr" => "French".to_string(),
-            "es" => "Spanish".to_string(),
-            "de" => "German".to_string(),
-            "it" => "Italian".to_string(),
-            "pt" => "Portuguese".to_string(),
-            "nl" => "Dutch".to_string(),
-            "pl" => "Polish".to_string(),
-            "ru" => "Russian".to_string(),
-            "ja" => "Japanese".to_string(),
-            "zh" => "Chinese".to_string(),
-            "ko" => "Korean".to_string(),
-            other => other.to_uppercase(),
+    /// Point the translator at another OpenAI-compatible endpoint
+    /// e.g., "http://localhost:11434/v1" for Ollama
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_model(mut self, model: String) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Inject project glossary terms into the system prompt
+    pub fn with_glossary(mut self, glossary: Glossary) -> Self {
+        if !glossary.is_empty() {
+            self.glossary = Some(glossary);
         }
+        self
     }
-}
 
-#[derive(Serialize, Deserialize)]
-struct OpenAIMessage {
-    role: String,
-    content: String,
-}
+    /// Build the system prompt for JSON batch mode
+    fn batch_system_prompt(&self, units: &[TranslationUnit], req: &TranslationRequest) -> String {
+        let mut prompt = format!(
+            "You are a professional translator localizing the user interface of a software \
+             application {}. You receive a JSON object whose keys are translation keys and \
+             whose values contain the source \"text\" and optional \"context\" (file path, \
+             component, JSX element, developer comment, max_length). Use the context to pick \
+             the right wording, follow developer comments, and keep \
+             translations within max_length characters when given. Keep placeholders such as \
+             {{name}} or {{{{count}}}} unchanged. Respond with a JSON object mapping every \
+             input key to its translated string, and nothing else.",
+            prompt::direction(req)
+        );
 
-#[derive(Serialize)]
-struct OpenAIRequest {
-    model: String,
-    messages: Vec<OpenAIMessage>,
-    temperature: f32,
-}
+        prompt.push_str(&prompt::style_section(&req.style));
 
-#[derive(Deserialize)]
-struct OpenAIChoice {
-    message: OpenAIMessage,
-}
+        let texts: Vec<&str> = units.iter().map(|u| u.text.as_str()).collect();
+        prompt.push_str(&prompt::terminology_section(
+            self.glossary.as_ref(),
+            &texts,
+            &req.target.tag(),
+        ));
+        prompt
+    }
 
-#[derive(Deserialize)]
-struct OpenAIResponse {
-    choices: Vec<OpenAIChoice>,
-}
+    /// Serialize a chunk of units as the JSON object sent in batch mode
+    fn batch_payload(units: &[TranslationUnit]) -> anyhow::Result<String> {
+        let payload: serde_json::Map<String, serde_json::Value> = units
+            .iter()
+            .map(|unit| {
+                let mut entry = serde_json::json!({ "text": unit.text });
+                if !unit.context.is_empty() {
+                    entry["context"] = serde_json::to_value(&unit.context)?;
+                }
+                Ok((unit.key.clone(), entry))
+            })
+            .collect::<anyhow::Result<_>>()?;
 
-#[async_trait]
-impl Translator for OpenAITranslator {
-    async fn translate(&self, text: &str, target_lang: &str) -> anyhow::Result<String> {
-        let target_lang_name = self.lang_code_to_name(target_lang);
+        Ok(serde_json::to_string_pretty(&payload)?)
+    }
 
-        let system_prompt = format!(
-            "You are a professional translator. Translate the following text to {}. \
-             Return only the translated text, no explanations, no markdown formatting.",
-            target_lang_name
-        );
+    /// Parse and validate a batch response: every requested key must map to a string
+    fn parse_batch_response(
+        content: &str,
+        units: &[TranslationUnit],
+    ) -> anyhow::Result<HashMap<String, String>> {
+        let value: serde_json::Value = serde_json::from_str(content.trim())
+            .map_err(|e| anyhow::anyhow!("OpenAI batch response is not valid JSON: {}", e))?;
 
+        let object = value
+            .as_object()
+            .ok_or_else(|| anyhow::anyhow!("OpenAI batch response is not a JSON object"))?;
+
+        let mut translations = HashMap::new();
+        let mut missing = Vec::new();
+
+        for unit in units {
+            match object.get(&unit.key).and_then(|v| v.as_str()) {
+                Some(text) if !text.trim().is_empty() => {
+                    translations.insert(unit.key.clone(), text.trim().to_string());
+                }
+                _ => missing.push(unit.key.as_str()),
+            }
+        }
+
+        if !missing.is_empty() {
+            return Err(anyhow::anyhow!(
+                "OpenAI batch response is missing keys: {}",
+                missing.join(", ")
+            ));
+        }
+
+        if object.len() > units.len() {
+            tracing::warn!(
+                "OpenAI batch response contains {} unexpected keys",
+                object.len() - units.len()
+            );
+        }
+
+        Ok(translations)
+    }
+
+    /// Send a chat completion and return the content of the first choice
+    async fn complete(
+        &self,
+        system_prompt: String,
+        user_content: String,
+        json_mode: bool,
+    ) -> anyhow::Result<String> {
         let request = OpenAIRequest {
-            model: "gpt-3.5-turbo".to_string(),
+            model: self.model.clone(),
             messages: vec![
                 OpenAIMessage {
                     role: "system".to_string(),
@@ -80,19 +160,26 @@ impl Translator for OpenAITranslator {
                 },
                 OpenAIMessage {
                     role: "user".to_string(),
-                    content: text.to_string(),
+                    content: user_content,
                 },
             ],
             temperature: 0.3,
+            response_format: json_mode.then(|| OpenAIResponseFormat {
+                format_type: "json_object".to_string(),
+            }),
         };
 
-        let response = self
+        let mut http_request = self
             .client
-            .post("https://api.openai.com/v1/chat/completions")
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .json(&request)
-            .send()
-            .await?;
+            .post(format!("{}/chat/completions", self.base_url))
+            .json(&request);
+
+        // Local servers usually run without authentication
+        if !self.api_key.is_empty() {
+            http_request = http_request.header("Authorization", format!("Bearer {}", self.api_key));
+        }
+
+        let response = http_request.send().await?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -110,7 +197,232 @@ impl Translator for OpenAITranslator {
             return Err(anyhow::anyhow!("OpenAI returned no choices"));
         }
 
-        let translated = data.choices[0].message.content.trim().to_string();
-        Ok(translated)
+        Ok(data.choices[0].message.content.trim().to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct OpenAIMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct OpenAIResponseFormat {
+    #[serde(rename = "type")]
+    format_type: String,
+}
+
+#[derive(Serialize)]
+struct OpenAIRequest {
+    model: String,
+    messages: Vec<OpenAIMessage>,
+    temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<OpenAIResponseFormat>,
+}
+
+#[derive(Deserialize)]
+struct OpenAIChoice {
+    message: OpenAIMessage,
+}
+
+#[derive(Deserialize)]
+struct OpenAIResponse {
+    choices: Vec<OpenAIChoice>,
+}
+
+#[async_trait]
+impl Translator for OpenAITranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let system_prompt = prompt::system_prompt(self.glossary.as_ref(), text, req);
+        self.complete(system_prompt, text.to_string(), false).await
+    }
+
+    async fn translate_batch(
+        &self,
+        units: &[TranslationUnit],
+        req: &TranslationRequest,
+    ) -> anyhow::Result<HashMap<String, String>> {
+        if units.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let system_prompt = self.batch_system_prompt(units, req);
+        let payload = Self::batch_payload(units)?;
+
+        tracing::debug!(
+            "Translating batch of {} keys to {}",
+            units.len(),
+            req.target
+        );
+        let content = self.complete(system_prompt, payload, true).await?;
+
+        Self::parse_batch_response(&content, units)
+    }
+
+    /// List prices for api.openai.com; other servers are reported with unknown pricing
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        if self.base_url != OPENAI_API_URL {
+            return Some(CostModel::tokens("openai-compatible", None));
+        }
+
+        let pricing = MODEL_PRICING
+            .iter()
+            .find(|(prefix, _, _)| self.model.starts_with(prefix))
+            .map(|&(_, input, output)| (input, output));
+        Some(CostModel::tokens("openai", pricing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{Formality, KeyContext, TranslationStyle};
+
+    fn unit(key: &str, text: &str) -> TranslationUnit {
+        TranslationUnit {
+            key: key.to_string(),
+            text: text.to_string(),
+            context: KeyContext::default(),
+        }
+    }
+
+    #[test]
+    fn test_system_prompt_includes_glossary_terms() {
+        let glossary = Glossary {
+            source_lang: None,
+            do_not_translate: vec!["Langsmith Pro".to_string()],
+            terms: HashMap::from([(
+                "Workspace".to_string(),
+                HashMap::from([("de".to_string(), "Arbeitsbereich".to_string())]),
+            )]),
+        };
+        let translator = OpenAITranslator::new("key".to_string()).with_glossary(glossary);
+
+        let req = TranslationRequest::parse(Some("en"), "de").unwrap();
+        let prompt = prompt::system_prompt(
+            translator.glossary.as_ref(),
+            "Open Langsmith Pro workspace",
+            &req,
+        );
+        assert!(prompt.contains("from English to German"));
+        assert!(prompt.contains("\"Langsmith Pro\" must not be translated"));
+        assert!(prompt.contains("\"Workspace\" -> \"Arbeitsbereich\""));
+
+        let prompt = prompt::system_prompt(translator.glossary.as_ref(), "Open settings", &req);
+        assert!(!prompt.contains("terminology"));
+    }
+
+    #[test]
+    fn test_prompts_include_style() {
+        let translator = OpenAITranslator::new("key".to_string());
+        let req = TranslationRequest::parse(Some("en"), "fr")
+            .unwrap()
+            .with_style(TranslationStyle {
+                formality: Some(Formality::Informal),
+                tone: Some("playful, short sentences".to_string()),
+            });
+
+        let single = prompt::system_prompt(None, "Save", &req);
+        assert!(single.contains("informally"));
+        assert!(single.contains("Style guide: playful, short sentences"));
+
+        let batch = translator.batch_system_prompt(&[unit("save", "Save")], &req);
+        assert!(batch.contains("\"tu\""));
+
+        let plain = prompt::system_prompt(
+            None,
+            "Save",
+            &TranslationRequest::parse(None, "fr").unwrap(),
+        );
+        assert!(!plain.contains("informally"));
+    }
+
+    #[test]
+    fn test_single_prompt_includes_context() {
+        let req = TranslationRequest::parse(Some("en"), "de")
+            .unwrap()
+            .with_context(&KeyContext {
+                component: Some("Form".to_string()),
+                element: Some("button".to_string()),
+                ..Default::default()
+            });
+
+        let prompt = prompt::system_prompt(None, "Save", &req);
+        assert!(prompt.contains("Context (do not translate): Shown in <button> of Form."));
+
+        let plain = TranslationRequest::parse(Some("en"), "de")
+            .unwrap()
+            .with_context(&KeyContext::default());
+        assert!(plain.context.is_none());
+        assert!(!prompt::system_prompt(None, "Save", &plain).contains("Context"));
+    }
+
+    #[test]
+    fn test_batch_payload_includes_context() {
+        let mut with_context = unit("button_save", "Save");
+        with_context.context = KeyContext {
+            file_path: Some("src/Form.tsx".to_string()),
+            component: Some("Form".to_string()),
+            comment: Some("Submits the signup form".to_string()),
+            max_length: Some(12),
+            ..Default::default()
+        };
+        let units = vec![with_context, unit("title", "Welcome")];
+
+        let payload = OpenAITranslator::batch_payload(&units).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&payload).unwrap();
+
+        assert_eq!(value["button_save"]["text"], "Save");
+        assert_eq!(value["button_save"]["context"]["component"], "Form");
+        assert_eq!(value["button_save"]["context"]["max_length"], 12);
+        assert_eq!(
+            value["button_save"]["context"]["comment"],
+            "Submits the signup form"
+        );
+        assert!(value["title"].get("context").is_none());
+    }
+
+    #[test]
+    fn test_parse_batch_response_valid() {
+        let units = vec![unit("save", "Save"), unit("cancel", "Cancel")];
+        let content = r#"{"save": "Speichern", "cancel": "Abbrechen"}"#;
+
+        let result = OpenAITranslator::parse_batch_response(content, &units).unwrap();
+        assert_eq!(result["save"], "Speichern");
+        assert_eq!(result["cancel"], "Abbrechen");
+    }
+
+    #[test]
+    fn test_parse_batch_response_missing_key() {
+        let units = vec![unit("save", "Save"), unit("cancel", "Cancel")];
+        let content = r#"{"save": "Speichern", "cancel": 3}"#;
+
+        let err = OpenAITranslator::parse_batch_response(content, &units).unwrap_err();
+        assert!(err.to_string().contains("cancel"));
+    }
+
+    #[test]
+    fn test_parse_batch_response_not_json() {
+        let units = vec![unit("save", "Save")];
+        assert!(OpenAITranslator::parse_batch_response("Speichern", &units).is_err());
+    }
+
+    #[test]
+    fn test_cost_model_by_model() {
+        let target = Locale::parse("de").unwrap();
+        let model = |translator: OpenAITranslator| translator.cost_model(&target).unwrap();
+
+        let mini = model(OpenAITranslator::new("key".to_string()).with_model("gpt-4o-mini".into()));
+        assert_eq!(mini.input_price, Some(0.15));
+
+        let unknown = model(OpenAITranslator::new("key".to_string()).with_model("o9".into()));
+        assert_eq!(unknown.input_price, None);
+
+        let local = model(
+            OpenAITranslator::new(String::new()).with_base_url("http://localhost:11434/v1".into()),
+        );
+        assert_eq!(local.provider, "openai-compatible");
     }
 }
@@ -0,0 +1,88 @@
+//! Shared prompt helpers for LLM-based translators (OpenAI, Anthropic)
+
+use crate::domain::models::{Formality, Glossary, TranslationRequest, TranslationStyle};
+
+/// Build the single-string system prompt, listing only glossary terms that occur in the text
+pub fn system_prompt(glossary: Option<&Glossary>, text: &str, req: &TranslationRequest) -> String {
+    let mut prompt = format!(
+        "You are a professional translator. Translate the following text {}. \
+         Return only the translated text, no explanations, no markdown formatting.",
+        direction(req)
+    );
+    prompt.push_str(&style_section(&req.style));
+    if let Some(context) = req.context.as_ref().and_then(|c| c.describe()) {
+        prompt.push_str(&format!(
+            "\n\nContext (do not translate): {} Use it to pick the right wording.",
+            context
+        ));
+    }
+    prompt.push_str(&terminology_section(glossary, &[text], &req.target.tag()));
+    prompt
+}
+
+/// Formality and tone instructions, empty when no style is configured
+pub fn style_section(style: &TranslationStyle) -> String {
+    let mut section = String::new();
+    if style.is_empty() {
+        return section;
+    }
+    match style.formality {
+        Some(Formality::Informal) => section.push_str(
+            "\n\nAddress the reader informally (e.g., \"du\" in German, \"tu\" in French).",
+        ),
+        Some(Formality::Formal) => section.push_str(
+            "\n\nAddress the reader formally (e.g., \"Sie\" in German, \"vous\" in French).",
+        ),
+        None => {}
+    }
+    if let Some(tone) = style.tone.as_deref().filter(|t| !t.trim().is_empty()) {
+        section.push_str(&format!("\n\nStyle guide: {}", tone.trim()));
+    }
+    section
+}
+
+/// Describe the translation direction, e.g. "from English to German"
+///
+/// Naming the source keeps models from guessing the language of short strings.
+pub fn direction(req: &TranslationRequest) -> String {
+    match &req.source {
+        Some(source) => format!(
+            "from {} to {}",
+            source.display_name(),
+            req.target.display_name()
+        ),
+        None => format!("to {}", req.target.display_name()),
+    }
+}
+
+/// Glossary instructions for the terms occurring in any of the texts
+pub fn terminology_section(
+    glossary: Option<&Glossary>,
+    texts: &[&str],
+    target_lang: &str,
+) -> String {
+    let Some(glossary) = glossary else {
+        return String::new();
+    };
+
+    let mut entries: Vec<(String, String)> = texts
+        .iter()
+        .flat_map(|text| glossary.entries_in(text, target_lang))
+        .collect();
+    entries.sort();
+    entries.dedup();
+
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    let mut section = String::from("\n\nUse this terminology exactly:");
+    for (term, expected) in entries {
+        if term == expected {
+            section.push_str(&format!("\n- \"{}\" must not be translated", term));
+        } else {
+            section.push_str(&format!("\n- \"{}\" -> \"{}\"", term, expected));
+        }
+    }
+    section
+}
@@ -0,0 +1,216 @@
+use crate::domain::locale::Locale;
+use crate::domain::models::{CostModel, TranslationRequest};
+use crate::domain::ports::Translator;
+use async_trait::async_trait;
+use regex::Regex;
+use std::time::Duration;
+
+/// Right-to-left override / pop directional formatting, used for pseudo-bidi output
+const RLO: char = '\u{202E}';
+const PDF: char = '\u{202C}';
+
+/// Offline pseudo-localization "translator"
+///
+/// Produces visibly foreign but readable output so hard-coded and truncated
+/// strings stand out in the UI: "Save changes" -> "[Šåṽé çĥåñĝéš ~~~~]".
+/// Placeholders ({name}, {{count}}, %s, <b>...) are left untouched.
+pub struct PseudoTranslator {
+    /// Extra length added as padding, relative to the source length
+    expansion: f32,
+    /// Mirror text with bidi control characters (also enabled for "*-XB" locales)
+    rtl: bool,
+}
+
+impl Default for PseudoTranslator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PseudoTranslator {
+    pub fn new() -> Self {
+        Self {
+            expansion: 0.3,
+            rtl: false,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_expansion(mut self, expansion: f32) -> Self {
+        self.expansion = expansion.max(0.0);
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Pseudo-bidi locales follow the Android convention ("ar-XB", "en-XB")
+    fn is_rtl_locale(target: &Locale) -> bool {
+        target.region.as_deref() == Some("XB")
+    }
+
+    /// Pseudo-localize a string, keeping placeholders intact
+    fn pseudolocalize(&self, text: &str, rtl: bool) -> String {
+        // Regex compile-time unwrap is safe (compile-time constant)
+        let placeholder = Regex::new(
+            r"\{\{[^}]*\}\}|\{[^}]*\}|%(\([^)]*\))?[sdif@%]|\$\{[^}]*\}|</?[a-zA-Z][^>]*>",
+        )
+        .expect("invalid regex");
+
+        let mut body = String::new();
+        let mut letters = 0;
+        let mut last = 0;
+
+        for m in placeholder.find_iter(text) {
+            let segment = &text[last..m.start()];
+            letters += segment.chars().filter(|c| c.is_alphanumeric()).count();
+            body.push_str(&self.transform_segment(segment, rtl));
+            body.push_str(m.as_str());
+            last = m.end();
+        }
+        let tail = &text[last..];
+        letters += tail.chars().filter(|c| c.is_alphanumeric()).count();
+        body.push_str(&self.transform_segment(tail, rtl));
+
+        let padding = (letters as f32 * self.expansion).ceil() as usize;
+        if padding > 0 {
+            body.push(' ');
+            body.push_str(&"~".repeat(padding));
+        }
+
+        format!("[{}]", body)
+    }
+
+    fn transform_segment(&self, segment: &str, rtl: bool) -> String {
+        if segment.is_empty() {
+            return String::new();
+        }
+
+        let accented: String = segment.chars().map(accent).collect();
+        if rtl && accented.chars().any(|c| c.is_alphanumeric()) {
+            format!("{}{}{}", RLO, accented, PDF)
+        } else {
+            accented
+        }
+    }
+}
+
+/// Map an ASCII letter to an accented look-alike
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'å',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        other => other,
+    }
+}
+
+#[async_trait]
+impl Translator for PseudoTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let rtl = self.rtl || Self::is_rtl_locale(&req.target);
+        Ok(self.pseudolocalize(text, rtl))
+    }
+
+    fn rate_limit(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    fn cost_model(&self, _target: &Locale) -> Option<CostModel> {
+        Some(CostModel::characters("pseudo", 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accents_and_brackets() {
+        let translator = PseudoTranslator::new().with_expansion(0.0);
+        assert_eq!(translator.pseudolocalize("Save", false), "[Šåṽé]");
+    }
+
+    #[test]
+    fn test_expansion_padding() {
+        let translator = PseudoTranslator::new();
+        // 10 letters -> 3 padding characters
+        let result = translator.pseudolocalize("Hello World", false);
+        assert_eq!(result, "[Ĥéļļö Ŵöŕļð ~~~]");
+    }
+
+    #[test]
+    fn test_placeholders_preserved() {
+        let translator = PseudoTranslator::new().with_expansion(0.0);
+        let result = translator.pseudolocalize("Hi {name}, {{count}} new <b>items</b> %s", false);
+        assert_eq!(result, "[Ĥî {name}, {{count}} ñéŵ <b>îţéɱš</b> %s]");
+    }
+
+    #[test]
+    fn test_rtl_mirroring() {
+        let translator = PseudoTranslator::new().with_expansion(0.0);
+        let result = translator.pseudolocalize("Hi {name}", true);
+        assert_eq!(result, format!("[{}Ĥî {}{{name}}]", RLO, PDF));
+    }
+
+    #[test]
+    fn test_rtl_locale_detection() {
+        let rtl = |tag: &str| PseudoTranslator::is_rtl_locale(&Locale::parse(tag).unwrap());
+        assert!(rtl("ar-XB"));
+        assert!(rtl("en_xb"));
+        assert!(!rtl("en-XA"));
+        assert!(!rtl("de"));
+    }
+}
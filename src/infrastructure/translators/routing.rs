@@ -0,0 +1,413 @@
+use crate::domain::locale::Locale;
+use crate::domain::models::{CostModel, Quota, TranslationRequest, TranslationUnit};
+use crate::domain::ports::Translator;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Tries providers in order until one succeeds
+///
+/// Providers that do not support the target language are skipped without a
+/// request; errors from a provider move the key on to the next one.
+pub struct FallbackTranslator {
+    providers: Vec<(String, Box<dyn Translator>)>,
+}
+
+impl FallbackTranslator {
+    pub fn new(providers: Vec<(String, Box<dyn Translator>)>) -> Self {
+        Self { providers }
+    }
+
+    fn candidates<'a>(
+        &'a self,
+        target: &'a Locale,
+    ) -> impl Iterator<Item = &'a (String, Box<dyn Translator>)> + 'a {
+        self.providers.iter().filter(move |(name, translator)| {
+            let supported = translator.supports_language(target);
+            if !supported {
+                tracing::debug!("{} does not support {}, skipping", name, target);
+            }
+            supported
+        })
+    }
+
+    fn no_provider_error(&self, target: &Locale, errors: Vec<String>) -> anyhow::Error {
+        if errors.is_empty() {
+            anyhow::anyhow!(
+                "No configured provider supports {} (tried: {})",
+                target,
+                self.providers
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        } else {
+            anyhow::anyhow!("All providers failed: {}", errors.join("; "))
+        }
+    }
+}
+
+#[async_trait]
+impl Translator for FallbackTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        let mut errors = Vec::new();
+
+        for (name, translator) in self.candidates(&req.target) {
+            match translator.translate(text, req).await {
+                Ok(translated) => return Ok(translated),
+                Err(e) => {
+                    tracing::warn!(
+                        "{} failed for {}, trying next provider: {}",
+                        name,
+                        req.target,
+                        e
+                    );
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        Err(self.no_provider_error(&req.target, errors))
+    }
+
+    async fn translate_batch(
+        &self,
+        units: &[TranslationUnit],
+        req: &TranslationRequest,
+    ) -> anyhow::Result<HashMap<String, String>> {
+        let mut errors = Vec::new();
+
+        for (name, translator) in self.candidates(&req.target) {
+            match translator.translate_batch(units, req).await {
+                Ok(translations) => return Ok(translations),
+                Err(e) => {
+                    tracing::warn!(
+                        "{} batch failed for {}, trying next provider: {}",
+                        name,
+                        req.target,
+                        e
+                    );
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        Err(self.no_provider_error(&req.target, errors))
+    }
+
+    fn supports_language(&self, target: &Locale) -> bool {
+        self.providers
+            .iter()
+            .any(|(_, translator)| translator.supports_language(target))
+    }
+
+    fn rate_limit(&self) -> Duration {
+        self.providers
+            .iter()
+            .map(|(_, translator)| translator.rate_limit())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Cost of the first provider that would be tried
+    fn cost_model(&self, target: &Locale) -> Option<CostModel> {
+        self.candidates(target)
+            .next()
+            .and_then(|(_, translator)| translator.cost_model(target))
+    }
+
+    async fn quota(&self) -> anyhow::Result<Vec<Quota>> {
+        let mut quotas = Vec::new();
+        for (name, translator) in &self.providers {
+            match translator.quota().await {
+                Ok(provider_quotas) => quotas.extend(provider_quotas),
+                Err(e) => tracing::warn!("Could not fetch {} usage: {}", name, e),
+            }
+        }
+        Ok(quotas)
+    }
+}
+
+/// Routes each target language to its own provider chain
+pub struct RoutingTranslator {
+    /// (languages, chain) pairs, checked in order
+    routes: Vec<(Vec<String>, Box<dyn Translator>)>,
+    /// Chain for languages without a route
+    default: Option<Box<dyn Translator>>,
+}
+
+impl RoutingTranslator {
+    pub fn new(
+        routes: Vec<(Vec<String>, Box<dyn Translator>)>,
+        default: Option<Box<dyn Translator>>,
+    ) -> Self {
+        Self { routes, default }
+    }
+
+    /// Find the chain for a locale: exact match first, then language only ("pt-BR" -> "pt")
+    ///
+    /// Route entries that are not valid locales never match.
+    fn route(&self, target: &Locale) -> anyhow::Result<&dyn Translator> {
+        let matches = |wanted: &Locale| {
+            self.routes
+                .iter()
+                .find(|(languages, _)| {
+                    languages
+                        .iter()
+                        .filter_map(|lang| Locale::parse(lang).ok())
+                        .any(|locale| &locale == wanted)
+                })
+                .map(|(_, translator)| translator.as_ref())
+        };
+
+        let language_only = Locale {
+            language: target.language.clone(),
+            script: None,
+            region: None,
+            variants: Vec::new(),
+        };
+
+        matches(target)
+            .or_else(|| matches(&language_only))
+            .or(self.default.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("No provider route configured for {}", target))
+    }
+}
+
+#[async_trait]
+impl Translator for RoutingTranslator {
+    async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+        self.route(&req.target)?.translate(text, req).await
+    }
+
+    async fn translate_batch(
+        &self,
+        units: &[TranslationUnit],
+        req: &TranslationRequest,
+    ) -> anyhow::Result<HashMap<String, String>> {
+        self.route(&req.target)?.translate_batch(units, req).await
+    }
+
+    fn supports_language(&self, target: &Locale) -> bool {
+        self.route(target)
+            .map(|translator| translator.supports_language(target))
+            .unwrap_or(false)
+    }
+
+    fn rate_limit(&self) -> Duration {
+        self.routes
+            .iter()
+            .map(|(_, translator)| translator.rate_limit())
+            .chain(
+                self.default
+                    .iter()
+                    .map(|translator| translator.rate_limit()),
+            )
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn cost_model(&self, target: &Locale) -> Option<CostModel> {
+        self.route(target).ok()?.cost_model(target)
+    }
+
+    async fn quota(&self) -> anyhow::Result<Vec<Quota>> {
+        let mut quotas: Vec<Quota> = Vec::new();
+        for (route, translator) in self
+            .routes
+            .iter()
+            .map(|(languages, translator)| (languages.join(", "), translator))
+            .chain(
+                self.default
+                    .iter()
+                    .map(|translator| ("default".to_string(), translator)),
+            )
+        {
+            match translator.quota().await {
+                Ok(route_quotas) => {
+                    for quota in route_quotas {
+                        // The same provider can back several routes
+                        if !quotas.contains(&quota) {
+                            quotas.push(quota);
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!("Could not fetch usage for route {}: {}", route, e),
+            }
+        }
+        Ok(quotas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test double: prefixes text with its name, fails or rejects on demand
+    struct StubTranslator {
+        name: &'static str,
+        fails: bool,
+        languages: Option<Vec<&'static str>>,
+    }
+
+    fn to(target: &str) -> TranslationRequest {
+        TranslationRequest::parse(Some("en"), target).unwrap()
+    }
+
+    impl StubTranslator {
+        fn boxed(name: &'static str) -> Box<dyn Translator> {
+            Box::new(Self {
+                name,
+                fails: false,
+                languages: None,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl Translator for StubTranslator {
+        async fn translate(&self, text: &str, req: &TranslationRequest) -> anyhow::Result<String> {
+            if self.fails {
+                return Err(anyhow::anyhow!("{} is down", self.name));
+            }
+            Ok(format!("{}:{}:{}", self.name, req.target, text))
+        }
+
+        async fn quota(&self) -> anyhow::Result<Vec<Quota>> {
+            if self.fails {
+                return Err(anyhow::anyhow!("{} is down", self.name));
+            }
+            Ok(vec![Quota {
+                provider: self.name.to_string(),
+                used: 10,
+                limit: Some(100),
+            }])
+        }
+
+        fn supports_language(&self, target: &Locale) -> bool {
+            self.languages
+                .as_ref()
+                .map(|langs| langs.contains(&target.language.as_str()))
+                .unwrap_or(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fallback_on_error() {
+        let chain = FallbackTranslator::new(vec![
+            (
+                "primary".to_string(),
+                Box::new(StubTranslator {
+                    name: "primary",
+                    fails: true,
+                    languages: None,
+                }),
+            ),
+            ("secondary".to_string(), StubTranslator::boxed("secondary")),
+        ]);
+
+        let result = chain.translate("Save", &to("de")).await.unwrap();
+        assert_eq!(result, "secondary:de:Save");
+    }
+
+    #[tokio::test]
+    async fn test_fallback_skips_unsupported_language() {
+        let chain = FallbackTranslator::new(vec![
+            (
+                "deepl".to_string(),
+                Box::new(StubTranslator {
+                    name: "deepl",
+                    fails: false,
+                    languages: Some(vec!["de", "fr"]),
+                }),
+            ),
+            ("openai".to_string(), StubTranslator::boxed("openai")),
+        ]);
+
+        assert_eq!(
+            chain.translate("Save", &to("de")).await.unwrap(),
+            "deepl:de:Save"
+        );
+        assert_eq!(
+            chain.translate("Save", &to("vi")).await.unwrap(),
+            "openai:vi:Save"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fallback_all_failed() {
+        let chain = FallbackTranslator::new(vec![(
+            "deepl".to_string(),
+            Box::new(StubTranslator {
+                name: "deepl",
+                fails: false,
+                languages: Some(vec!["de"]),
+            }),
+        )]);
+
+        let err = chain.translate("Save", &to("hi")).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("No configured provider supports hi")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_routing_by_language() {
+        let router = RoutingTranslator::new(
+            vec![(
+                vec!["de".to_string(), "fr".to_string()],
+                StubTranslator::boxed("deepl"),
+            )],
+            Some(StubTranslator::boxed("openai")),
+        );
+
+        assert_eq!(
+            router.translate("Save", &to("de")).await.unwrap(),
+            "deepl:de:Save"
+        );
+        assert_eq!(
+            router.translate("Save", &to("fr-CA")).await.unwrap(),
+            "deepl:fr-CA:Save"
+        );
+        assert_eq!(
+            router.translate("Save", &to("vi")).await.unwrap(),
+            "openai:vi:Save"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_routing_without_default() {
+        let router = RoutingTranslator::new(
+            vec![(vec!["de".to_string()], StubTranslator::boxed("deepl"))],
+            None,
+        );
+
+        assert!(router.translate("Save", &to("hi")).await.is_err());
+        assert!(!router.supports_language(&Locale::parse("hi").unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_routing_quota_skips_failing_route() {
+        let router = RoutingTranslator::new(
+            vec![
+                (
+                    vec!["ja".to_string()],
+                    Box::new(StubTranslator {
+                        name: "google",
+                        fails: true,
+                        languages: None,
+                    }),
+                ),
+                (vec!["de".to_string()], StubTranslator::boxed("deepl")),
+                (vec!["fr".to_string()], StubTranslator::boxed("deepl")),
+            ],
+            Some(StubTranslator::boxed("openai")),
+        );
+
+        let quotas = router.quota().await.unwrap();
+        let providers: Vec<&str> = quotas.iter().map(|q| q.provider.as_str()).collect();
+        assert_eq!(providers, vec!["deepl", "openai"]);
+    }
+}